@@ -0,0 +1,208 @@
+use crate::{Applicative, Empty, Monad, Pure};
+
+/// Alternativeは、空の値と選択（フォールバック）を提供する型クラスです。
+///
+/// Alternativeは、Applicativeに「失敗」を表す空の値（`Empty::empty`）と、
+/// 2つの値のどちらかを選ぶ`combine_k`を追加します。`Option`では最初に成功した値を、
+/// `Vec`では両方の要素を連結した値を返します。
+///
+/// # 型クラス階層における位置
+///
+/// AlternativeはApplicativeとEmptyを組み合わせた型クラスです：
+/// ```text
+///               Applicative    Empty
+///                     \        /
+///                      v      v
+///                    Alternative
+///                         |
+///                         v
+///          Monad ----> MonadPlus
+/// ```
+///
+/// # Alternativeの法則
+///
+/// 1. 結合法則
+///    ```rust,ignore
+///    a.combine_k(b).combine_k(c) == a.combine_k(b.combine_k(c))
+///    ```
+///
+/// 2. 左単位元
+///    ```rust,ignore
+///    Empty::empty().combine_k(a) == a
+///    ```
+///
+/// 3. 右単位元
+///    ```rust,ignore
+///    a.combine_k(Empty::empty()) == a
+///    ```
+///
+/// # メソッド
+///
+/// * `combine_k` - 2つの値を選択・結合する
+/// * `or_else` - 値が空の場合にのみフォールバックを評価する
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{Alternative, Empty};
+///
+/// let result = None.combine_k(Some(1)).combine_k(Some(2));
+/// assert_eq!(result, Some(1));
+///
+/// let result = vec![1, 2].combine_k(Vec::empty()).combine_k(vec![3]);
+/// assert_eq!(result, vec![1, 2, 3]);
+/// ```
+pub trait Alternative: Applicative + Empty {
+    fn combine_k(self, other: Self) -> Self;
+
+    fn or_else<F>(self, f: F) -> Self
+    where
+        Self: Sized,
+        F: FnOnce() -> Self,
+    {
+        self.combine_k(f())
+    }
+}
+
+/// MonadPlusは、MonadとAlternativeを組み合わせた型クラスです。
+///
+/// # MonadPlusの法則
+///
+/// Alternativeの法則に加えて、以下の法則を満たす必要があります：
+///
+/// 1. 左零元：空の値に対するbindは空の値を返す
+///    ```rust,ignore
+///    Empty::empty().bind(f) == Empty::empty()
+///    ```
+///
+/// 2. 右零元：空の値を返す関数でbindすると空の値になる
+///    ```rust,ignore
+///    a.bind(|_| Empty::empty()) == Empty::empty()
+///    ```
+pub trait MonadPlus: Monad + Alternative {}
+
+/// 条件が真の場合は`pure(())`を、偽の場合は空の値を返します。
+///
+/// bindの連鎖の途中で使用することで、条件を満たさない計算を打ち切ることができます。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{guard, Bind};
+///
+/// let check = |x: i32| guard::<Option<()>>(x > 0).bind(move |_| Some(x * 2));
+/// assert_eq!(check(5), Some(10));
+/// assert_eq!(check(-5), None);
+/// ```
+pub fn guard<F>(cond: bool) -> F
+where
+    F: Alternative + Pure<Elm = (), M<()> = F>,
+{
+    if cond {
+        F::pure(())
+    } else {
+        F::empty()
+    }
+}
+
+/// `Option<A>`に対するAlternativeの実装
+///
+/// 最初に`Some`である値を返します。`or_else`はフォールバックを遅延評価します。
+impl<A: Clone> Alternative for Option<A> {
+    fn combine_k(self, other: Self) -> Self {
+        match self {
+            Some(_) => self,
+            None => other,
+        }
+    }
+
+    fn or_else<F>(self, f: F) -> Self
+    where
+        F: FnOnce() -> Self,
+    {
+        match self {
+            Some(_) => self,
+            None => f(),
+        }
+    }
+}
+
+/// `Vec<A>`に対するAlternativeの実装
+///
+/// 2つのベクトルを連結します。
+impl<A: Clone> Alternative for Vec<A> {
+    fn combine_k(self, other: Self) -> Self {
+        let mut concat = self;
+        concat.extend(other);
+        concat
+    }
+}
+
+impl<A: Clone> MonadPlus for Option<A> {}
+impl<A: Clone> MonadPlus for Vec<A> {}
+
+#[cfg(test)]
+mod laws {
+    mod option {
+        use crate::{Alternative, Bind, Empty};
+
+        #[quickcheck]
+        fn alternative_associativity(a: Option<i32>, b: Option<i32>, c: Option<i32>) -> bool {
+            a.combine_k(b).combine_k(c) == a.combine_k(b.combine_k(c))
+        }
+
+        #[quickcheck]
+        fn alternative_left_identity(a: Option<i32>) -> bool {
+            Option::empty().combine_k(a) == a
+        }
+
+        #[quickcheck]
+        fn alternative_right_identity(a: Option<i32>) -> bool {
+            a.combine_k(Option::empty()) == a
+        }
+
+        #[quickcheck]
+        fn monad_plus_left_zero(n: i32) -> bool {
+            Option::<i32>::empty().bind(|x| Some(*x + n)) == Option::empty()
+        }
+
+        #[quickcheck]
+        fn or_else_consistency(a: Option<i32>, b: Option<i32>) -> bool {
+            Alternative::or_else(a, || b) == a.combine_k(b)
+        }
+    }
+
+    mod vec {
+        use crate::{Alternative, Bind, Empty};
+
+        #[quickcheck]
+        fn alternative_associativity(a: Vec<i32>, b: Vec<i32>, c: Vec<i32>) -> bool {
+            a.clone().combine_k(b.clone()).combine_k(c.clone()) == a.combine_k(b.combine_k(c))
+        }
+
+        #[quickcheck]
+        fn alternative_left_identity(a: Vec<i32>) -> bool {
+            Vec::empty().combine_k(a.clone()) == a
+        }
+
+        #[quickcheck]
+        fn alternative_right_identity(a: Vec<i32>) -> bool {
+            a.clone().combine_k(Vec::empty()) == a
+        }
+
+        #[quickcheck]
+        fn monad_plus_right_zero(a: Vec<i32>) -> bool {
+            a.bind(|_| Vec::<i32>::empty()) == Vec::<i32>::empty()
+        }
+    }
+
+    mod guard {
+        use crate::{guard, Bind};
+
+        #[quickcheck]
+        fn guard_filters_values(n: i32) -> bool {
+            let filtered = guard::<Vec<()>>(n % 2 == 0).bind(|_| vec![n]);
+            filtered == if n % 2 == 0 { vec![n] } else { vec![] }
+        }
+    }
+}
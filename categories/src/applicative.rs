@@ -40,3 +40,115 @@ impl<A: Clone> Applicative for Box<A> {}
 impl<A: Clone> Applicative for Option<A> {}
 impl<A: Clone, E> Applicative for Result<A, E> {}
 impl<A: Clone> Applicative for Vec<A> {}
+
+/// ApplicativeFamilyは、Applicativeの型コンストラクタ`M<_>`全体に対する操作を提供する型クラスです。
+///
+/// `Pure`と`Apply`はそれぞれ独立した`M<U>`を持つため、汎用コードの中では
+/// 両者が同じ型コンストラクタであることを表現できません。この型クラスは
+/// `Apply::M<U>`に対して`pure`、`map`、`map2`を直接提供することで、
+/// `Traverse`のように任意のApplicativeを扱う処理を記述できるようにします。
+///
+/// # メソッド
+///
+/// * `pure_of` - 任意の値を`M<U>`にリフトする
+/// * `map_of` - `M<U>`内の値を変換する
+/// * `map2` - `M<U>`と`Self`を組み合わせる（`fu`の作用が先に評価される）
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::ApplicativeFamily;
+///
+/// let acc = <Option<i32> as ApplicativeFamily>::pure_of(vec![1]);
+/// let result = Option::map2(acc, Some(2), |mut v, a| {
+///     v.push(a);
+///     v
+/// });
+/// assert_eq!(result, Some(vec![1, 2]));
+/// ```
+pub trait ApplicativeFamily: Applicative {
+    fn pure_of<U: Clone>(value: U) -> <Self as Apply>::M<U>;
+
+    fn map_of<U: Clone, V: Clone, F>(fu: <Self as Apply>::M<U>, f: F) -> <Self as Apply>::M<V>
+    where
+        F: Fn(U) -> V;
+
+    fn map2<U: Clone, V: Clone, F>(
+        fu: <Self as Apply>::M<U>,
+        fa: Self,
+        f: F,
+    ) -> <Self as Apply>::M<V>
+    where
+        F: Fn(U, <Self as Apply>::Elm) -> V;
+}
+
+impl<A: Clone> ApplicativeFamily for Option<A> {
+    fn pure_of<U: Clone>(value: U) -> Option<U> {
+        Some(value)
+    }
+
+    fn map_of<U: Clone, V: Clone, F>(fu: Option<U>, f: F) -> Option<V>
+    where
+        F: Fn(U) -> V,
+    {
+        fu.map(f)
+    }
+
+    fn map2<U: Clone, V: Clone, F>(fu: Option<U>, fa: Self, f: F) -> Option<V>
+    where
+        F: Fn(U, A) -> V,
+    {
+        Some(f(fu?, fa?))
+    }
+}
+
+impl<A: Clone, E> ApplicativeFamily for Result<A, E> {
+    fn pure_of<U: Clone>(value: U) -> Result<U, E> {
+        Ok(value)
+    }
+
+    fn map_of<U: Clone, V: Clone, F>(fu: Result<U, E>, f: F) -> Result<V, E>
+    where
+        F: Fn(U) -> V,
+    {
+        fu.map(f)
+    }
+
+    fn map2<U: Clone, V: Clone, F>(fu: Result<U, E>, fa: Self, f: F) -> Result<V, E>
+    where
+        F: Fn(U, A) -> V,
+    {
+        let u = fu?;
+        let a = fa?;
+        Ok(f(u, a))
+    }
+}
+
+/// `Vec<A>`に対するApplicativeFamilyの実装
+///
+/// `map2`は`Bind`と整合するように、すべての組み合わせ（直積）を返します。
+impl<A: Clone> ApplicativeFamily for Vec<A> {
+    fn pure_of<U: Clone>(value: U) -> Vec<U> {
+        vec![value]
+    }
+
+    fn map_of<U: Clone, V: Clone, F>(fu: Vec<U>, f: F) -> Vec<V>
+    where
+        F: Fn(U) -> V,
+    {
+        fu.into_iter().map(f).collect()
+    }
+
+    fn map2<U: Clone, V: Clone, F>(fu: Vec<U>, fa: Self, f: F) -> Vec<V>
+    where
+        F: Fn(U, A) -> V,
+    {
+        let mut result = Vec::with_capacity(fu.len() * fa.len());
+        for u in fu.iter() {
+            for a in fa.iter() {
+                result.push(f(u.clone(), a.clone()));
+            }
+        }
+        result
+    }
+}
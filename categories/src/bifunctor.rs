@@ -0,0 +1,253 @@
+use crate::RecursionState;
+
+/// Bifunctorは、2つの型パラメータの両方を写像できる型クラスです。
+///
+/// Functorが1つの型パラメータ（`Elm`）のみを変換するのに対し、Bifunctorは
+/// `Left`と`Right`の2つの側をそれぞれ独立に、または同時に変換することができます。
+///
+/// # 型クラス階層における位置
+///
+/// Bifunctorは独立した型クラスであり、他の型クラスとの直接的な階層関係はありません。
+///
+/// # Bifunctorの法則
+///
+/// 1. 恒等関数を適用しても値は変わらない（恒等法則）
+///    ```rust,ignore
+///    x.bimap(|a| a.clone(), |b| b.clone()) == x
+///    ```
+///
+/// 2. 関数の合成は、個別に適用した結果と同じ（合成法則）
+///    ```rust,ignore
+///    x.bimap(|a| f1(&g1(a)), |b| f2(&g2(b))) == x.bimap(g1, g2).bimap(f1, f2)
+///    ```
+///
+/// 3. `bimap`は`left_map`と`right_map`の組み合わせと等しい
+///    ```rust,ignore
+///    x.bimap(f, g) == x.left_map(f).right_map(g)
+///    ```
+///
+/// # 型パラメータ
+///
+/// * `Left` - 左側の要素の型
+/// * `Right` - 右側の要素の型
+/// * `M<C, D>` - 変換後の型（C、Dはそれぞれ新しい左側・右側の型）
+///
+/// # メソッド
+///
+/// * `bimap` - 左側と右側の両方に関数を適用する
+/// * `left_map` - 左側のみに関数を適用する
+/// * `right_map` - 右側のみに関数を適用する
+pub trait Bifunctor: Sized {
+    type Left;
+    type Right;
+    type M<C: Clone, D: Clone>;
+
+    fn bimap<C: Clone, D: Clone, F, G>(self, f: F, g: G) -> Self::M<C, D>
+    where
+        F: Fn(&Self::Left) -> C,
+        G: Fn(&Self::Right) -> D;
+
+    fn left_map<C: Clone, F>(self, f: F) -> Self::M<C, Self::Right>
+    where
+        Self::Right: Clone,
+        F: Fn(&Self::Left) -> C,
+    {
+        self.bimap(f, |b| b.clone())
+    }
+
+    fn right_map<D: Clone, G>(self, g: G) -> Self::M<Self::Left, D>
+    where
+        Self::Left: Clone,
+        G: Fn(&Self::Right) -> D,
+    {
+        self.bimap(|a| a.clone(), g)
+    }
+}
+
+/// `Result<A, E>`に対するBifunctorの実装
+///
+/// `Err`側を左側、`Ok`側を右側として扱います。したがって`right_map`は`fmap`と同じ結果になります。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::Bifunctor;
+///
+/// let ok_value: Result<i32, &str> = Ok(5);
+/// assert_eq!(ok_value.bimap(|e| e.len(), |x| x * 2), Ok(10));
+///
+/// let err_value: Result<i32, &str> = Err("error");
+/// assert_eq!(err_value.left_map(|e| e.len()), Err(5));
+/// ```
+impl<A, E> Bifunctor for Result<A, E> {
+    type Left = E;
+    type Right = A;
+    type M<C: Clone, D: Clone> = Result<D, C>;
+
+    fn bimap<C: Clone, D: Clone, F, G>(self, f: F, g: G) -> Result<D, C>
+    where
+        F: Fn(&E) -> C,
+        G: Fn(&A) -> D,
+    {
+        match self {
+            Ok(a) => Ok(g(&a)),
+            Err(e) => Err(f(&e)),
+        }
+    }
+}
+
+/// `(A, B)`に対するBifunctorの実装
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::Bifunctor;
+///
+/// let pair = (1, "hello");
+/// assert_eq!(pair.bimap(|a| a + 1, |b| b.len()), (2, 5));
+/// assert_eq!(pair.left_map(|a| a * 10), (10, "hello"));
+/// ```
+impl<A, B> Bifunctor for (A, B) {
+    type Left = A;
+    type Right = B;
+    type M<C: Clone, D: Clone> = (C, D);
+
+    fn bimap<C: Clone, D: Clone, F, G>(self, f: F, g: G) -> (C, D)
+    where
+        F: Fn(&A) -> C,
+        G: Fn(&B) -> D,
+    {
+        (f(&self.0), g(&self.1))
+    }
+}
+
+/// `RecursionState<Done, Cont>`に対するBifunctorの実装
+///
+/// `Continue`側を左側、`Done`側を右側として扱います。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{Bifunctor, RecursionState};
+///
+/// let state: RecursionState<i32, i32> = RecursionState::Continue(1);
+/// assert_eq!(
+///     state.bimap(|c| c + 1, |d| d.to_string()),
+///     RecursionState::Continue(2)
+/// );
+///
+/// let state: RecursionState<i32, i32> = RecursionState::Done(1);
+/// assert_eq!(
+///     state.right_map(|d| d.to_string()),
+///     RecursionState::Done("1".to_string())
+/// );
+/// ```
+impl<Done, Cont> Bifunctor for RecursionState<Done, Cont> {
+    type Left = Cont;
+    type Right = Done;
+    type M<C: Clone, D: Clone> = RecursionState<D, C>;
+
+    fn bimap<C: Clone, D: Clone, F, G>(self, f: F, g: G) -> RecursionState<D, C>
+    where
+        F: Fn(&Cont) -> C,
+        G: Fn(&Done) -> D,
+    {
+        match self {
+            RecursionState::Continue(c) => RecursionState::Continue(f(&c)),
+            RecursionState::Done(d) => RecursionState::Done(g(&d)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    mod result {
+        use crate::{Bifunctor, Functor};
+
+        #[quickcheck]
+        fn bifunctor_identity(n: Result<i32, String>) -> bool {
+            n.clone().bimap(|e| e.clone(), |a| *a) == n
+        }
+
+        #[quickcheck]
+        fn bifunctor_composition(n: Result<i32, String>) -> bool {
+            let f1: fn(&usize) -> usize = |x| *x * 2;
+            let g1: fn(&String) -> usize = |e| e.len();
+            let f2: fn(&i32) -> i32 = |x| *x + 4;
+            let g2: fn(&i32) -> i32 = |x| *x * 3;
+            n.clone().bimap(g1, g2).bimap(f1, f2) == n.bimap(|e| f1(&g1(e)), |a| f2(&g2(a)))
+        }
+
+        #[quickcheck]
+        fn bimap_consistency(n: Result<i32, String>) -> bool {
+            let f: fn(&String) -> usize = |e| e.len();
+            let g: fn(&i32) -> i32 = |x| *x + 4;
+            n.clone().bimap(f, g) == n.left_map(f).right_map(g)
+        }
+
+        #[quickcheck]
+        fn right_map_is_fmap(n: Result<i32, String>) -> bool {
+            n.clone().right_map(|x| *x * 2) == n.fmap(|x| *x * 2)
+        }
+    }
+
+    mod tuple {
+        use crate::Bifunctor;
+
+        #[quickcheck]
+        fn bifunctor_identity(n: (i32, String)) -> bool {
+            n.clone().bimap(|a| *a, |b| b.clone()) == n
+        }
+
+        #[quickcheck]
+        fn bifunctor_composition(n: (i32, String)) -> bool {
+            let f1: fn(&i32) -> i32 = |x| *x + 4;
+            let g1: fn(&i32) -> i32 = |x| *x * 3;
+            let f2: fn(&usize) -> usize = |x| *x * 2;
+            let g2: fn(&String) -> usize = |b| b.len();
+            n.clone().bimap(g1, g2).bimap(f1, f2) == n.bimap(|a| f1(&g1(a)), |b| f2(&g2(b)))
+        }
+
+        #[quickcheck]
+        fn bimap_consistency(n: (i32, String)) -> bool {
+            let f: fn(&i32) -> i32 = |x| *x + 4;
+            let g: fn(&String) -> usize = |b| b.len();
+            n.clone().bimap(f, g) == n.left_map(f).right_map(g)
+        }
+    }
+
+    mod recursion_state {
+        use crate::{Bifunctor, RecursionState};
+
+        fn to_state(n: Result<i32, String>) -> RecursionState<i32, String> {
+            match n {
+                Ok(d) => RecursionState::Done(d),
+                Err(c) => RecursionState::Continue(c),
+            }
+        }
+
+        #[quickcheck]
+        fn bifunctor_identity(n: Result<i32, String>) -> bool {
+            let state = to_state(n);
+            state.clone().bimap(|c| c.clone(), |d| *d) == state
+        }
+
+        #[quickcheck]
+        fn bifunctor_composition(n: Result<i32, String>) -> bool {
+            let state = to_state(n);
+            let f1: fn(&usize) -> usize = |x| *x * 2;
+            let g1: fn(&String) -> usize = |c| c.len();
+            let f2: fn(&i32) -> i32 = |x| *x + 4;
+            let g2: fn(&i32) -> i32 = |x| *x * 3;
+            state.clone().bimap(g1, g2).bimap(f1, f2) == state.bimap(|c| f1(&g1(c)), |d| f2(&g2(d)))
+        }
+
+        #[quickcheck]
+        fn bimap_consistency(n: Result<i32, String>) -> bool {
+            let state = to_state(n);
+            let f: fn(&String) -> usize = |c| c.len();
+            let g: fn(&i32) -> i32 = |x| *x + 4;
+            state.clone().bimap(f, g) == state.left_map(f).right_map(g)
+        }
+    }
+}
@@ -0,0 +1,739 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, LinkedList, VecDeque};
+use std::convert::TryFrom;
+use std::hash::Hash;
+use std::ops::ControlFlow;
+
+use crate::{
+    Applicative, ApplicativeFamily, Apply, Bind, Foldable, Functor, Monad, Pure, Traverse,
+};
+
+// --- VecDeque
+
+/// `VecDeque<A>`に対する型クラスの実装
+///
+/// `Vec`と同じく、`Bind`は各要素の結果を順に連結します。
+/// `Apply`は、`Bind`と整合するように、関数と値のすべての組み合わせに適用します。
+///
+/// # 例
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use rust_fp_categories::{Apply, Bind, Foldable, Functor};
+///
+/// let values: VecDeque<i32> = vec![1, 2].into_iter().collect();
+/// assert_eq!(values.clone().fmap(|x| x * 10), VecDeque::from(vec![10, 20]));
+/// assert_eq!(
+///     values.clone().bind(|x| VecDeque::from(vec![*x, -*x])),
+///     VecDeque::from(vec![1, -1, 2, -2])
+/// );
+/// let fs: VecDeque<fn(&i32) -> i32> = VecDeque::from(vec![|x: &i32| x + 1, |x: &i32| x * 2]);
+/// assert_eq!(values.clone().ap(fs), VecDeque::from(vec![2, 3, 2, 4]));
+/// assert_eq!(values.fold_left(0, |acc, x| acc + x), 3);
+/// ```
+impl<A> Functor for VecDeque<A> {
+    type Elm = A;
+    type M<B: Clone> = VecDeque<B>;
+
+    fn fmap<B: Clone, F>(self, f: F) -> VecDeque<B>
+    where
+        F: Fn(&A) -> B,
+    {
+        self.iter().map(f).collect()
+    }
+}
+
+impl<A: Clone> Pure for VecDeque<A> {
+    type Elm = A;
+    type M<U: Clone> = VecDeque<U>;
+
+    fn pure(value: A) -> VecDeque<A> {
+        VecDeque::from(vec![value])
+    }
+
+    fn unit() -> VecDeque<()> {
+        VecDeque::from(vec![()])
+    }
+}
+
+impl<A> Apply for VecDeque<A> {
+    type Elm = A;
+    type M<U: Clone> = VecDeque<U>;
+
+    fn ap<B: Clone, F>(self, fs: VecDeque<F>) -> VecDeque<B>
+    where
+        F: Clone + Fn(&A) -> B,
+    {
+        fs.iter().flat_map(|f| self.iter().map(f)).collect()
+    }
+}
+
+impl<A: Clone> Applicative for VecDeque<A> {}
+
+impl<A> Bind for VecDeque<A> {
+    type Elm = A;
+    type M<U: Clone> = VecDeque<U>;
+
+    fn bind<B: Clone, F>(self, f: F) -> VecDeque<B>
+    where
+        F: Fn(&A) -> VecDeque<B>,
+    {
+        self.iter().flat_map(f).collect()
+    }
+}
+
+impl<A: Clone> Monad for VecDeque<A> {}
+
+impl<A> Foldable for VecDeque<A> {
+    type Elm = A;
+
+    fn fold_left<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(B, &A) -> B,
+    {
+        self.iter().fold(b, f)
+    }
+
+    fn fold_right<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(&A, B) -> B,
+    {
+        self.iter().rev().fold(b, |b, a| f(a, b))
+    }
+
+    fn try_fold<B, C, F>(&self, b: B, f: F) -> ControlFlow<C, B>
+    where
+        F: Fn(B, &A) -> ControlFlow<C, B>,
+    {
+        self.iter().try_fold(b, f)
+    }
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<A> Traverse for VecDeque<A> {
+    fn traverse<G, F>(self, f: F) -> <G as Apply>::M<VecDeque<<G as Apply>::Elm>>
+    where
+        G: ApplicativeFamily,
+        <G as Apply>::Elm: Clone,
+        VecDeque<<G as Apply>::Elm>: Clone,
+        F: Fn(&A) -> G,
+    {
+        let size = self.len();
+        self.iter()
+            .fold(G::pure_of(VecDeque::with_capacity(size)), |acc, a| {
+                G::map2(acc, f(a), |mut values, b| {
+                    values.push_back(b);
+                    values
+                })
+            })
+    }
+}
+
+// --- LinkedList
+
+/// `LinkedList<A>`に対する型クラスの実装
+///
+/// `VecDeque`と同じく、`Apply`は`Bind`と整合するように、関数と値のすべての組み合わせに適用します。
+///
+/// # 例
+///
+/// ```
+/// use std::collections::LinkedList;
+/// use rust_fp_categories::{Foldable, Functor, Traverse};
+///
+/// let values: LinkedList<i32> = vec![1, 2, 3].into_iter().collect();
+/// assert_eq!(values.clone().fmap(|x| x + 1).to_vec(), vec![2, 3, 4]);
+/// assert_eq!(
+///     values.traverse(|x| if *x > 0 { Some(*x) } else { None }).map(|l| l.length()),
+///     Some(3)
+/// );
+/// ```
+impl<A> Functor for LinkedList<A> {
+    type Elm = A;
+    type M<B: Clone> = LinkedList<B>;
+
+    fn fmap<B: Clone, F>(self, f: F) -> LinkedList<B>
+    where
+        F: Fn(&A) -> B,
+    {
+        self.iter().map(f).collect()
+    }
+}
+
+impl<A: Clone> Pure for LinkedList<A> {
+    type Elm = A;
+    type M<U: Clone> = LinkedList<U>;
+
+    fn pure(value: A) -> LinkedList<A> {
+        let mut list = LinkedList::new();
+        list.push_back(value);
+        list
+    }
+
+    fn unit() -> LinkedList<()> {
+        LinkedList::pure(())
+    }
+}
+
+impl<A> Apply for LinkedList<A> {
+    type Elm = A;
+    type M<U: Clone> = LinkedList<U>;
+
+    fn ap<B: Clone, F>(self, fs: LinkedList<F>) -> LinkedList<B>
+    where
+        F: Clone + Fn(&A) -> B,
+    {
+        fs.iter().flat_map(|f| self.iter().map(f)).collect()
+    }
+}
+
+impl<A: Clone> Applicative for LinkedList<A> {}
+
+impl<A> Bind for LinkedList<A> {
+    type Elm = A;
+    type M<U: Clone> = LinkedList<U>;
+
+    fn bind<B: Clone, F>(self, f: F) -> LinkedList<B>
+    where
+        F: Fn(&A) -> LinkedList<B>,
+    {
+        self.iter().flat_map(f).collect()
+    }
+}
+
+impl<A: Clone> Monad for LinkedList<A> {}
+
+impl<A> Foldable for LinkedList<A> {
+    type Elm = A;
+
+    fn fold_left<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(B, &A) -> B,
+    {
+        self.iter().fold(b, f)
+    }
+
+    fn fold_right<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(&A, B) -> B,
+    {
+        self.iter().rev().fold(b, |b, a| f(a, b))
+    }
+
+    fn try_fold<B, C, F>(&self, b: B, f: F) -> ControlFlow<C, B>
+    where
+        F: Fn(B, &A) -> ControlFlow<C, B>,
+    {
+        self.iter().try_fold(b, f)
+    }
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<A> Traverse for LinkedList<A> {
+    fn traverse<G, F>(self, f: F) -> <G as Apply>::M<LinkedList<<G as Apply>::Elm>>
+    where
+        G: ApplicativeFamily,
+        <G as Apply>::Elm: Clone,
+        LinkedList<<G as Apply>::Elm>: Clone,
+        F: Fn(&A) -> G,
+    {
+        self.iter().fold(G::pure_of(LinkedList::new()), |acc, a| {
+            G::map2(acc, f(a), |mut values, b| {
+                values.push_back(b);
+                values
+            })
+        })
+    }
+}
+
+// --- HashMap
+
+/// `HashMap<K, V>`に対する、値についての型クラスの実装
+///
+/// キーはそのままに値だけを変換・畳み込みます。`Foldable`の走査順序は`HashMap`の反復順序に従い、不定です。
+/// `pure`で作るべきキーが存在しないため、`Pure`/`Apply`/`Bind`は実装していません。
+///
+/// # 例
+///
+/// ```
+/// use std::collections::HashMap;
+/// use rust_fp_categories::{Foldable, Functor, Sum};
+///
+/// let stock: HashMap<&str, i32> = vec![("apple", 3), ("pear", 5)].into_iter().collect();
+/// let doubled = stock.fmap(|n| n * 2);
+/// assert_eq!(doubled["pear"], 10);
+/// assert_eq!(doubled.fold_map(|n| Sum(*n)), Sum(16));
+/// ```
+impl<K: Eq + Hash, V> Functor for HashMap<K, V> {
+    type Elm = V;
+    type M<B: Clone> = HashMap<K, B>;
+
+    fn fmap<B: Clone, F>(self, f: F) -> HashMap<K, B>
+    where
+        F: Fn(&V) -> B,
+    {
+        self.into_iter().map(|(k, v)| (k, f(&v))).collect()
+    }
+}
+
+impl<K, V> Foldable for HashMap<K, V> {
+    type Elm = V;
+
+    fn fold_left<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(B, &V) -> B,
+    {
+        self.values().fold(b, f)
+    }
+
+    fn fold_right<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(&V, B) -> B,
+    {
+        let values: Vec<&V> = self.values().collect();
+        values.into_iter().rev().fold(b, |b, v| f(v, b))
+    }
+
+    fn try_fold<B, C, F>(&self, b: B, f: F) -> ControlFlow<C, B>
+    where
+        F: Fn(B, &V) -> ControlFlow<C, B>,
+    {
+        self.values().try_fold(b, f)
+    }
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> Traverse for HashMap<K, V> {
+    fn traverse<G, F>(self, f: F) -> <G as Apply>::M<HashMap<K, <G as Apply>::Elm>>
+    where
+        G: ApplicativeFamily,
+        <G as Apply>::Elm: Clone,
+        HashMap<K, <G as Apply>::Elm>: Clone,
+        F: Fn(&V) -> G,
+    {
+        let size = self.len();
+        self.iter()
+            .fold(G::pure_of(HashMap::with_capacity(size)), |acc, (k, v)| {
+                G::map2(acc, f(v), |mut values, b| {
+                    values.insert(k.clone(), b);
+                    values
+                })
+            })
+    }
+}
+
+// --- BTreeMap
+
+/// `BTreeMap<K, V>`に対する、値についての型クラスの実装
+///
+/// `HashMap`と同じく値だけを扱いますが、`Foldable`はキーの昇順に走査します。
+///
+/// # 例
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use rust_fp_categories::{Foldable, Functor, Traverse};
+///
+/// let scores: BTreeMap<&str, i32> = vec![("bob", 70), ("alice", 90)].into_iter().collect();
+/// assert_eq!(scores.to_vec(), vec![90, 70]);
+///
+/// let passed = scores.traverse(|n| if *n >= 60 { Ok(*n) } else { Err(*n) });
+/// assert_eq!(passed.map(|m| m["bob"]), Ok(70));
+/// ```
+impl<K: Ord, V> Functor for BTreeMap<K, V> {
+    type Elm = V;
+    type M<B: Clone> = BTreeMap<K, B>;
+
+    fn fmap<B: Clone, F>(self, f: F) -> BTreeMap<K, B>
+    where
+        F: Fn(&V) -> B,
+    {
+        self.into_iter().map(|(k, v)| (k, f(&v))).collect()
+    }
+}
+
+impl<K, V> Foldable for BTreeMap<K, V> {
+    type Elm = V;
+
+    fn fold_left<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(B, &V) -> B,
+    {
+        self.values().fold(b, f)
+    }
+
+    fn fold_right<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(&V, B) -> B,
+    {
+        self.values().rev().fold(b, |b, v| f(v, b))
+    }
+
+    fn try_fold<B, C, F>(&self, b: B, f: F) -> ControlFlow<C, B>
+    where
+        F: Fn(B, &V) -> ControlFlow<C, B>,
+    {
+        self.values().try_fold(b, f)
+    }
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K: Clone + Ord, V> Traverse for BTreeMap<K, V> {
+    fn traverse<G, F>(self, f: F) -> <G as Apply>::M<BTreeMap<K, <G as Apply>::Elm>>
+    where
+        G: ApplicativeFamily,
+        <G as Apply>::Elm: Clone,
+        BTreeMap<K, <G as Apply>::Elm>: Clone,
+        F: Fn(&V) -> G,
+    {
+        self.iter()
+            .fold(G::pure_of(BTreeMap::new()), |acc, (k, v)| {
+                G::map2(acc, f(v), |mut values, b| {
+                    values.insert(k.clone(), b);
+                    values
+                })
+            })
+    }
+}
+
+// --- BTreeSet
+
+/// `BTreeSet<A>`に対するFoldableの実装
+///
+/// 要素の昇順に走査します。変換後の要素に`Ord`を要求できないため、`Functor`は実装していません。
+///
+/// # 例
+///
+/// ```
+/// use std::collections::BTreeSet;
+/// use rust_fp_categories::Foldable;
+///
+/// let ids: BTreeSet<u32> = vec![3, 1, 2].into_iter().collect();
+/// assert_eq!(ids.to_vec(), vec![1, 2, 3]);
+/// assert_eq!(ids.find(|id| *id > 1), Some(2));
+/// ```
+impl<A> Foldable for BTreeSet<A> {
+    type Elm = A;
+
+    fn fold_left<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(B, &A) -> B,
+    {
+        self.iter().fold(b, f)
+    }
+
+    fn fold_right<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(&A, B) -> B,
+    {
+        self.iter().rev().fold(b, |b, a| f(a, b))
+    }
+
+    fn try_fold<B, C, F>(&self, b: B, f: F) -> ControlFlow<C, B>
+    where
+        F: Fn(B, &A) -> ControlFlow<C, B>,
+    {
+        self.iter().try_fold(b, f)
+    }
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+// --- [A; N]
+
+/// 固定長配列`[A; N]`に対する型クラスの実装
+///
+/// 長さが型で決まっているため、`Apply`は同じ位置の要素どうしに適用し、
+/// `pure`はすべての位置に同じ値を置きます。
+/// `Bind`は、`i`番目の要素から作った配列の`i`番目の要素を取り出します(対角成分)。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{Apply, Bind, Functor, Pure};
+///
+/// let xs = [1, 2, 3];
+/// assert_eq!(xs.fmap(|x| x * 2), [2, 4, 6]);
+///
+/// let fs: [fn(&i32) -> i32; 3] = [|x| x + 1, |x| x * 10, |x| -x];
+/// assert_eq!(xs.ap(fs), [2, 20, -3]);
+///
+/// assert_eq!(<[i32; 3]>::pure(7), [7, 7, 7]);
+/// assert_eq!(xs.bind(|x| [*x, x * 10, x * 100]), [1, 20, 300]);
+/// ```
+impl<A, const N: usize> Functor for [A; N] {
+    type Elm = A;
+    type M<B: Clone> = [B; N];
+
+    fn fmap<B: Clone, F>(self, f: F) -> [B; N]
+    where
+        F: Fn(&A) -> B,
+    {
+        self.map(|a| f(&a))
+    }
+}
+
+impl<A: Clone, const N: usize> Pure for [A; N] {
+    type Elm = A;
+    type M<U: Clone> = [U; N];
+
+    fn pure(value: A) -> [A; N] {
+        [(); N].map(|_| value.clone())
+    }
+
+    fn unit() -> [(); N] {
+        [(); N]
+    }
+}
+
+impl<A, const N: usize> Apply for [A; N] {
+    type Elm = A;
+    type M<U: Clone> = [U; N];
+
+    fn ap<B: Clone, F>(self, fs: [F; N]) -> [B; N]
+    where
+        F: Clone + Fn(&A) -> B,
+    {
+        let mut fs = fs.iter();
+        self.map(|a| (fs.next().expect("arrays have the same length"))(&a))
+    }
+}
+
+impl<A: Clone, const N: usize> Applicative for [A; N] {}
+
+impl<A, const N: usize> Bind for [A; N] {
+    type Elm = A;
+    type M<U: Clone> = [U; N];
+
+    fn bind<B: Clone, F>(self, f: F) -> [B; N]
+    where
+        F: Fn(&A) -> [B; N],
+    {
+        let mut index = 0;
+        self.map(|a| {
+            let b = f(&a)[index].clone();
+            index += 1;
+            b
+        })
+    }
+}
+
+impl<A: Clone, const N: usize> Monad for [A; N] {}
+
+impl<A, const N: usize> Foldable for [A; N] {
+    type Elm = A;
+
+    fn fold_left<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(B, &A) -> B,
+    {
+        self.iter().fold(b, f)
+    }
+
+    fn fold_right<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(&A, B) -> B,
+    {
+        self.iter().rev().fold(b, |b, a| f(a, b))
+    }
+
+    fn try_fold<B, C, F>(&self, b: B, f: F) -> ControlFlow<C, B>
+    where
+        F: Fn(B, &A) -> ControlFlow<C, B>,
+    {
+        self.iter().try_fold(b, f)
+    }
+
+    fn length(&self) -> usize {
+        N
+    }
+}
+
+impl<A, const N: usize> Traverse for [A; N] {
+    fn traverse<G, F>(self, f: F) -> <G as Apply>::M<[<G as Apply>::Elm; N]>
+    where
+        G: ApplicativeFamily,
+        <G as Apply>::Elm: Clone,
+        [<G as Apply>::Elm; N]: Clone,
+        F: Fn(&A) -> G,
+    {
+        let values = self
+            .iter()
+            .fold(G::pure_of(Vec::with_capacity(N)), |acc, a| {
+                G::map2(acc, f(a), |mut values, b| {
+                    values.push(b);
+                    values
+                })
+            });
+        G::map_of(values, |values| {
+            <[<G as Apply>::Elm; N]>::try_from(values)
+                .ok()
+                .expect("traverse keeps the length of the array")
+        })
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    mod vec_deque {
+        use crate::{Apply, Bind, Functor, Pure, Traverse};
+        use std::collections::VecDeque;
+
+        fn f(x: &i32) -> VecDeque<i32> {
+            VecDeque::from(vec![*x, x.wrapping_add(1)])
+        }
+
+        fn g(x: &i32) -> VecDeque<i32> {
+            if x % 2 == 0 {
+                VecDeque::new()
+            } else {
+                VecDeque::from(vec![x.wrapping_mul(3)])
+            }
+        }
+
+        #[quickcheck]
+        fn functor_identity(xs: Vec<i32>) -> bool {
+            let xs = VecDeque::from(xs);
+            xs.clone().fmap(|x| *x) == xs
+        }
+
+        #[quickcheck]
+        fn applicative_identity(xs: Vec<i32>) -> bool {
+            let xs = VecDeque::from(xs);
+            xs.clone().ap(VecDeque::pure(|x: &i32| *x)) == xs
+        }
+
+        #[quickcheck]
+        fn monad_left_identity(n: i32) -> bool {
+            VecDeque::pure(n).bind(f) == f(&n)
+        }
+
+        #[quickcheck]
+        fn monad_right_identity(xs: Vec<i32>) -> bool {
+            let xs = VecDeque::from(xs);
+            xs.clone().bind(|x| VecDeque::pure(*x)) == xs
+        }
+
+        #[quickcheck]
+        fn monad_associativity(xs: Vec<i32>) -> bool {
+            let xs = VecDeque::from(xs);
+            xs.clone().bind(f).bind(g) == xs.bind(|x| f(x).bind(g))
+        }
+
+        #[quickcheck]
+        fn traverse_identity(xs: Vec<i32>) -> bool {
+            let xs = VecDeque::from(xs);
+            xs.clone().traverse(|x| Some(*x)) == Some(xs)
+        }
+    }
+
+    mod linked_list {
+        use crate::{Bind, Functor, Pure};
+        use std::collections::LinkedList;
+
+        fn f(x: &i32) -> LinkedList<i32> {
+            vec![*x, x.wrapping_sub(1)].into_iter().collect()
+        }
+
+        #[quickcheck]
+        fn functor_composition(xs: Vec<i32>) -> bool {
+            let xs: LinkedList<i32> = xs.into_iter().collect();
+            let f1 = |x: &i32| x.wrapping_mul(2);
+            let f2 = |x: &i32| x.wrapping_add(4);
+            xs.clone().fmap(f1).fmap(f2) == xs.fmap(|x| f2(&f1(x)))
+        }
+
+        #[quickcheck]
+        fn monad_identities(n: i32, xs: Vec<i32>) -> bool {
+            let xs: LinkedList<i32> = xs.into_iter().collect();
+            LinkedList::pure(n).bind(f) == f(&n) && xs.clone().bind(|x| LinkedList::pure(*x)) == xs
+        }
+    }
+
+    mod maps {
+        use crate::{Foldable, Functor, Traverse};
+        use std::collections::{BTreeMap, HashMap};
+
+        #[quickcheck]
+        fn hash_map_functor_keeps_keys(m: HashMap<u8, i32>) -> bool {
+            let mapped = m.clone().fmap(|v| v.wrapping_mul(2));
+            mapped.len() == m.len() && m.iter().all(|(k, v)| mapped[k] == v.wrapping_mul(2))
+        }
+
+        #[quickcheck]
+        fn btree_map_functor_identity(m: BTreeMap<u8, i32>) -> bool {
+            m.clone().fmap(|v| *v) == m
+        }
+
+        #[quickcheck]
+        fn btree_map_folds_values_in_key_order(m: BTreeMap<u8, i32>) -> bool {
+            m.to_vec() == m.values().copied().collect::<Vec<_>>()
+        }
+
+        #[quickcheck]
+        fn hash_map_traverse_identity(m: HashMap<u8, i32>) -> bool {
+            m.clone().traverse(|v| Some(*v)) == Some(m)
+        }
+    }
+
+    mod array {
+        use crate::{Apply, Bind, Functor, Pure, Traverse};
+
+        fn f(x: &i32) -> [i32; 3] {
+            [*x, x.wrapping_add(1), x.wrapping_mul(2)]
+        }
+
+        fn g(x: &i32) -> [i32; 3] {
+            [x.wrapping_sub(1), *x, x.wrapping_neg()]
+        }
+
+        #[quickcheck]
+        fn applicative_identity(a: i32, b: i32, c: i32) -> bool {
+            let xs = [a, b, c];
+            xs.ap(<[fn(&i32) -> i32; 3]>::pure(|x: &i32| *x)) == xs
+        }
+
+        #[quickcheck]
+        fn applicative_homomorphism(n: i32) -> bool {
+            let h = |x: &i32| x.wrapping_mul(5);
+            <[i32; 3]>::pure(n).ap(<[_; 3]>::pure(h)) == <[i32; 3]>::pure(h(&n))
+        }
+
+        #[quickcheck]
+        fn monad_left_identity(n: i32) -> bool {
+            <[i32; 3]>::pure(n).bind(f) == f(&n)
+        }
+
+        #[quickcheck]
+        fn monad_right_identity(a: i32, b: i32, c: i32) -> bool {
+            let xs = [a, b, c];
+            xs.bind(|x| <[i32; 3]>::pure(*x)) == xs
+        }
+
+        #[quickcheck]
+        fn monad_associativity(a: i32, b: i32, c: i32) -> bool {
+            let xs = [a, b, c];
+            xs.bind(f).bind(g) == xs.bind(|x| f(x).bind(g))
+        }
+
+        #[quickcheck]
+        fn traverse_consistency(a: i32, b: i32) -> bool {
+            let check = |x: &i32| if x % 3 == 0 { None } else { Some(*x) };
+            [a, b].traverse(check) == vec![a, b].traverse(check).map(|v| [v[0], v[1]])
+                && [a, b].fmap(|x| *x).traverse(|x| Some(*x)) == Some([a, b])
+        }
+    }
+}
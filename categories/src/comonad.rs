@@ -0,0 +1,367 @@
+use std::fmt;
+use std::rc::Rc;
+
+use crate::NonEmptyVec;
+
+/// Comonadは、文脈から値を取り出し、文脈に依存する計算を拡張する型クラスです。
+///
+/// Monadが「値を文脈に入れて連鎖させる」のに対し、Comonadは「文脈全体を見て値を計算する」
+/// 操作を提供します。移動平均のようなスライディングウィンドウ処理や、セルオートマトンのような
+/// 近傍に依存する計算を自然に記述することができます。
+///
+/// # 型クラス階層における位置
+///
+/// ComonadはMonadと双対の関係にある独立した型クラスです：
+/// ```text
+///   Monad: pure    (A -> M<A>)      bind   (M<A> -> (A -> M<B>) -> M<B>)
+///   Comonad: extract (W<A> -> A)    extend (W<A> -> (W<A> -> B) -> W<B>)
+/// ```
+///
+/// # Comonadの法則
+///
+/// 1. 左恒等法則
+///    ```rust,ignore
+///    w.extend(|w| w.extract()) == w
+///    ```
+///
+/// 2. 右恒等法則
+///    ```rust,ignore
+///    w.extend(f).extract() == f(&w)
+///    ```
+///
+/// 3. 結合法則
+///    ```rust,ignore
+///    w.extend(f).extend(g) == w.extend(|w| g(&w.clone().extend(f)))
+///    ```
+///
+/// # 型パラメータ
+///
+/// * `Elm` - 文脈が保持する値の型
+/// * `M<B>` - 変換後の型（Bは新しい値の型）
+///
+/// # メソッド
+///
+/// * `extract` - 文脈から現在の値を取り出す
+/// * `extend` - 文脈全体を受け取る関数を、すべての位置に適用する
+/// * `duplicate` - 文脈を、各位置の文脈を値とする文脈に変換する
+pub trait Comonad: Sized {
+    type Elm;
+    type M<B: Clone>;
+
+    fn extract(&self) -> Self::Elm;
+
+    fn extend<B: Clone + 'static, F>(self, f: F) -> Self::M<B>
+    where
+        F: Fn(&Self) -> B + 'static;
+
+    fn duplicate(self) -> Self::M<Self>
+    where
+        Self: Clone + 'static,
+    {
+        self.extend(|w: &Self| w.clone())
+    }
+}
+
+/// `NonEmptyVec<A>`に対するComonadの実装
+///
+/// `extend`は、各位置から末尾までの部分列に関数を適用します。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{Comonad, NonEmptyVec};
+///
+/// // 3要素のスライディングウィンドウで平均を計算する
+/// let values = NonEmptyVec::new(1.0, vec![2.0, 3.0, 4.0, 5.0]);
+/// let smoothed = values.extend(|w: &NonEmptyVec<f64>| {
+///     let window: Vec<f64> = w.iter().take(3).cloned().collect();
+///     window.iter().sum::<f64>() / window.len() as f64
+/// });
+/// assert_eq!(smoothed.to_vec(), vec![2.0, 3.0, 4.0, 4.5, 5.0]);
+/// ```
+impl<A: Clone> Comonad for NonEmptyVec<A> {
+    type Elm = A;
+    type M<B: Clone> = NonEmptyVec<B>;
+
+    fn extract(&self) -> A {
+        self.head().clone()
+    }
+
+    fn extend<B: Clone + 'static, F>(self, f: F) -> NonEmptyVec<B>
+    where
+        F: Fn(&Self) -> B + 'static,
+    {
+        let head = f(&self);
+        let mut tail = Vec::with_capacity(self.len() - 1);
+        let mut current = self.tail_non_empty();
+        while let Some(w) = current {
+            tail.push(f(&w));
+            current = w.tail_non_empty();
+        }
+        NonEmptyVec::new(head, tail)
+    }
+}
+
+/// Storeは、位置`S`と、位置から値を得る関数の組で表される文脈です。
+///
+/// セルオートマトンのように、ある位置の値をその近傍から計算する処理に適しています。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{Comonad, Store};
+///
+/// // 位置0の周辺だけが生きているセル（ルール90のセルオートマトン）
+/// let cells = Store::new(|i: &i32| *i == 0, 0);
+/// let rule90 = |w: &Store<i32, bool>| w.peeks(|i| i - 1) != w.peeks(|i| i + 1);
+///
+/// let next = cells.extend(rule90);
+/// assert!(!next.peek(&0));
+/// assert!(next.peek(&-1));
+/// assert!(next.peek(&1));
+/// ```
+pub struct Store<S, A> {
+    run: Rc<dyn Fn(&S) -> A>,
+    pos: S,
+}
+
+impl<S: Clone + 'static, A: 'static> Store<S, A> {
+    pub fn new<F>(f: F, pos: S) -> Self
+    where
+        F: Fn(&S) -> A + 'static,
+    {
+        Store {
+            run: Rc::new(f),
+            pos,
+        }
+    }
+
+    pub fn pos(&self) -> &S {
+        &self.pos
+    }
+
+    pub fn peek(&self, s: &S) -> A {
+        (self.run)(s)
+    }
+
+    pub fn peeks<F>(&self, f: F) -> A
+    where
+        F: Fn(&S) -> S,
+    {
+        self.peek(&f(&self.pos))
+    }
+
+    pub fn seek(self, pos: S) -> Self {
+        Store { run: self.run, pos }
+    }
+}
+
+impl<S: Clone, A> Clone for Store<S, A> {
+    fn clone(&self) -> Self {
+        Store {
+            run: Rc::clone(&self.run),
+            pos: self.pos.clone(),
+        }
+    }
+}
+
+impl<S: fmt::Debug, A> fmt::Debug for Store<S, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Store").field("pos", &self.pos).finish()
+    }
+}
+
+impl<S: Clone + 'static, A: 'static> Comonad for Store<S, A> {
+    type Elm = A;
+    type M<B: Clone> = Store<S, B>;
+
+    fn extract(&self) -> A {
+        self.peek(&self.pos)
+    }
+
+    fn extend<B: Clone + 'static, F>(self, f: F) -> Store<S, B>
+    where
+        F: Fn(&Self) -> B + 'static,
+    {
+        let run = Rc::clone(&self.run);
+        Store::new(
+            move |s: &S| {
+                f(&Store {
+                    run: Rc::clone(&run),
+                    pos: s.clone(),
+                })
+            },
+            self.pos,
+        )
+    }
+}
+
+/// Envは、読み取り専用の環境`E`と値`A`の組で表される文脈です。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{Comonad, Env};
+///
+/// let env = Env::new(10, 5);
+/// let scaled = env.extend(|w: &Env<i32, i32>| w.extract() * w.ask());
+/// assert_eq!(scaled.extract(), 50);
+/// assert_eq!(*scaled.ask(), 10);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Env<E, A> {
+    env: E,
+    value: A,
+}
+
+impl<E, A> Env<E, A> {
+    pub fn new(env: E, value: A) -> Self {
+        Env { env, value }
+    }
+
+    pub fn ask(&self) -> &E {
+        &self.env
+    }
+
+    pub fn value(&self) -> &A {
+        &self.value
+    }
+
+    pub fn local<F>(self, f: F) -> Self
+    where
+        F: FnOnce(E) -> E,
+    {
+        Env {
+            env: f(self.env),
+            value: self.value,
+        }
+    }
+}
+
+impl<E: Clone, A: Clone> Comonad for Env<E, A> {
+    type Elm = A;
+    type M<B: Clone> = Env<E, B>;
+
+    fn extract(&self) -> A {
+        self.value.clone()
+    }
+
+    fn extend<B: Clone + 'static, F>(self, f: F) -> Env<E, B>
+    where
+        F: Fn(&Self) -> B + 'static,
+    {
+        let value = f(&self);
+        Env {
+            env: self.env,
+            value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    mod non_empty_vec {
+        use crate::{Comonad, NonEmptyVec};
+
+        fn sum_window(w: &NonEmptyVec<i64>) -> i64 {
+            w.iter().take(2).sum()
+        }
+
+        fn size(w: &NonEmptyVec<i64>) -> i64 {
+            w.len() as i64
+        }
+
+        #[quickcheck]
+        fn comonad_left_identity(head: i64, tail: Vec<i64>) -> bool {
+            let w = NonEmptyVec::new(head, tail);
+            w.clone().extend(|w: &NonEmptyVec<i64>| w.extract()) == w
+        }
+
+        #[quickcheck]
+        fn comonad_right_identity(head: i64, tail: Vec<i64>) -> bool {
+            let w = NonEmptyVec::new(head, tail);
+            w.clone().extend(sum_window).extract() == sum_window(&w)
+        }
+
+        #[quickcheck]
+        fn comonad_associativity(head: i64, tail: Vec<i64>) -> bool {
+            let w = NonEmptyVec::new(head, tail);
+            w.clone().extend(sum_window).extend(size)
+                == w.extend(|w: &NonEmptyVec<i64>| size(&w.clone().extend(sum_window)))
+        }
+
+        #[quickcheck]
+        fn duplicate_extract(head: i64, tail: Vec<i64>) -> bool {
+            let w = NonEmptyVec::new(head, tail);
+            w.clone().duplicate().extract() == w
+        }
+    }
+
+    mod store {
+        use crate::{Comonad, Store};
+
+        fn store(pos: i32) -> Store<i32, i64> {
+            Store::new(|s: &i32| (*s as i64) * 3 + 1, pos)
+        }
+
+        fn neighbours(w: &Store<i32, i64>) -> i64 {
+            w.peeks(|s| s.wrapping_sub(1)) + w.peeks(|s| s.wrapping_add(1))
+        }
+
+        fn doubled(w: &Store<i32, i64>) -> i64 {
+            w.extract() * 2
+        }
+
+        #[quickcheck]
+        fn comonad_left_identity(pos: i32, probe: i32) -> bool {
+            let w = store(pos);
+            let extended = w.clone().extend(|w: &Store<i32, i64>| w.extract());
+            extended.extract() == w.extract() && extended.peek(&probe) == w.peek(&probe)
+        }
+
+        #[quickcheck]
+        fn comonad_right_identity(pos: i32) -> bool {
+            let w = store(pos);
+            w.clone().extend(neighbours).extract() == neighbours(&w)
+        }
+
+        #[quickcheck]
+        fn comonad_associativity(pos: i32, probe: i32) -> bool {
+            let w = store(pos);
+            let lhs = w.clone().extend(neighbours).extend(doubled);
+            let rhs = w.extend(|w: &Store<i32, i64>| doubled(&w.clone().extend(neighbours)));
+            lhs.extract() == rhs.extract() && lhs.peek(&probe) == rhs.peek(&probe)
+        }
+    }
+
+    mod env {
+        use crate::{Comonad, Env};
+
+        fn scaled(w: &Env<i64, i64>) -> i64 {
+            w.extract().wrapping_mul(*w.ask())
+        }
+
+        fn offset(w: &Env<i64, i64>) -> i64 {
+            w.extract().wrapping_add(*w.ask())
+        }
+
+        #[quickcheck]
+        fn comonad_left_identity(e: i64, a: i64) -> bool {
+            let w = Env::new(e, a);
+            w.clone().extend(|w: &Env<i64, i64>| w.extract()) == w
+        }
+
+        #[quickcheck]
+        fn comonad_right_identity(e: i64, a: i64) -> bool {
+            let w = Env::new(e, a);
+            w.clone().extend(scaled).extract() == scaled(&w)
+        }
+
+        #[quickcheck]
+        fn comonad_associativity(e: i64, a: i64) -> bool {
+            let w = Env::new(e, a);
+            w.clone().extend(scaled).extend(offset)
+                == w.extend(|w: &Env<i64, i64>| offset(&w.clone().extend(scaled)))
+        }
+    }
+}
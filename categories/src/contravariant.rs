@@ -0,0 +1,243 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::rc::Rc;
+
+/// Contravariantは、入力側の型を変換する型クラスです。
+///
+/// Functorが出力（コンテナ内の値）を変換するのに対し、Contravariantは入力を変換します。
+/// 述語や比較関数のように「値を消費する」型に対して、前段に変換関数を挟むことで、
+/// 別の型の値を扱えるようにします。
+///
+/// # 型クラス階層における位置
+///
+/// Contravariantは独立した型クラスであり、他の型クラスとの直接的な階層関係はありません。
+///
+/// # Contravariantの法則
+///
+/// 1. 恒等関数を適用しても振る舞いは変わらない（恒等法則）
+///    ```rust,ignore
+///    x.contramap(|a| a.clone()) == x
+///    ```
+///
+/// 2. 関数の合成は、個別に適用した結果と同じ（合成法則）
+///    ```rust,ignore
+///    x.contramap(f).contramap(g) == x.contramap(|c| f(&g(c)))
+///    ```
+///
+/// # 型パラメータ
+///
+/// * `Elm` - 消費される値の型
+/// * `M<B>` - 変換後の型（Bは新しい入力の型）
+///
+/// # メソッド
+///
+/// * `contramap` - 入力に関数を適用してから、元の処理に渡す新しい値を返す
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{Comparator, Contravariant};
+/// use std::cmp::Ordering;
+///
+/// struct User {
+///     name: String,
+/// }
+///
+/// let by_string: Comparator<String> = Comparator::new(|a: &String, b: &String| a.cmp(b));
+/// let by_name: Comparator<User> = by_string.contramap(|u: &User| u.name.clone());
+///
+/// let alice = User { name: "alice".to_string() };
+/// let bob = User { name: "bob".to_string() };
+/// assert_eq!(by_name.compare(&alice, &bob), Ordering::Less);
+/// ```
+pub trait Contravariant {
+    type Elm;
+    type M<B: 'static>;
+
+    fn contramap<B: 'static, F>(self, f: F) -> Self::M<B>
+    where
+        F: Fn(&B) -> Self::Elm + 'static;
+}
+
+/// 値が条件を満たすかどうかを判定する述語です。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{Contravariant, Predicate};
+///
+/// let is_even = Predicate::new(|n: &i32| n % 2 == 0);
+/// let has_even_length = is_even.contramap(|s: &String| s.len() as i32);
+///
+/// assert!(has_even_length.test(&"ab".to_string()));
+/// assert!(!has_even_length.test(&"abc".to_string()));
+/// ```
+pub struct Predicate<A> {
+    f: Rc<dyn Fn(&A) -> bool>,
+}
+
+impl<A: 'static> Predicate<A> {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&A) -> bool + 'static,
+    {
+        Predicate { f: Rc::new(f) }
+    }
+
+    pub fn test(&self, value: &A) -> bool {
+        (self.f)(value)
+    }
+
+    pub fn and(self, other: Self) -> Self {
+        Predicate::new(move |a| self.test(a) && other.test(a))
+    }
+
+    pub fn or(self, other: Self) -> Self {
+        Predicate::new(move |a| self.test(a) || other.test(a))
+    }
+
+    pub fn negate(self) -> Self {
+        Predicate::new(move |a| !self.test(a))
+    }
+}
+
+impl<A> Clone for Predicate<A> {
+    fn clone(&self) -> Self {
+        Predicate {
+            f: Rc::clone(&self.f),
+        }
+    }
+}
+
+impl<A> fmt::Debug for Predicate<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Predicate")
+    }
+}
+
+impl<A: 'static> Contravariant for Predicate<A> {
+    type Elm = A;
+    type M<B: 'static> = Predicate<B>;
+
+    fn contramap<B: 'static, F>(self, f: F) -> Predicate<B>
+    where
+        F: Fn(&B) -> A + 'static,
+    {
+        Predicate::new(move |b| self.test(&f(b)))
+    }
+}
+
+/// 2つの値の順序を判定する比較関数です。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::Comparator;
+/// use std::cmp::Ordering;
+///
+/// let cmp = Comparator::new(|a: &i32, b: &i32| a.cmp(b));
+/// assert_eq!(cmp.compare(&1, &2), Ordering::Less);
+///
+/// let mut values = vec![3, 1, 2];
+/// values.sort_by(|a, b| cmp.compare(a, b));
+/// assert_eq!(values, vec![1, 2, 3]);
+/// ```
+pub struct Comparator<A> {
+    f: CompareFn<A>,
+}
+
+type CompareFn<A> = Rc<dyn Fn(&A, &A) -> Ordering>;
+
+impl<A: 'static> Comparator<A> {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&A, &A) -> Ordering + 'static,
+    {
+        Comparator { f: Rc::new(f) }
+    }
+
+    pub fn compare(&self, x: &A, y: &A) -> Ordering {
+        (self.f)(x, y)
+    }
+}
+
+impl<A> Clone for Comparator<A> {
+    fn clone(&self) -> Self {
+        Comparator {
+            f: Rc::clone(&self.f),
+        }
+    }
+}
+
+impl<A> fmt::Debug for Comparator<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Comparator")
+    }
+}
+
+impl<A: 'static> Contravariant for Comparator<A> {
+    type Elm = A;
+    type M<B: 'static> = Comparator<B>;
+
+    fn contramap<B: 'static, F>(self, f: F) -> Comparator<B>
+    where
+        F: Fn(&B) -> A + 'static,
+    {
+        Comparator::new(move |x, y| self.compare(&f(x), &f(y)))
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    mod predicate {
+        use crate::{Contravariant, Predicate};
+
+        fn is_positive() -> Predicate<i32> {
+            Predicate::new(|n: &i32| *n > 0)
+        }
+
+        #[quickcheck]
+        fn contravariant_identity(n: i32) -> bool {
+            is_positive().contramap(|x: &i32| *x).test(&n) == is_positive().test(&n)
+        }
+
+        #[quickcheck]
+        fn contravariant_composition(n: i64) -> bool {
+            let f: fn(&i64) -> i32 = |x| (*x % 1000) as i32;
+            let g: fn(&i64) -> i64 = |x| *x - 7;
+            is_positive().contramap(f).contramap(g).test(&n)
+                == is_positive().contramap(move |x: &i64| f(&g(x))).test(&n)
+        }
+
+        #[quickcheck]
+        fn and_or_negate(n: i32) -> bool {
+            let is_even = Predicate::new(|n: &i32| n % 2 == 0);
+            is_positive().and(is_even.clone()).test(&n) == (n > 0 && n % 2 == 0)
+                && is_positive().or(is_even.clone()).test(&n) == (n > 0 || n % 2 == 0)
+                && is_even.negate().test(&n) == (n % 2 != 0)
+        }
+    }
+
+    mod comparator {
+        use crate::{Comparator, Contravariant};
+
+        fn natural() -> Comparator<i32> {
+            Comparator::new(|a: &i32, b: &i32| a.cmp(b))
+        }
+
+        #[quickcheck]
+        fn contravariant_identity(a: i32, b: i32) -> bool {
+            natural().contramap(|x: &i32| *x).compare(&a, &b) == natural().compare(&a, &b)
+        }
+
+        #[quickcheck]
+        fn contravariant_composition(a: String, b: String) -> bool {
+            let f: fn(&usize) -> i32 = |x| -(*x as i32);
+            let g: fn(&String) -> usize = |s| s.len();
+            natural().contramap(f).contramap(g).compare(&a, &b)
+                == natural()
+                    .contramap(move |s: &String| f(&g(s)))
+                    .compare(&a, &b)
+        }
+    }
+}
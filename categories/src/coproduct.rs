@@ -0,0 +1,271 @@
+use std::fmt::Display;
+
+use crate::{HCons, HList, HNil, Here, Poly, There};
+
+/// Coproductは、いずれか1つの型の値を持つヘテロジニアスな直和型です。
+///
+/// HListが「すべての要素を持つ」積であるのに対し、Coproductは「どれか1つの要素を持つ」和を表します。
+/// `Inl(h)`は先頭の型`H`の値を、`Inr(t)`は残りの型のCoproduct`T`の値を持ちます。
+/// 終端の`CNil`は値を持たない型です。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{Coprod, Coproduct};
+///
+/// type IntOrStr = Coprod!(i32, &'static str);
+///
+/// let value = IntOrStr::inject("hello");
+/// assert_eq!(value.get::<&str, _>(), Some(&"hello"));
+/// assert_eq!(value.get::<i32, _>(), None);
+///
+/// let len = value.fold(rust_fp_categories::hlist![|i: i32| i as usize, |s: &str| s.len()]);
+/// assert_eq!(len, 5);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Coproduct<H, T> {
+    /// 先頭の型の値
+    Inl(H),
+    /// 残りの型の値
+    Inr(T),
+}
+
+/// 空のCoproductを表す型です。値を持たないため、構築することはできません。
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum CNil {}
+
+impl CNil {
+    /// 値が存在しないことを利用して、任意の型の値を返します。
+    pub fn absurd<A>(self) -> A {
+        match self {}
+    }
+}
+
+impl<H, T> Coproduct<H, T> {
+    /// 型`U`の値を、その型の位置に注入したCoproductを作成します。
+    ///
+    /// 位置`I`は型推論で決まります。同じ型が複数含まれる場合は推論できません。
+    pub fn inject<U, I>(value: U) -> Self
+    where
+        Self: CoprodInjector<U, I>,
+    {
+        CoprodInjector::inject(value)
+    }
+
+    /// 型`U`の値を持っていれば、その参照を返します。
+    pub fn get<U, I>(&self) -> Option<&U>
+    where
+        Self: CoprodSelector<U, I>,
+    {
+        CoprodSelector::get(self)
+    }
+
+    /// 型`U`の値を持っていれば、その値を返します。
+    pub fn take<U, I>(self) -> Option<U>
+    where
+        Self: CoprodSelector<U, I>,
+    {
+        CoprodSelector::take(self)
+    }
+
+    /// 型ごとの関数を並べたHListで畳み込みます。関数は型の並びと同じ順に指定します。
+    pub fn fold<Folder, R>(self, folder: Folder) -> R
+    where
+        Self: CoproductFoldable<Folder, R>,
+    {
+        CoproductFoldable::fold(self, folder)
+    }
+
+    /// すべての型の値を同じ型`R`に写す`Poly`で畳み込みます。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use rust_fp_categories::{Coprod, Coproduct, ShowPoly};
+    ///
+    /// let value = <Coprod!(i32, bool)>::inject(true);
+    /// assert_eq!(value.fold_poly(ShowPoly), "true");
+    /// ```
+    pub fn fold_poly<P, R>(self, p: P) -> R
+    where
+        Self: CoproductPolyFoldable<P, R>,
+    {
+        CoproductPolyFoldable::fold_poly(self, &p)
+    }
+}
+
+/// 位置`I`に型`T`の値を注入する型クラスです。
+pub trait CoprodInjector<T, I> {
+    fn inject(value: T) -> Self;
+}
+
+impl<H, T> CoprodInjector<H, Here> for Coproduct<H, T> {
+    fn inject(value: H) -> Self {
+        Coproduct::Inl(value)
+    }
+}
+
+impl<H, T: CoprodInjector<U, I>, U, I> CoprodInjector<U, There<I>> for Coproduct<H, T> {
+    fn inject(value: U) -> Self {
+        Coproduct::Inr(T::inject(value))
+    }
+}
+
+/// 位置`I`にある型`T`の値を取り出す型クラスです。
+pub trait CoprodSelector<T, I> {
+    fn get(&self) -> Option<&T>;
+
+    fn take(self) -> Option<T>;
+}
+
+impl<H, T> CoprodSelector<H, Here> for Coproduct<H, T> {
+    fn get(&self) -> Option<&H> {
+        match self {
+            Coproduct::Inl(h) => Some(h),
+            Coproduct::Inr(_) => None,
+        }
+    }
+
+    fn take(self) -> Option<H> {
+        match self {
+            Coproduct::Inl(h) => Some(h),
+            Coproduct::Inr(_) => None,
+        }
+    }
+}
+
+impl<H, T: CoprodSelector<U, I>, U, I> CoprodSelector<U, There<I>> for Coproduct<H, T> {
+    fn get(&self) -> Option<&U> {
+        match self {
+            Coproduct::Inl(_) => None,
+            Coproduct::Inr(t) => t.get(),
+        }
+    }
+
+    fn take(self) -> Option<U> {
+        match self {
+            Coproduct::Inl(_) => None,
+            Coproduct::Inr(t) => t.take(),
+        }
+    }
+}
+
+/// 型ごとの関数を並べたHList`Folder`で、Coproductを畳み込む型クラスです。
+pub trait CoproductFoldable<Folder, R> {
+    fn fold(self, folder: Folder) -> R;
+}
+
+impl<R> CoproductFoldable<HNil, R> for CNil {
+    fn fold(self, _: HNil) -> R {
+        self.absurd()
+    }
+}
+
+impl<H, T, F, FTail, R> CoproductFoldable<HCons<F, FTail>, R> for Coproduct<H, T>
+where
+    F: FnOnce(H) -> R,
+    FTail: HList,
+    T: CoproductFoldable<FTail, R>,
+{
+    fn fold(self, folder: HCons<F, FTail>) -> R {
+        match self {
+            Coproduct::Inl(h) => (folder.head)(h),
+            Coproduct::Inr(t) => t.fold(folder.tail),
+        }
+    }
+}
+
+/// `Poly`でCoproductを畳み込む型クラスです。
+pub trait CoproductPolyFoldable<P, R> {
+    fn fold_poly(self, p: &P) -> R;
+}
+
+impl<P, R> CoproductPolyFoldable<P, R> for CNil {
+    fn fold_poly(self, _: &P) -> R {
+        self.absurd()
+    }
+}
+
+impl<H, T, P, R> CoproductPolyFoldable<P, R> for Coproduct<H, T>
+where
+    P: Poly<H, Output = R>,
+    T: CoproductPolyFoldable<P, R>,
+{
+    fn fold_poly(self, p: &P) -> R {
+        match self {
+            Coproduct::Inl(h) => p.call(h),
+            Coproduct::Inr(t) => t.fold_poly(p),
+        }
+    }
+}
+
+impl<H: Display, T: Display> Display for Coproduct<H, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Coproduct::Inl(h) => write!(f, "{}", h),
+            Coproduct::Inr(t) => write!(f, "{}", t),
+        }
+    }
+}
+
+impl Display for CNil {
+    fn fmt(&self, _: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {}
+    }
+}
+
+/// Coproductの型を表すマクロです。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{CNil, Coprod, Coproduct};
+///
+/// let value: Coprod!(i32, bool) = Coproduct::Inr(Coproduct::Inl(true));
+/// let same: Coproduct<i32, Coproduct<bool, CNil>> = value;
+/// assert_eq!(same.take::<bool, _>(), Some(true));
+/// ```
+#[macro_export]
+macro_rules! Coprod {
+    () => { $crate::CNil };
+    ($x:ty) => { $crate::Coproduct<$x, $crate::CNil> };
+    ($x:ty, $($y:ty),*) => { $crate::Coproduct<$x, $crate::Coprod!($($y),*)> };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hlist, ShowPoly};
+
+    type Value = Coprod!(i32, String, bool);
+
+    #[test]
+    fn test_inject_and_select() {
+        let value = Value::inject("abc".to_string());
+        assert_eq!(value, Coproduct::Inr(Coproduct::Inl("abc".to_string())));
+        assert_eq!(value.get::<String, _>(), Some(&"abc".to_string()));
+        assert_eq!(value.get::<i32, _>(), None);
+        assert_eq!(value.clone().take::<bool, _>(), None);
+        assert_eq!(value.take::<String, _>(), Some("abc".to_string()));
+    }
+
+    #[test]
+    fn test_fold() {
+        let describe = |value: Value| {
+            value.fold(hlist![
+                |i: i32| format!("int {}", i),
+                |s: String| format!("string {}", s),
+                |b: bool| format!("bool {}", b),
+            ])
+        };
+        assert_eq!(describe(Value::inject(1)), "int 1");
+        assert_eq!(describe(Value::inject("a".to_string())), "string a");
+        assert_eq!(describe(Value::inject(false)), "bool false");
+    }
+
+    #[test]
+    fn test_fold_poly_and_display() {
+        assert_eq!(Value::inject(42).fold_poly(ShowPoly), "42");
+        assert_eq!(format!("{}", Value::inject(true)), "true");
+    }
+}
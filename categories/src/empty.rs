@@ -1,3 +1,7 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+
 pub trait Empty {
     fn empty() -> Self;
     fn is_empty(&self) -> bool;
@@ -25,3 +29,51 @@ impl Empty for String {
         self.len() == 0
     }
 }
+
+impl Empty for Ordering {
+    fn empty() -> Ordering {
+        Ordering::Equal
+    }
+    fn is_empty(&self) -> bool {
+        *self == Ordering::Equal
+    }
+}
+
+impl<T> Empty for Option<T> {
+    fn empty() -> Option<T> {
+        None
+    }
+    fn is_empty(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl<A, E: Empty> Empty for Result<A, E> {
+    fn empty() -> Result<A, E> {
+        Err(E::empty())
+    }
+    fn is_empty(&self) -> bool {
+        match self {
+            Ok(_) => false,
+            Err(e) => e.is_empty(),
+        }
+    }
+}
+
+impl<K, V, S: BuildHasher + Default> Empty for HashMap<K, V, S> {
+    fn empty() -> HashMap<K, V, S> {
+        HashMap::default()
+    }
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K, V> Empty for BTreeMap<K, V> {
+    fn empty() -> BTreeMap<K, V> {
+        BTreeMap::new()
+    }
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,159 @@
+//! 等価性を、型に対する実装ではなく明示的なインスタンス値として扱う型クラスです。
+//!
+//! 標準ライブラリの`Eq`トレイトと名前が重なるため、このモジュールはクレートのルートには
+//! 再エクスポートしていません。`rust_fp_categories::eq::Eq`として参照してください。
+
+use std::fmt;
+use std::rc::Rc;
+
+use crate::Contravariant;
+
+/// 2つの値が等しいかどうかを判定するインスタンスです。
+///
+/// 1つの型に対して、用途に応じた複数の等価性を値として使い分けることができます。
+///
+/// # Eqの法則
+///
+/// 1. 反射律: `eq.eqv(a, a) == true`
+/// 2. 対称律: `eq.eqv(a, b) == eq.eqv(b, a)`
+/// 3. 推移律: `eq.eqv(a, b) && eq.eqv(b, c)`ならば`eq.eqv(a, c) == true`
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::eq::Eq;
+///
+/// struct User {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// let by_id = Eq::by(|u: &User| u.id);
+/// let a = User { id: 1, name: "alice".to_string() };
+/// let b = User { id: 1, name: "ALICE".to_string() };
+/// assert!(by_id.eqv(&a, &b));
+///
+/// let names = Eq::by(|s: &String| s.to_lowercase())
+///     .distinct(vec!["a".to_string(), "B".to_string(), "A".to_string(), "b".to_string()]);
+/// assert_eq!(names, vec!["a".to_string(), "B".to_string()]);
+/// ```
+pub struct Eq<A> {
+    f: EqFn<A>,
+}
+
+type EqFn<A> = Rc<dyn Fn(&A, &A) -> bool>;
+
+impl<A: 'static> Eq<A> {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&A, &A) -> bool + 'static,
+    {
+        Eq { f: Rc::new(f) }
+    }
+
+    /// `PartialEq`による等価性を使うインスタンスを作成します。
+    pub fn natural() -> Self
+    where
+        A: PartialEq,
+    {
+        Eq::new(|x: &A, y: &A| x == y)
+    }
+
+    /// 値から取り出したキーの等価性で比較するインスタンスを作成します。
+    pub fn by<B, F>(key: F) -> Self
+    where
+        B: PartialEq,
+        F: Fn(&A) -> B + 'static,
+    {
+        Eq::new(move |x, y| key(x) == key(y))
+    }
+
+    pub fn eqv(&self, x: &A, y: &A) -> bool {
+        (self.f)(x, y)
+    }
+
+    pub fn neqv(&self, x: &A, y: &A) -> bool {
+        !self.eqv(x, y)
+    }
+
+    /// 両方のインスタンスで等しい場合にのみ等しいとみなすインスタンスを返します。
+    pub fn and(self, other: Self) -> Self {
+        Eq::new(move |x, y| self.eqv(x, y) && other.eqv(x, y))
+    }
+
+    /// 等しい要素のうち、最初に現れたものだけを残します。
+    pub fn distinct(&self, values: Vec<A>) -> Vec<A> {
+        let mut result: Vec<A> = Vec::with_capacity(values.len());
+        for value in values {
+            if !result.iter().any(|kept| self.eqv(kept, &value)) {
+                result.push(value);
+            }
+        }
+        result
+    }
+}
+
+impl<A> Clone for Eq<A> {
+    fn clone(&self) -> Self {
+        Eq {
+            f: Rc::clone(&self.f),
+        }
+    }
+}
+
+impl<A> fmt::Debug for Eq<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Eq")
+    }
+}
+
+impl<A: 'static> Contravariant for Eq<A> {
+    type Elm = A;
+    type M<B: 'static> = Eq<B>;
+
+    fn contramap<B: 'static, F>(self, f: F) -> Eq<B>
+    where
+        F: Fn(&B) -> A + 'static,
+    {
+        Eq::new(move |x, y| self.eqv(&f(x), &f(y)))
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::eq::Eq;
+
+    fn modulo() -> Eq<i32> {
+        Eq::by(|n: &i32| n.rem_euclid(5))
+    }
+
+    #[quickcheck]
+    fn eq_reflexivity(a: i32) -> bool {
+        modulo().eqv(&a, &a)
+    }
+
+    #[quickcheck]
+    fn eq_symmetry(a: i32, b: i32) -> bool {
+        modulo().eqv(&a, &b) == modulo().eqv(&b, &a)
+    }
+
+    #[quickcheck]
+    fn eq_transitivity(a: i32, b: i32, c: i32) -> bool {
+        let eq = modulo();
+        !(eq.eqv(&a, &b) && eq.eqv(&b, &c)) || eq.eqv(&a, &c)
+    }
+
+    #[quickcheck]
+    fn natural_agrees_with_partial_eq(a: i32, b: i32) -> bool {
+        Eq::natural().eqv(&a, &b) == (a == b)
+    }
+
+    #[quickcheck]
+    fn distinct_keeps_first_occurrences(values: Vec<i32>) -> bool {
+        let distinct = modulo().distinct(values.clone());
+        distinct.len() <= 5
+            && values
+                .iter()
+                .all(|v| distinct.iter().filter(|d| modulo().eqv(d, v)).count() == 1)
+    }
+}
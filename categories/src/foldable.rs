@@ -1,3 +1,44 @@
+use std::cmp::Ordering;
+use std::ops::ControlFlow;
+
+use crate::Monoid;
+
+/// Foldable型クラスは、要素を順に畳み込んで1つの値にまとめられるデータ構造を表します。
+///
+/// 実装が必要なのは`fold_left`と`fold_right`だけで、それ以外のメソッドはこの2つから導出されます。
+///
+/// # 途中で止まる畳み込み
+///
+/// `try_fold`、`fold_while`、`exists`、`for_all`、`find`は、結果が確定した時点で`f`の呼び出しを止めます。
+/// 既定の実装は`fold_left`を使うため、残りの要素の走査自体は続きます。
+/// 走査を途中で止められるデータ構造では、`try_fold`を上書きしてください。
+/// 同様に、要素数を直接持つデータ構造では`length`を上書きできます。
+///
+/// # 例
+///
+/// ```
+/// use std::ops::ControlFlow;
+/// use rust_fp_categories::{Foldable, Max, Sum};
+///
+/// let latencies = vec![120, 80, 200, 95];
+///
+/// let (total, slowest) = latencies.fold_map(|ms| (Sum(*ms), Max(*ms)));
+/// assert_eq!((total, slowest), (Sum(495), Max(200)));
+///
+/// assert_eq!(latencies.length(), 4);
+/// assert!(latencies.exists(|ms| *ms > 150));
+/// assert_eq!(latencies.find(|ms| *ms < 100), Some(80));
+///
+/// // 合計が200を超えた時点で止める
+/// let budget = latencies.fold_while(0, |acc, ms| {
+///     if acc + ms > 200 {
+///         ControlFlow::Break(acc)
+///     } else {
+///         ControlFlow::Continue(acc + ms)
+///     }
+/// });
+/// assert_eq!(budget, 200);
+/// ```
 pub trait Foldable: Sized {
     type Elm;
 
@@ -8,6 +49,168 @@ pub trait Foldable: Sized {
     fn fold_right<B, F>(&self, b: B, f: F) -> B
     where
         F: Fn(&Self::Elm, B) -> B;
+
+    /// 各要素を`f`で`Monoid`に写し、左から順に結合します。
+    fn fold_map<M, F>(&self, f: F) -> M
+    where
+        M: Monoid,
+        F: Fn(&Self::Elm) -> M,
+    {
+        self.fold_left(M::empty(), |acc, a| acc.combine(f(a)))
+    }
+
+    /// すべての要素を左から順に結合します。空の場合は単位元を返します。
+    fn combine_all(&self) -> Self::Elm
+    where
+        Self::Elm: Monoid + Clone,
+    {
+        self.fold_map(|a| a.clone())
+    }
+
+    /// `f`が`ControlFlow::Break`を返した時点で畳み込みを止め、その値を返します。
+    fn try_fold<B, C, F>(&self, b: B, f: F) -> ControlFlow<C, B>
+    where
+        F: Fn(B, &Self::Elm) -> ControlFlow<C, B>,
+    {
+        self.fold_left(ControlFlow::Continue(b), |acc, a| match acc {
+            ControlFlow::Continue(b) => f(b, a),
+            ControlFlow::Break(c) => ControlFlow::Break(c),
+        })
+    }
+
+    /// `f`が`ControlFlow::Break`を返すまで畳み込みます。
+    /// `Break`と`Continue`のどちらで終わった場合も、その時点の値を返します。
+    fn fold_while<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(B, &Self::Elm) -> ControlFlow<B, B>,
+    {
+        match self.try_fold(b, f) {
+            ControlFlow::Continue(b) | ControlFlow::Break(b) => b,
+        }
+    }
+
+    /// 右からの畳み込みを、必要な分だけ評価します。
+    ///
+    /// `f`は要素と、残りの要素を畳み込んだ結果`Later`を受け取ります。
+    /// `Later::value`を呼ばなければ、それより右の要素は評価されません。
+    /// 評価した要素数に比例してスタックを使います。
+    fn fold_right_lazy<B, F>(&self, b: B, f: F) -> B
+    where
+        Self::Elm: Clone,
+        F: Fn(&Self::Elm, Later<'_, B>) -> B,
+    {
+        fold_right_lazy_slice(&self.to_vec(), b, &f)
+    }
+
+    fn length(&self) -> usize {
+        self.fold_left(0, |n, _| n + 1)
+    }
+
+    /// `p`を満たす要素が1つでもあれば`true`を返します。
+    fn exists<P>(&self, p: P) -> bool
+    where
+        P: Fn(&Self::Elm) -> bool,
+    {
+        self.try_fold((), |_, a| {
+            if p(a) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .is_break()
+    }
+
+    /// すべての要素が`p`を満たせば`true`を返します。空の場合は`true`です。
+    fn for_all<P>(&self, p: P) -> bool
+    where
+        P: Fn(&Self::Elm) -> bool,
+    {
+        !self.exists(|a| !p(a))
+    }
+
+    /// `p`を満たす最初の要素を返します。
+    fn find<P>(&self, p: P) -> Option<Self::Elm>
+    where
+        Self::Elm: Clone,
+        P: Fn(&Self::Elm) -> bool,
+    {
+        match self.try_fold((), |_, a| {
+            if p(a) {
+                ControlFlow::Break(a.clone())
+            } else {
+                ControlFlow::Continue(())
+            }
+        }) {
+            ControlFlow::Break(a) => Some(a),
+            ControlFlow::Continue(()) => None,
+        }
+    }
+
+    fn to_vec(&self) -> Vec<Self::Elm>
+    where
+        Self::Elm: Clone,
+    {
+        self.fold_left(Vec::new(), |mut acc, a| {
+            acc.push(a.clone());
+            acc
+        })
+    }
+
+    /// `f`による順序で最小の要素を返します。最小の要素が複数ある場合は最初のものを返します。
+    fn min_by<F>(&self, f: F) -> Option<Self::Elm>
+    where
+        Self::Elm: Clone,
+        F: Fn(&Self::Elm, &Self::Elm) -> Ordering,
+    {
+        self.fold_left(None, |acc, a| match acc {
+            Some(min) if f(&min, a) != Ordering::Greater => Some(min),
+            _ => Some(a.clone()),
+        })
+    }
+
+    /// `f`による順序で最大の要素を返します。最大の要素が複数ある場合は最後のものを返します。
+    fn max_by<F>(&self, f: F) -> Option<Self::Elm>
+    where
+        Self::Elm: Clone,
+        F: Fn(&Self::Elm, &Self::Elm) -> Ordering,
+    {
+        self.fold_left(None, |acc, a| match acc {
+            Some(max) if f(&max, a) == Ordering::Greater => Some(max),
+            _ => Some(a.clone()),
+        })
+    }
+}
+
+/// `fold_right_lazy`で、まだ評価していない残りの畳み込みを表します。
+pub struct Later<'a, B> {
+    next: &'a mut dyn FnMut() -> B,
+}
+
+impl<B> Later<'_, B> {
+    /// 残りの要素を畳み込んだ結果を評価します。
+    pub fn value(self) -> B {
+        (self.next)()
+    }
+}
+
+fn fold_right_lazy_slice<A, B, F>(items: &[A], b: B, f: &F) -> B
+where
+    F: Fn(&A, Later<'_, B>) -> B,
+{
+    fn go<A, B, F>(items: &[A], b: &mut Option<B>, f: &F) -> B
+    where
+        F: Fn(&A, Later<'_, B>) -> B,
+    {
+        match items.split_first() {
+            None => b.take().expect("the initial value must be used only once"),
+            Some((head, tail)) => {
+                let mut next = || go(tail, b, f);
+                f(head, Later { next: &mut next })
+            }
+        }
+    }
+    go(items, &mut Some(b), f)
 }
 
 impl<A> Foldable for Vec<A> {
@@ -26,4 +229,262 @@ impl<A> Foldable for Vec<A> {
     {
         self.iter().rev().fold(b, |x, y| f(y, x))
     }
+
+    fn try_fold<B, C, F>(&self, b: B, f: F) -> ControlFlow<C, B>
+    where
+        F: Fn(B, &Self::Elm) -> ControlFlow<C, B>,
+    {
+        self.iter().try_fold(b, f)
+    }
+
+    fn fold_right_lazy<B, F>(&self, b: B, f: F) -> B
+    where
+        Self::Elm: Clone,
+        F: Fn(&Self::Elm, Later<'_, B>) -> B,
+    {
+        fold_right_lazy_slice(self, b, &f)
+    }
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+/// `Option<A>`に対するFoldableの実装
+///
+/// `Some`の場合は1つの要素を、`None`の場合は空のコンテナとして畳み込みます。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{Foldable, Sum};
+///
+/// assert_eq!(Some(3).fold_map(|n| Sum(*n)), Sum(3));
+/// assert_eq!(None::<i32>.length(), 0);
+/// ```
+impl<A> Foldable for Option<A> {
+    type Elm = A;
+
+    fn fold_left<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(B, &Self::Elm) -> B,
+    {
+        match self {
+            Some(a) => f(b, a),
+            None => b,
+        }
+    }
+
+    fn fold_right<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(&Self::Elm, B) -> B,
+    {
+        match self {
+            Some(a) => f(a, b),
+            None => b,
+        }
+    }
+
+    fn length(&self) -> usize {
+        if self.is_some() {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use std::ops::ControlFlow;
+
+    use crate::{Foldable, Max, Min, NonEmptyVec, Sum};
+
+    #[quickcheck]
+    fn fold_map_is_fold_left(values: Vec<i64>) -> bool {
+        values.fold_map(|n| Sum(*n)) == Sum(values.fold_left(0, |acc, n| acc + n))
+    }
+
+    #[quickcheck]
+    fn combine_all_is_fold_map(values: Vec<String>) -> bool {
+        values.combine_all() == values.fold_map(|s| s.clone())
+    }
+
+    #[quickcheck]
+    fn derived_queries_agree_with_iterator(values: Vec<i32>) -> bool {
+        values.length() == values.len()
+            && values.exists(|n| n % 3 == 0) == values.iter().any(|n| n % 3 == 0)
+            && values.for_all(|n| *n > 0) == values.iter().all(|n| *n > 0)
+            && values.find(|n| n % 3 == 0) == values.iter().copied().find(|n| n % 3 == 0)
+            && values.min_by(|a, b| a.cmp(b)) == values.iter().copied().min()
+            && values.max_by(|a, b| a.cmp(b)) == values.iter().copied().max()
+            && values.fold_map(|n| Min(*n))
+                == values.min_by(|a, b| a.cmp(b)).map_or(Min(i32::MAX), Min)
+            && values.fold_map(|n| Max(*n))
+                == values.max_by(|a, b| a.cmp(b)).map_or(Max(i32::MIN), Max)
+    }
+
+    #[quickcheck]
+    fn default_try_fold_agrees_with_override(head: i32, tail: Vec<i32>, limit: i32) -> bool {
+        let step = |acc: i32, n: &i32| {
+            if *n > limit {
+                ControlFlow::Break(acc)
+            } else {
+                ControlFlow::Continue(acc.wrapping_add(*n))
+            }
+        };
+        let mut values = vec![head];
+        values.extend(tail.clone());
+        // NonEmptyVecはtry_foldを上書きしているので、Vecと結果が一致することを確認する
+        let non_empty = NonEmptyVec::new(head, tail);
+        values.try_fold(0, step) == non_empty.try_fold(0, step)
+            && values.fold_while(0, step) == non_empty.fold_while(0, step)
+    }
+
+    #[quickcheck]
+    fn fold_right_lazy_agrees_with_fold_right(values: Vec<i32>) -> bool {
+        let strict = values.fold_right(Vec::new(), |n, mut acc| {
+            acc.push(*n);
+            acc
+        });
+        let lazy = values.fold_right_lazy(Vec::new(), |n, rest| {
+            let mut acc = rest.value();
+            acc.push(*n);
+            acc
+        });
+        strict == lazy
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::ops::ControlFlow;
+
+    use crate::{Foldable, NonEmptyVec};
+
+    #[derive(crate::Foldable)]
+    struct Rose<A> {
+        value: A,
+        children: Vec<Rose<A>>,
+    }
+
+    #[derive(crate::Foldable)]
+    enum Shape<A> {
+        Point(A),
+        Segment((A, A), &'static str),
+        Polygon(Vec<Box<A>>),
+        Empty,
+    }
+
+    #[test]
+    fn test_derived_foldable() {
+        let rose = Rose {
+            value: 1,
+            children: vec![
+                Rose {
+                    value: 2,
+                    children: vec![],
+                },
+                Rose {
+                    value: 3,
+                    children: vec![Rose {
+                        value: 4,
+                        children: vec![],
+                    }],
+                },
+            ],
+        };
+        assert_eq!(rose.to_vec(), vec![1, 2, 3, 4]);
+        assert_eq!(
+            rose.fold_right(vec![], |a, mut acc| {
+                acc.push(*a);
+                acc
+            }),
+            vec![4, 3, 2, 1]
+        );
+        assert_eq!(rose.length(), 4);
+
+        assert_eq!(Shape::Point(1).to_vec(), vec![1]);
+        assert_eq!(Shape::Segment((1, 2), "s").to_vec(), vec![1, 2]);
+        assert_eq!(
+            Shape::Segment((1, 2), "s").fold_right(0, |a, acc| acc * 10 + a),
+            21
+        );
+        assert_eq!(
+            Shape::Polygon(vec![Box::new(3), Box::new(4), Box::new(5)]).to_vec(),
+            vec![3, 4, 5]
+        );
+        assert!(Shape::<i32>::Empty.to_vec().is_empty());
+    }
+
+    #[derive(Clone)]
+    struct Counted(Vec<i32>);
+
+    // try_foldを上書きしない、既定の実装だけを使うFoldable
+    impl Foldable for Counted {
+        type Elm = i32;
+
+        fn fold_left<B, F>(&self, b: B, f: F) -> B
+        where
+            F: Fn(B, &i32) -> B,
+        {
+            self.0.iter().fold(b, f)
+        }
+
+        fn fold_right<B, F>(&self, b: B, f: F) -> B
+        where
+            F: Fn(&i32, B) -> B,
+        {
+            self.0.iter().rev().fold(b, |b, a| f(a, b))
+        }
+    }
+
+    #[test]
+    fn test_short_circuit_stops_calling_f() {
+        let calls = Cell::new(0);
+        let found = Counted(vec![1, 2, 3, 4, 5]).exists(|n| {
+            calls.set(calls.get() + 1);
+            *n == 2
+        });
+        assert!(found);
+        assert_eq!(calls.get(), 2);
+
+        let calls = Cell::new(0);
+        let result: ControlFlow<&str, i32> = vec![1, 2, 3].try_fold(0, |acc, n| {
+            calls.set(calls.get() + 1);
+            if *n == 2 {
+                ControlFlow::Break("stopped")
+            } else {
+                ControlFlow::Continue(acc + n)
+            }
+        });
+        assert_eq!(result, ControlFlow::Break("stopped"));
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_fold_right_lazy_stops_early() {
+        let calls = Cell::new(0);
+        let first_even = Counted((1..=1000).collect()).fold_right_lazy(None, |n, rest| {
+            calls.set(calls.get() + 1);
+            if n % 2 == 0 {
+                Some(*n)
+            } else {
+                rest.value()
+            }
+        });
+        assert_eq!(first_even, Some(2));
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_non_empty_vec_queries() {
+        let values = NonEmptyVec::new(3, vec![1, 2]);
+        assert_eq!(values.length(), 3);
+        assert_eq!(values.min_by(|a, b| a.cmp(b)), Some(1));
+        assert!(values.for_all(|n| *n > 0));
+        assert_eq!(Foldable::to_vec(&values), vec![3, 1, 2]);
+        assert_eq!(Counted(vec![]).find(|_| true), None);
+    }
 }
@@ -39,13 +39,95 @@
 //! for_yield! マクロは以下の構文をサポートしています：
 //!
 //! - `bind a = expr;` - bind操作（flat_map）。exprはBindを実装している必要があります。
-//! - `let a = expr;` - 変数への代入。
+//! - `bind (a, b) = expr;` - 左辺にはタプルや構造体などの論駁不可能なパターンも書けます。
+//! - `_ = expr;` - 結果を捨てて効果だけを逐次実行します（`bind _ = expr;`と同じです）。
+//! - `if cond;` - ガード。条件が偽の場合は`Empty::empty()`を返します。
+//! - `let a = expr;` - 変数への代入。左辺にはパターンも書けます。
 //! - `yield expr` - 最後の式（結果を返す）。
 //!
+//! bindで束縛した変数はクロージャの引数と同じく参照として渡されるため、
+//! パターンで分解した各変数も参照になります。
+//!
+//! # ガード
+//!
+//! `if cond;`は、`bind _ = guard(cond);`と同じ意味を持ちます。
+//! 結果の型が`Empty`を実装している必要があるため、`Option`や`Vec`などで使用できます。
+//!
+//! ```
+//! use rust_fp_categories::{for_yield, Bind};
+//!
+//! let pairs = for_yield! {
+//!     bind a = vec![1, 2, 3];
+//!     bind b = vec![1, 2, 3];
+//!     if a < b;
+//!     yield vec![(*a, *b)]
+//! };
+//! assert_eq!(pairs, vec![(1, 2), (1, 3), (2, 3)]);
+//! ```
+//!
+//! # move モード
+//!
+//! 先頭に`move;`を書くと、各bindのクロージャを`move`クロージャとして生成します。
+//! `Reader`のように関数を保持し、`'static`なクロージャを必要とするモナドで使用します。
+//!
+//! move モードでは、束縛した変数を後続のクロージャに渡す前に`clone`します。
+//! そのため、束縛される値は`Clone`を実装している必要があります。
+//! また、マクロの外側の変数はクロージャにムーブされます。
+//! 外側の変数を2つ目以降のbindの後で使う場合は、先頭で`let`を使って束縛し直してください。
+//! `let`で束縛した変数も、後続のクロージャに渡す前に`clone`されます。
+//! パターンによるbind、`_ = expr;`、ガードもmove モードで使用でき、
+//! パターンで分解した各変数は複製された値になります。
+//!
+//! ```
+//! use rust_fp_categories::{for_yield, Pure, Reader};
+//!
+//! let program = for_yield! {
+//!     move;
+//!     bind a = Reader::asks(|env: &i32| env + 1);
+//!     bind b = Reader::asks(|env: &i32| env * 2);
+//!     let c = a + b;
+//!     yield Reader::pure(c)
+//! };
+//! assert_eq!(program.run(&10), 31);
+//! ```
+//!
+//! # Applicative モード
+//!
+//! 互いに独立した計算は、[`for_yield_ap!`]で`Apply::ap`を使って結合できます。
+//! `Bind`を必要としないため、`Validated`のようにすべてのエラーを蓄積する型でも使用できます。
+//! 各bindの式は前のbindの結果を参照できず、`yield`にはコンテナに包まない値を書きます。
+//! 束縛される値は`Clone`を実装している必要があり、`yield`では複製した値を所有権ごと使用できます。
+//!
+//! ```
+//! use rust_fp_categories::{for_yield_ap, NonEmptyVec, Validated, ValidatedNev};
+//!
+//! fn non_empty(field: &str, value: &str) -> ValidatedNev<String, String> {
+//!     if value.is_empty() {
+//!         Validated::invalid_nev(format!("{} is empty", field))
+//!     } else {
+//!         Validated::Valid(value.to_string())
+//!     }
+//! }
+//!
+//! let result = for_yield_ap! {
+//!     bind first = non_empty("first", "");
+//!     bind last = non_empty("last", "");
+//!     yield format!("{} {}", first, last)
+//! };
+//! assert_eq!(
+//!     result,
+//!     Validated::Invalid(NonEmptyVec::new(
+//!         "first is empty".to_string(),
+//!         vec!["last is empty".to_string()]
+//!     ))
+//! );
+//! ```
+//!
 //! # 制限事項
 //!
 //! - マクロ内の各式は、セミコロンで区切る必要があります（最後の式を除く）。
-//! - bind操作を使用する場合、左辺は単一の識別子である必要があります。
+//! - bind操作の左辺には、`Some(a)`のような論駁可能なパターンは書けません。
+//! - ガードは最後の式の前に書く必要があります。
 //! - 最後の式は、セミコロンを付けてはいけません。
 
 /// for_yield マクロは、Scalaのfor/yield構文やHaskellのdo記法に似た、
@@ -66,28 +148,192 @@
 /// ```
 #[macro_export]
 macro_rules! for_yield {
-    // 最後の式（結果を返す）
-    (yield $e:expr) => {
+    // move モードの開始
+    (move; $($rest:tt)*) => {
+        $crate::for_yield!(@move [] $($rest)*)
+    };
+
+    // move モードで束縛した変数を複製し直す
+    (@restore) => {};
+    (@restore $v:ident $($rest:tt)*) => {
+        #[allow(unused_variables)]
+        let $v = $v.clone();
+        $crate::for_yield!(@restore $($rest)*);
+    };
+    (@restore ($h:ident, $p:pat) $($rest:tt)*) => {
+        let $h = $h.clone();
+        #[allow(unused_variables)]
+        let $p = $h.clone();
+        $crate::for_yield!(@restore $($rest)*);
+    };
+
+    // move モードの最後の式
+    (@move [$($v:tt)*] yield $e:expr) => {{
+        $crate::for_yield!(@restore $($v)*);
         $e
+    }};
+
+    // move モードのbind操作
+    (@move [$($v:tt)*] bind $i:ident = $e:expr; $($rest:tt)*) => {{
+        $crate::for_yield!(@restore $($v)*);
+        $e.bind(move |$i| {
+            #[allow(unused_variables)]
+            let $i = $i.clone();
+            $crate::for_yield!(@move [$($v)* $i] $($rest)*)
+        })
+    }};
+
+    // move モードのパターンによるbind操作
+    (@move [$($v:tt)*] bind $p:pat = $e:expr; $($rest:tt)*) => {{
+        $crate::for_yield!(@restore $($v)*);
+        $e.bind(move |value| {
+            let value = value.clone();
+            #[allow(unused_variables)]
+            let $p = value.clone();
+            $crate::for_yield!(@move [$($v)* (value, $p)] $($rest)*)
+        })
+    }};
+
+    // move モードの効果の逐次実行
+    (@move [$($v:tt)*] _ = $e:expr; $($rest:tt)*) => {{
+        $crate::for_yield!(@restore $($v)*);
+        $e.bind(move |_| $crate::for_yield!(@move [$($v)*] $($rest)*))
+    }};
+
+    // move モードのガード
+    (@move [$($v:tt)*] if $cond:expr; $($rest:tt)*) => {
+        if $cond {
+            $crate::for_yield!(@move [$($v)*] $($rest)*)
+        } else {
+            $crate::Empty::empty()
+        }
     };
 
-    // 最後の式（自動的にコンテナに包む）
-    ($e:expr) => {
+    // move モードの変数への代入
+    (@move [$($v:tt)*] let $i:ident = $e:expr; $($rest:tt)*) => {{
+        let $i = $e;
+        $crate::for_yield!(@move [$($v)* $i] $($rest)*)
+    }};
+
+    // move モードのパターンによる変数への代入
+    (@move [$($v:tt)*] let $p:pat = $e:expr; $($rest:tt)*) => {{
+        let value = $e;
+        #[allow(unused_variables)]
+        let $p = value.clone();
+        $crate::for_yield!(@move [$($v)* (value, $p)] $($rest)*)
+    }};
+
+    // 最後の式（結果を返す）
+    (yield $e:expr) => {
         $e
     };
 
     // bind操作
-    (bind $i:ident = $e:expr; $($rest:tt)*) => {
-        $e.bind(|$i| for_yield!($($rest)*))
+    (bind $p:pat = $e:expr; $($rest:tt)*) => {
+        $e.bind(|$p| $crate::for_yield!($($rest)*))
+    };
+
+    // 効果の逐次実行（結果を捨てる）
+    (_ = $e:expr; $($rest:tt)*) => {
+        $e.bind(|_| $crate::for_yield!($($rest)*))
+    };
+
+    // ガード（条件が偽の場合は空の値）
+    (if $cond:expr; $($rest:tt)*) => {
+        if $cond {
+            $crate::for_yield!($($rest)*)
+        } else {
+            $crate::Empty::empty()
+        }
     };
 
     // 変数への代入
-    (let $i:ident = $e:expr; $($rest:tt)*) => {
+    (let $p:pat = $e:expr; $($rest:tt)*) => {
         {
-            let $i = $e;
-            for_yield!($($rest)*)
+            let $p = $e;
+            $crate::for_yield!($($rest)*)
         }
     };
+
+    // 最後の式（自動的にコンテナに包む）
+    ($e:expr) => {
+        $e
+    };
+}
+
+/// for_yield_ap マクロは、互いに独立した計算を`Apply::ap`で結合する、for_yieldのApplicative版です。
+///
+/// `Bind`を実装していない`Validated`のような型でも使用でき、`Validated`ではすべてのエラーが蓄積されます。
+/// 各bindの式は前のbindの結果を参照できません。`yield`にはコンテナに包まない値を書きます。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{for_yield_ap, NonEmptyVec, Validated, ValidatedNev};
+///
+/// fn positive(n: i32) -> ValidatedNev<String, i32> {
+///     if n > 0 {
+///         Validated::Valid(n)
+///     } else {
+///         Validated::invalid_nev(format!("{} is not positive", n))
+///     }
+/// }
+///
+/// let result = for_yield_ap! {
+///     bind width = positive(3);
+///     bind height = positive(4);
+///     yield width * height
+/// };
+/// assert_eq!(result, Validated::Valid(12));
+///
+/// let result = for_yield_ap! {
+///     bind width = positive(-3);
+///     bind height = positive(-4);
+///     yield width * height
+/// };
+/// assert_eq!(
+///     result,
+///     Validated::Invalid(NonEmptyVec::new(
+///         "-3 is not positive".to_string(),
+///         vec!["-4 is not positive".to_string()]
+///     ))
+/// );
+/// ```
+#[macro_export]
+macro_rules! for_yield_ap {
+    // 最初のbind
+    (bind $p:pat = $e:expr; $($rest:tt)*) => {
+        $crate::for_yield_ap!(@product [$e] [$p] $($rest)*)
+    };
+
+    // これまでの結果と次のbindの結果を組にする
+    (@product [$acc:expr] [$($ps:tt)*] bind $p:pat = $e:expr; $($rest:tt)*) => {
+        $crate::for_yield_ap!(
+            @product
+            [{
+                let fs = $crate::Functor::fmap($acc, |acc: &_| {
+                    let acc = ::std::clone::Clone::clone(acc);
+                    move |value: &_| {
+                        (
+                            ::std::clone::Clone::clone(&acc),
+                            ::std::clone::Clone::clone(value),
+                        )
+                    }
+                });
+                $crate::Apply::ap($e, fs)
+            }]
+            [($($ps)*, $p)]
+            $($rest)*
+        )
+    };
+
+    // 最後の式
+    (@product [$acc:expr] [$ps:pat] yield $e:expr) => {
+        $crate::Functor::fmap($acc, |value: &_| {
+            let $ps = ::std::clone::Clone::clone(value);
+            $e
+        })
+    };
 }
 
 #[cfg(test)]
@@ -159,4 +405,200 @@ mod tests {
         };
         assert_eq!(result, Some(12));
     }
+
+    #[test]
+    fn test_for_yield_move_with_reader() {
+        use crate::{Pure, Reader};
+
+        let program = for_yield! {
+            move;
+            bind name = Reader::asks(|env: &(String, u32)| env.0.clone());
+            bind age = Reader::asks(|env: &(String, u32)| env.1);
+            let next = age + 1;
+            bind greeting = Reader::pure(format!("{} ({})", name, next));
+            yield Reader::pure(format!("{}, {}", greeting, name))
+        };
+        assert_eq!(program.run(&("alice".to_string(), 20)), "alice (21), alice");
+    }
+
+    #[test]
+    fn test_for_yield_move_with_option() {
+        let result = for_yield! {
+            move;
+            bind a = Some("a".to_string());
+            bind b = Some("b".to_string());
+            yield Some(format!("{}{}", a, b))
+        };
+        assert_eq!(result, Some("ab".to_string()));
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn test_for_yield_with_patterns() {
+        let result = for_yield! {
+            bind (a, b) = Some((1, 2));
+            bind Point { x, y } = Some(Point { x: 10, y: 20 });
+            let (c, d) = (a + x, b + y);
+            yield Some(c * d)
+        };
+        assert_eq!(result, Some(11 * 22));
+    }
+
+    #[test]
+    fn test_for_yield_with_guard() {
+        let result = for_yield! {
+            bind a = Some(4);
+            if a % 2 == 0;
+            yield Some(a / 2)
+        };
+        assert_eq!(result, Some(2));
+
+        let result = for_yield! {
+            bind a = Some(3);
+            if a % 2 == 0;
+            yield Some(a / 2)
+        };
+        assert_eq!(result, None);
+
+        let result = for_yield! {
+            bind a = vec![1, 2, 3, 4];
+            if a % 2 == 0;
+            bind b = vec![10, 20];
+            yield vec![a + b]
+        };
+        assert_eq!(result, vec![12, 22, 14, 24]);
+    }
+
+    #[test]
+    fn test_for_yield_with_sequencing() {
+        fn check(n: i32) -> Result<(), String> {
+            if n > 0 {
+                Ok(())
+            } else {
+                Err(format!("{} is not positive", n))
+            }
+        }
+
+        let result: Result<i32, String> = for_yield! {
+            bind a = Ok(1);
+            _ = check(*a);
+            bind b = Ok(2);
+            _ = check(*b);
+            yield Ok(a + b)
+        };
+        assert_eq!(result, Ok(3));
+
+        let result: Result<i32, String> = for_yield! {
+            bind a = Ok(1);
+            _ = check(a - 1);
+            yield Ok(a * 10)
+        };
+        assert_eq!(result, Err("0 is not positive".to_string()));
+    }
+
+    #[test]
+    fn test_for_yield_move_with_patterns() {
+        use crate::{Pure, Reader};
+
+        let program = for_yield! {
+            move;
+            bind (name, age) = Reader::asks(|env: &(String, u32)| env.clone());
+            let (upper, next) = (name.to_uppercase(), age + 1);
+            bind Point { x, y } = Reader::pure(Point { x: 1, y: 2 });
+            yield Reader::pure(format!("{} {} ({}) {}", name, upper, next, x + y))
+        };
+        assert_eq!(
+            program.run(&("alice".to_string(), 20)),
+            "alice ALICE (21) 3"
+        );
+    }
+
+    #[test]
+    fn test_for_yield_move_with_sequencing_and_guard() {
+        use crate::{Pure, State};
+
+        let program = for_yield! {
+            move;
+            bind before = State::<u32, u32>::get();
+            _ = State::modify(|s: u32| s * 2);
+            bind after = State::<u32, u32>::get();
+            yield State::pure((before, after))
+        };
+        assert_eq!(program.run(5), (10, (5, 10)));
+
+        let label = "even".to_string();
+        let result = for_yield! {
+            move;
+            bind a = Some(4);
+            if a % 2 == 0;
+            bind b = Some(label.clone());
+            yield Some(format!("{} is {}", a, b))
+        };
+        assert_eq!(result, Some("4 is even".to_string()));
+    }
+
+    mod applicative {
+        use crate::{NonEmptyVec, Validated, ValidatedNev};
+
+        fn positive(field: &str, n: i32) -> ValidatedNev<String, i32> {
+            if n > 0 {
+                Validated::Valid(n)
+            } else {
+                Validated::invalid_nev(format!("{} must be positive", field))
+            }
+        }
+
+        #[test]
+        fn test_for_yield_ap_with_validated() {
+            let result = for_yield_ap! {
+                bind x = positive("x", 1);
+                bind y = positive("y", 2);
+                bind (w, h) = positive("w", 3).map2(positive("h", 4), |w, h| (w, h));
+                yield super::Point { x: x * w, y: y * h }
+            };
+            assert_eq!(result, Validated::Valid(super::Point { x: 3, y: 8 }));
+
+            let result = for_yield_ap! {
+                bind x = positive("x", 0);
+                bind y = positive("y", 2);
+                bind z = positive("z", -1);
+                yield x + y + z
+            };
+            assert_eq!(
+                result,
+                Validated::Invalid(NonEmptyVec::new(
+                    "x must be positive".to_string(),
+                    vec!["z must be positive".to_string()]
+                ))
+            );
+        }
+
+        #[test]
+        fn test_for_yield_ap_with_option() {
+            let result = for_yield_ap! {
+                bind a = Some("a".to_string());
+                yield a + "!"
+            };
+            assert_eq!(result, Some("a!".to_string()));
+
+            let result = for_yield_ap! {
+                bind a = Some("a".to_string());
+                bind b = Some("b".to_string());
+                yield a + &b
+            };
+            assert_eq!(result, Some("ab".to_string()));
+
+            let result = for_yield_ap! {
+                bind a = Some(1);
+                bind b = None::<i32>;
+                yield a + b
+            };
+            assert_eq!(result, None);
+        }
+    }
 }
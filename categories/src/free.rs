@@ -0,0 +1,598 @@
+use std::any::{type_name, Any};
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::{tail_rec, Apply, Functor, MonadFamily, Pure, RecursionState};
+
+/// インタプリタが命令の結果として返す値です。
+///
+/// 命令ごとに結果の型が異なるため、値の型を消去して保持します。
+/// 命令に渡された`ReplyTo`からのみ作成できます。
+#[derive(Clone)]
+pub struct Reply(Rc<dyn Any>);
+
+impl Reply {
+    fn downcast<T: 'static>(&self) -> Result<&T, FreeError> {
+        self.0
+            .downcast_ref::<T>()
+            .ok_or(FreeError::MismatchedReply {
+                expected: type_name::<T>(),
+            })
+    }
+}
+
+impl fmt::Debug for Reply {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Reply")
+    }
+}
+
+/// 命令の結果として、型`A`の値を返すための宛先です。
+///
+/// `Free::lift_f`が作成して命令に渡します。インタプリタは命令が保持する`ReplyTo`を使って結果を返すため、
+/// 命令の結果と異なる型の値を返すとコンパイルエラーになります。
+pub struct ReplyTo<A> {
+    _marker: PhantomData<fn(A)>,
+}
+
+impl<A: 'static> ReplyTo<A> {
+    /// `run`のインタプリタが返す結果を作成します。
+    pub fn send(&self, value: A) -> Reply {
+        Reply(Rc::new(value))
+    }
+
+    /// `fold_map`のインタプリタが返す、対象のモナドの値を作成します。
+    pub fn send_in<G>(&self, ga: G) -> G::M<Reply>
+    where
+        A: Clone,
+        G: Functor<Elm = A>,
+    {
+        ga.fmap(|a| Reply(Rc::new(a.clone())))
+    }
+}
+
+impl<A> fmt::Debug for ReplyTo<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ReplyTo<{}>", type_name::<A>())
+    }
+}
+
+/// Freeの実行に失敗したことを表すエラーです。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FreeError {
+    /// インタプリタが返した結果の型が、命令の結果の型と一致しない
+    ///
+    /// `ReplyTo`を、それを受け取った命令とは別の命令に持ち出した場合にだけ発生します。
+    MismatchedReply { expected: &'static str },
+}
+
+impl fmt::Display for FreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FreeError::MismatchedReply { expected } => write!(
+                f,
+                "interpreter replied with a value that is not of type {}",
+                expected
+            ),
+        }
+    }
+}
+
+impl Error for FreeError {}
+
+type Continuation<F> = Rc<dyn Fn(&Reply) -> Result<Rc<FreeOp<F>>, FreeError>>;
+
+/// 命令と継続の連鎖を表す内部表現です。
+enum FreeOp<F> {
+    Pure(Reply),
+    Suspend(F),
+    Bind(Option<Rc<FreeOp<F>>>, Continuation<F>),
+}
+
+impl<F> FreeOp<F> {
+    fn instruction(&self) -> &F {
+        match self {
+            FreeOp::Suspend(fa) => fa,
+            _ => unreachable!("only a suspended computation holds an instruction"),
+        }
+    }
+}
+
+impl<F> Drop for FreeOp<F> {
+    // 深くネストした木を再帰的に解放するとスタックが溢れるため、ループで解放します。
+    fn drop(&mut self) {
+        let mut next = match self {
+            FreeOp::Bind(inner, _) => inner.take(),
+            _ => None,
+        };
+        while let Some(op) = next {
+            next = match Rc::try_unwrap(op) {
+                Ok(mut op) => match &mut op {
+                    FreeOp::Bind(inner, _) => inner.take(),
+                    _ => None,
+                },
+                Err(_) => None,
+            };
+        }
+    }
+}
+
+/// 実行を待つ継続のスタックです。
+///
+/// `fold_map`では対象のモナドが継続を複数回呼び出すことがあるため、永続的なリストとして共有します。
+struct Continuations<F>(Option<Rc<Frame<F>>>);
+
+struct Frame<F> {
+    continuation: Continuation<F>,
+    rest: Option<Rc<Frame<F>>>,
+}
+
+impl<F> Continuations<F> {
+    fn push(self, continuation: Continuation<F>) -> Self {
+        Continuations(Some(Rc::new(Frame {
+            continuation,
+            rest: self.0,
+        })))
+    }
+
+    fn pop(self) -> Option<(Continuation<F>, Self)> {
+        self.0.map(|frame| {
+            (
+                Rc::clone(&frame.continuation),
+                Continuations(frame.rest.clone()),
+            )
+        })
+    }
+}
+
+impl<F> Clone for Continuations<F> {
+    fn clone(&self) -> Self {
+        Continuations(self.0.clone())
+    }
+}
+
+impl<F> Drop for Frame<F> {
+    // FreeOpと同じく、長いスタックを再帰せずに解放します。
+    fn drop(&mut self) {
+        let mut next = self.rest.take();
+        while let Some(frame) = next {
+            next = match Rc::try_unwrap(frame) {
+                Ok(mut frame) => frame.rest.take(),
+                Err(_) => None,
+            };
+        }
+    }
+}
+
+/// 次の命令に到達するか、計算が終わるまで解釈した結果です。
+enum Step<F> {
+    Done(Reply),
+    Suspended(Rc<FreeOp<F>>, Continuations<F>),
+}
+
+/// `bind`の連鎖を`tail_rec`のループで解釈し、次の命令まで進めます。
+fn resume<F>(op: Rc<FreeOp<F>>, continuations: Continuations<F>) -> Result<Step<F>, FreeError> {
+    tail_rec(
+        (op, continuations),
+        |(op, continuations): (Rc<FreeOp<F>>, Continuations<F>)| match &*op {
+            FreeOp::Bind(inner, k) => {
+                let inner = inner.as_ref().expect("bind must have an inner computation");
+                RecursionState::Continue((Rc::clone(inner), continuations.push(Rc::clone(k))))
+            }
+            FreeOp::Suspend(_) => {
+                RecursionState::Done(Ok(Step::Suspended(Rc::clone(&op), continuations)))
+            }
+            FreeOp::Pure(reply) => match continuations.pop() {
+                Some((k, rest)) => match k(reply) {
+                    Ok(next) => RecursionState::Continue((next, rest)),
+                    Err(e) => RecursionState::Done(Err(e)),
+                },
+                None => RecursionState::Done(Ok(Step::Done(reply.clone()))),
+            },
+        },
+    )
+}
+
+fn fold_step<F, A, G, N>(
+    step: Result<Step<F>, FreeError>,
+    nt: &N,
+) -> <G as Apply>::M<Result<A, FreeError>>
+where
+    A: Clone + 'static,
+    N: Fn(&F) -> G,
+    G: MonadFamily + Apply<Elm = Reply, M<Reply> = G>,
+{
+    match step {
+        Err(e) => G::pure_of(Err(e)),
+        Ok(Step::Done(reply)) => G::pure_of(reply.downcast::<A>().cloned()),
+        Ok(Step::Suspended(op, continuations)) => G::bind_of::<Reply, Result<A, FreeError>, _>(
+            nt(op.instruction()),
+            move |reply: Reply| {
+                let next = Rc::new(FreeOp::Pure(reply));
+                fold_step::<F, A, G, N>(resume(next, continuations.clone()), nt)
+            },
+        ),
+    }
+}
+
+/// Freeは、命令`F`の列として記述した計算を、データとして保持するモナドです。
+///
+/// 業務フローを命令の列として記述しておき、本番ではデータストアに対して、
+/// テストではログを記録するだけのインタプリタで実行する、といった使い分けができます。
+///
+/// # 命令の型について
+///
+/// Freeは継続を命令とは別に保持する形式（Freer）で実装しています。
+/// このクレートの`Functor::fmap`は`'static`でない関数も受け取るため、継続を関数として保持する命令は
+/// `Functor`を実装できません。そのため、命令`F`自体には`Functor`を要求しません。
+///
+/// 代わりに、各命令は`lift_f`から渡される`ReplyTo<A>`を保持し、インタプリタはそれを使って結果を返します。
+/// 命令の結果と異なる型の値を返すとコンパイルエラーになります。
+///
+/// ```compile_fail
+/// use rust_fp_categories::{Free, ReplyTo};
+///
+/// enum Op {
+///     Count(ReplyTo<usize>),
+/// }
+///
+/// let program: Free<Op, usize> = Free::lift_f(Op::Count);
+/// program.run(|op: &Op| match op {
+///     Op::Count(reply) => reply.send("42".to_string()),
+/// });
+/// ```
+///
+/// # 実行
+///
+/// * `run` - 命令を1つずつ値に変換しながら実行します。`tailrec`のトランポリンを使うため、
+///   `bind`の連鎖がどれだけ長くてもスタックオーバーフローは発生しません。
+/// * `fold_map` - 命令を対象のモナドの値に変換（自然変換）し、そのモナドの`bind_of`で連鎖させます。
+///
+/// `ReplyTo`を別の命令に持ち出した場合は、結果の型が一致しないため`FreeError`を返します。
+///
+/// # 型クラスとの関係
+///
+/// `fmap`と`bind`は固有メソッドとして提供し、`Pure`だけを型クラスとして実装しています。
+/// 理由は[型クラス階層の「関数を保持する型と型クラス」](https://github.com/j5ik2o/rust-fp/blob/main/categories/src/hierarchy.md)を参照してください。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{for_yield, Free, Pure, ReplyTo};
+/// use std::collections::HashMap;
+///
+/// enum KvOp {
+///     Put(String, i32, ReplyTo<()>),
+///     Get(String, ReplyTo<Option<i32>>),
+/// }
+///
+/// fn put(key: &str, value: i32) -> Free<KvOp, ()> {
+///     Free::lift_f(|reply| KvOp::Put(key.to_string(), value, reply))
+/// }
+///
+/// fn get(key: &str) -> Free<KvOp, Option<i32>> {
+///     Free::lift_f(|reply| KvOp::Get(key.to_string(), reply))
+/// }
+///
+/// let program = for_yield! {
+///     move;
+///     _ = put("a", 1);
+///     _ = put("b", 2);
+///     bind a = get("a");
+///     bind b = get("b");
+///     yield Free::pure(a.unwrap_or(0) + b.unwrap_or(0))
+/// };
+///
+/// let mut store = HashMap::new();
+/// let result = program.run(|op: &KvOp| match op {
+///     KvOp::Put(key, value, reply) => reply.send({
+///         store.insert(key.clone(), *value);
+///     }),
+///     KvOp::Get(key, reply) => reply.send(store.get(key).cloned()),
+/// });
+/// assert_eq!(result, Ok(3));
+/// ```
+pub struct Free<F, A> {
+    op: Rc<FreeOp<F>>,
+    _marker: PhantomData<A>,
+}
+
+impl<F: 'static, A: 'static> Free<F, A> {
+    fn from_op(op: FreeOp<F>) -> Self {
+        Free {
+            op: Rc::new(op),
+            _marker: PhantomData,
+        }
+    }
+
+    /// 命令を1つだけ実行する計算を作成します。
+    ///
+    /// `build`は、結果を返すための`ReplyTo<A>`を受け取って命令を作成します。
+    pub fn lift_f<B>(build: B) -> Self
+    where
+        B: FnOnce(ReplyTo<A>) -> F,
+    {
+        let reply_to = ReplyTo {
+            _marker: PhantomData,
+        };
+        Free::from_op(FreeOp::Suspend(build(reply_to)))
+    }
+
+    pub fn fmap<B: 'static, G>(self, f: G) -> Free<F, B>
+    where
+        G: Fn(&A) -> B + 'static,
+    {
+        self.bind(move |a| Free::from_op(FreeOp::Pure(Reply(Rc::new(f(a))))))
+    }
+
+    pub fn bind<B: 'static, G>(self, f: G) -> Free<F, B>
+    where
+        G: Fn(&A) -> Free<F, B> + 'static,
+    {
+        let k: Continuation<F> =
+            Rc::new(move |reply: &Reply| Ok(Rc::clone(&f(reply.downcast::<A>()?).op)));
+        Free::from_op(FreeOp::Bind(Some(self.op), k))
+    }
+
+    /// 命令を値に変換する関数を使って、計算を実行します。
+    ///
+    /// `bind`の連鎖はループで解釈されるため、スタックを消費しません。
+    pub fn run<H>(&self, handler: H) -> Result<A, FreeError>
+    where
+        A: Clone,
+        H: FnMut(&F) -> Reply,
+    {
+        let handler = RefCell::new(handler);
+        let reply = tail_rec(
+            resume(Rc::clone(&self.op), Continuations(None)),
+            |step: Result<Step<F>, FreeError>| match step {
+                Err(e) => RecursionState::Done(Err(e)),
+                Ok(Step::Done(reply)) => RecursionState::Done(Ok(reply)),
+                Ok(Step::Suspended(op, continuations)) => {
+                    let reply = (handler.borrow_mut())(op.instruction());
+                    RecursionState::Continue(resume(Rc::new(FreeOp::Pure(reply)), continuations))
+                }
+            },
+        )?;
+        reply.downcast::<A>().cloned()
+    }
+
+    /// 命令を対象のモナド`G`の値に変換する自然変換を使って、計算を`G`の計算に変換します。
+    ///
+    /// `nt`は、各命令をその命令の結果を要素とする`G`の値に変換し、`ReplyTo::send_in`で返します。
+    /// `bind`の連鎖は`run`と同じく`tail_rec`のループで解釈し、`G`の`bind_of`は命令ごとに1回だけ使用します。
+    /// `G`の`bind_of`が継続をその場で呼び出す場合は、命令の数に比例してスタックを消費します。
+    pub fn fold_map<G, N>(&self, nt: N) -> <G as Apply>::M<Result<A, FreeError>>
+    where
+        A: Clone,
+        N: Fn(&F) -> G,
+        G: MonadFamily + Apply<Elm = Reply, M<Reply> = G>,
+    {
+        fold_step::<F, A, G, N>(resume(Rc::clone(&self.op), Continuations(None)), &nt)
+    }
+}
+
+impl<F, A> Clone for Free<F, A> {
+    fn clone(&self) -> Self {
+        Free {
+            op: Rc::clone(&self.op),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F, A> fmt::Debug for Free<F, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Free")
+    }
+}
+
+impl<F: 'static, A: Clone + 'static> Pure for Free<F, A> {
+    type Elm = A;
+    type M<U: Clone> = Free<F, U>;
+
+    fn pure(value: A) -> Free<F, A> {
+        Free::from_op(FreeOp::Pure(Reply(Rc::new(value))))
+    }
+
+    fn unit() -> Free<F, ()> {
+        Free::from_op(FreeOp::Pure(Reply(Rc::new(()))))
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::{Free, FreeError, Pure, Reply, ReplyTo};
+
+    enum Op {
+        Add(i64, ReplyTo<i64>),
+    }
+
+    fn add(n: i64) -> Free<Op, i64> {
+        Free::lift_f(|reply| Op::Add(n, reply))
+    }
+
+    fn interpret(seed: i64) -> impl FnMut(&Op) -> Reply {
+        move |op| match op {
+            Op::Add(n, reply) => reply.send(seed.wrapping_add(*n)),
+        }
+    }
+
+    fn f(n: &i64) -> Free<Op, i64> {
+        add(n.wrapping_mul(2))
+    }
+
+    fn g(n: &i64) -> Free<Op, i64> {
+        add(n.wrapping_sub(5))
+    }
+
+    #[quickcheck]
+    fn monad_left_identity(n: i64, seed: i64) -> bool {
+        Free::pure(n).bind(f).run(interpret(seed)) == f(&n).run(interpret(seed))
+    }
+
+    #[quickcheck]
+    fn monad_right_identity(n: i64, seed: i64) -> bool {
+        let m = add(n);
+        m.clone().bind(|x| Free::pure(*x)).run(interpret(seed)) == m.run(interpret(seed))
+    }
+
+    #[quickcheck]
+    fn monad_associativity(n: i64, seed: i64) -> bool {
+        let m = add(n);
+        m.clone().bind(f).bind(g).run(interpret(seed))
+            == m.bind(|x| f(x).bind(g)).run(interpret(seed))
+    }
+
+    #[quickcheck]
+    fn run_and_fold_map_agree(n: i64, seed: i64) -> bool {
+        let m = add(n).bind(f).bind(g);
+        let folded: Option<Result<i64, FreeError>> = m.fold_map(|op: &Op| match op {
+            Op::Add(k, reply) => reply.send_in(Some(seed.wrapping_add(*k))),
+        });
+        folded == Some(m.run(interpret(seed)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Free, FreeError, Pure, ReplyTo, Writer};
+
+    #[derive(Debug)]
+    enum Console {
+        Read(ReplyTo<String>),
+        Write(String, ReplyTo<()>),
+    }
+
+    fn read() -> Free<Console, String> {
+        Free::lift_f(Console::Read)
+    }
+
+    fn write(line: String) -> Free<Console, ()> {
+        Free::lift_f(|reply| Console::Write(line, reply))
+    }
+
+    fn program() -> Free<Console, usize> {
+        read().bind(|name| {
+            let len = name.len();
+            write(format!("hello, {}", name)).fmap(move |_| len)
+        })
+    }
+
+    #[test]
+    fn test_fold_map_into_writer() {
+        let result = program().fold_map(|op: &Console| match op {
+            Console::Read(reply) => {
+                reply.send_in(Writer::new(vec!["read".to_string()], "rust".to_string()))
+            }
+            Console::Write(line, reply) => {
+                reply.send_in(Writer::new(vec![format!("write {}", line)], ()))
+            }
+        });
+        assert_eq!(
+            result.run(),
+            (
+                vec!["read".to_string(), "write hello, rust".to_string()],
+                Ok(4)
+            )
+        );
+    }
+
+    #[test]
+    fn test_fold_map_short_circuits() {
+        let result = program().fold_map(|op: &Console| match op {
+            Console::Read(reply) => reply.send_in(Some("rust".to_string())),
+            Console::Write(_, reply) => reply.send_in(None),
+        });
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_fold_map_into_vec_shares_continuations() {
+        let result = read()
+            .bind(|name| {
+                let name = name.clone();
+                read().fmap(move |other| format!("{}{}", name, other))
+            })
+            .fold_map(|op: &Console| match op {
+                Console::Read(reply) => reply.send_in(vec!["a".to_string(), "b".to_string()]),
+                Console::Write(_, reply) => reply.send_in(vec![()]),
+            });
+        let result: Result<Vec<String>, FreeError> = result.into_iter().collect();
+        assert_eq!(
+            result,
+            Ok(vec![
+                "aa".to_string(),
+                "ab".to_string(),
+                "ba".to_string(),
+                "bb".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_run_million_binds() {
+        let mut program = Free::<Console, u64>::pure(0);
+        for _ in 0..1_000_000 {
+            program = program.bind(|n| {
+                let n = *n;
+                write(String::new()).fmap(move |_| n + 1)
+            });
+        }
+        let mut writes = 0;
+        let result = program.run(|op: &Console| match op {
+            Console::Read(reply) => reply.send(String::new()),
+            Console::Write(_, reply) => {
+                writes += 1;
+                reply.send(())
+            }
+        });
+        assert_eq!(result, Ok(1_000_000));
+        assert_eq!(writes, 1_000_000);
+    }
+
+    #[test]
+    fn test_fold_map_million_binds() {
+        let mut program = read().fmap(|name| name.len() as u64);
+        for _ in 0..1_000_000 {
+            program = program.bind(|n| Free::pure(n + 1));
+        }
+        let result = program.fold_map(|op: &Console| match op {
+            Console::Read(reply) => reply.send_in(Some("rust".to_string())),
+            Console::Write(_, reply) => reply.send_in(Some(())),
+        });
+        assert_eq!(result, Some(Ok(1_000_004)));
+    }
+
+    #[test]
+    fn test_mismatched_reply_is_an_error() {
+        enum Probe {
+            Ask(ReplyTo<String>),
+            Idle,
+        }
+
+        // 別の命令から持ち出したReplyToで応答すると、結果の型が一致しません。
+        let mut stolen = None;
+        let _ = Free::<Probe, String>::lift_f(|reply| {
+            stolen = Some(reply);
+            Probe::Idle
+        });
+        let program: Free<Probe, usize> = Free::lift_f(|_| Probe::Ask(stolen.take().unwrap()));
+        let expected = Err(FreeError::MismatchedReply { expected: "usize" });
+
+        let result = program.run(|op: &Probe| match op {
+            Probe::Ask(reply) => reply.send("rust".to_string()),
+            Probe::Idle => unreachable!(),
+        });
+        assert_eq!(result, expected);
+
+        let result = program.fold_map(|op: &Probe| match op {
+            Probe::Ask(reply) => reply.send_in(Some("rust".to_string())),
+            Probe::Idle => unreachable!(),
+        });
+        assert_eq!(result, Some(expected));
+    }
+}
@@ -212,6 +212,62 @@ impl<A> Functor for Vec<A> {
 
 #[cfg(test)]
 mod laws {
+    mod derived {
+        use crate::Functor;
+        use std::convert::identity;
+
+        #[derive(Functor, Debug, PartialEq, Clone)]
+        enum Tree<A> {
+            Leaf,
+            Node(Box<Tree<A>>, A, Box<Tree<A>>),
+        }
+
+        #[derive(Functor, Debug, PartialEq, Clone)]
+        struct Tagged<A: Clone> {
+            tag: String,
+            value: A,
+            pairs: Vec<(A, u8)>,
+            children: Option<Vec<Tree<A>>>,
+        }
+
+        fn tree(values: &[i32]) -> Tree<i32> {
+            match values.split_first() {
+                None => Tree::Leaf,
+                Some((head, tail)) => {
+                    let (left, right) = tail.split_at(tail.len() / 2);
+                    Tree::Node(Box::new(tree(left)), *head, Box::new(tree(right)))
+                }
+            }
+        }
+
+        fn tagged(values: Vec<i32>) -> Tagged<i32> {
+            Tagged {
+                tag: "t".to_string(),
+                value: values.len() as i32,
+                pairs: values.iter().map(|v| (*v, 1)).collect(),
+                children: if values.is_empty() {
+                    None
+                } else {
+                    Some(vec![tree(&values), Tree::Leaf])
+                },
+            }
+        }
+
+        #[quickcheck]
+        fn covariant_identity(values: Vec<i32>) -> bool {
+            tree(&values).fmap(|x| identity(*x)) == tree(&values)
+                && tagged(values.clone()).fmap(|x| identity(*x)) == tagged(values)
+        }
+
+        #[quickcheck]
+        fn covariant_composition(values: Vec<i32>) -> bool {
+            let f1: fn(&i32) -> i64 = |x| *x as i64 * 2;
+            let f2: fn(&i64) -> String = |x| x.to_string();
+            tree(&values).fmap(f1).fmap(f2) == tree(&values).fmap(|x| f2(&f1(x)))
+                && tagged(values.clone()).fmap(f1).fmap(f2) == tagged(values).fmap(|x| f2(&f1(x)))
+        }
+    }
+
     mod option {
         use crate::Functor;
         use std::convert::identity;
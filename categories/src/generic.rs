@@ -0,0 +1,147 @@
+/// Generic型クラスは、データ型とその汎用的な表現(`Repr`)との相互変換を表します。
+///
+/// 構造体はフィールドを順に並べたHList(`HCons`/`HNil`)に、列挙型は各バリアントの
+/// HListを並べたCoproduct(`Coproduct`/`CNil`)に対応します。
+/// 汎用的な表現に対して実装した`Show`や`Semigroup`などの処理を、個々のデータ型に対して
+/// 書き直すことなく利用できます。
+///
+/// 通常は`#[derive(Generic)]`で実装します。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{hlist, Generic};
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct User {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Employee {
+///     id: String,
+///     years: u32,
+/// }
+///
+/// let user = User { name: "alice".to_string(), age: 20 };
+/// assert_eq!(user.into_repr(), hlist!["alice".to_string(), 20]);
+///
+/// let employee: Employee = rust_fp_categories::convert_from(User { name: "bob".to_string(), age: 3 });
+/// assert_eq!(employee, Employee { id: "bob".to_string(), years: 3 });
+/// ```
+pub trait Generic: Sized {
+    type Repr;
+
+    /// 値を汎用的な表現に変換します。
+    fn into_repr(self) -> Self::Repr;
+
+    /// 汎用的な表現から値を復元します。
+    fn from_repr(repr: Self::Repr) -> Self;
+}
+
+/// 同じ汎用的な表現を持つ型の間で値を変換します。
+pub fn convert_from<Src, Dst>(src: Src) -> Dst
+where
+    Src: Generic,
+    Dst: Generic<Repr = Src::Repr>,
+{
+    Dst::from_repr(src.into_repr())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{convert_from, hlist, Coproduct, Generic, HNil, Semigroup, ShowPoly};
+
+    #[derive(Generic, Debug, PartialEq, Clone)]
+    struct Stats {
+        count: u32,
+        names: Vec<String>,
+    }
+
+    #[derive(Generic, Debug, PartialEq)]
+    struct Pair<A>(A, A);
+
+    #[derive(Generic, Debug, PartialEq)]
+    struct Size {
+        width: u32,
+        height: u32,
+    }
+
+    #[derive(Generic, Debug, PartialEq)]
+    struct Marker;
+
+    #[derive(Generic, Debug, PartialEq)]
+    enum Shape {
+        Circle { radius: u32 },
+        Rect(u32, u32),
+        Empty,
+    }
+
+    #[test]
+    fn test_struct_round_trip() {
+        let stats = Stats {
+            count: 1,
+            names: vec!["a".to_string()],
+        };
+        let repr = stats.clone().into_repr();
+        assert_eq!(repr, hlist![1, vec!["a".to_string()]]);
+        assert_eq!(Stats::from_repr(repr), stats);
+
+        assert_eq!(Pair(1, 2).into_repr(), hlist![1, 2]);
+        assert_eq!(Pair::from_repr(hlist!["a", "b"]), Pair("a", "b"));
+        assert_eq!(Marker.into_repr(), HNil);
+        assert_eq!(Marker::from_repr(HNil), Marker);
+    }
+
+    #[test]
+    fn test_enum_round_trip() {
+        let circle = Shape::Circle { radius: 3 }.into_repr();
+        assert_eq!(circle, Coproduct::Inl(hlist![3]));
+        assert_eq!(Shape::from_repr(circle), Shape::Circle { radius: 3 });
+
+        let rect = Shape::Rect(1, 2).into_repr();
+        assert_eq!(
+            rect.get::<crate::Hlist!(u32, u32), _>(),
+            Some(&hlist![1, 2])
+        );
+        assert_eq!(Shape::from_repr(rect), Shape::Rect(1, 2));
+
+        let empty = Shape::Empty.into_repr();
+        assert_eq!(empty, Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(HNil))));
+        assert_eq!(Shape::from_repr(empty), Shape::Empty);
+    }
+
+    #[test]
+    fn test_generic_programming_over_repr() {
+        let a = Stats {
+            count: 1,
+            names: vec!["a".to_string()],
+        };
+        let b = Stats {
+            count: 2,
+            names: vec!["b".to_string()],
+        };
+        let combined = Stats::from_repr(a.into_repr().combine(b.into_repr()));
+        assert_eq!(combined.count, 3);
+        assert_eq!(combined.names, vec!["a".to_string(), "b".to_string()]);
+
+        let shown = Pair(1, 2).into_repr().map_poly(ShowPoly);
+        assert_eq!(shown, hlist!["1".to_string(), "2".to_string()]);
+
+        let pair = Shape::Rect(4, 5)
+            .into_repr()
+            .take::<crate::Hlist!(u32, u32), _>()
+            .map(Pair::from_repr);
+        assert_eq!(pair, Some(Pair(4, 5)));
+
+        let size: Size = convert_from(Pair(6, 7));
+        assert_eq!(
+            size,
+            Size {
+                width: 6,
+                height: 7
+            }
+        );
+    }
+}
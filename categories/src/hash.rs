@@ -0,0 +1,108 @@
+//! ハッシュ値の計算を、型に対する実装ではなく明示的なインスタンス値として扱う型クラスです。
+//!
+//! 標準ライブラリの`Hash`トレイトと名前が重なるため、このモジュールはクレートのルートには
+//! 再エクスポートしていません。`rust_fp_categories::hash::Hash`として参照してください。
+
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::Hasher;
+use std::rc::Rc;
+
+use crate::Contravariant;
+
+/// 値のハッシュ値を計算するインスタンスです。
+///
+/// `eq::Eq`のインスタンスと組み合わせて使う場合、等しい値は同じハッシュ値を持つ必要があります。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::hash::Hash;
+///
+/// let case_insensitive = Hash::by(|s: &String| s.to_lowercase());
+/// assert_eq!(
+///     case_insensitive.hash(&"Rust".to_string()),
+///     case_insensitive.hash(&"rust".to_string())
+/// );
+/// ```
+pub struct Hash<A> {
+    f: Rc<dyn Fn(&A) -> u64>,
+}
+
+impl<A: 'static> Hash<A> {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&A) -> u64 + 'static,
+    {
+        Hash { f: Rc::new(f) }
+    }
+
+    /// `std::hash::Hash`によるハッシュ値を使うインスタンスを作成します。
+    pub fn natural() -> Self
+    where
+        A: std::hash::Hash,
+    {
+        Hash::new(|a: &A| {
+            let mut hasher = DefaultHasher::new();
+            a.hash(&mut hasher);
+            hasher.finish()
+        })
+    }
+
+    /// 値から取り出したキーのハッシュ値を使うインスタンスを作成します。
+    pub fn by<B, F>(key: F) -> Self
+    where
+        B: std::hash::Hash + 'static,
+        F: Fn(&A) -> B + 'static,
+    {
+        Hash::<B>::natural().contramap(key)
+    }
+
+    pub fn hash(&self, value: &A) -> u64 {
+        (self.f)(value)
+    }
+}
+
+impl<A> Clone for Hash<A> {
+    fn clone(&self) -> Self {
+        Hash {
+            f: Rc::clone(&self.f),
+        }
+    }
+}
+
+impl<A> fmt::Debug for Hash<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Hash")
+    }
+}
+
+impl<A: 'static> Contravariant for Hash<A> {
+    type Elm = A;
+    type M<B: 'static> = Hash<B>;
+
+    fn contramap<B: 'static, F>(self, f: F) -> Hash<B>
+    where
+        F: Fn(&B) -> A + 'static,
+    {
+        Hash::new(move |b| self.hash(&f(b)))
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::eq::Eq;
+    use crate::hash::Hash;
+
+    #[quickcheck]
+    fn equal_values_have_equal_hashes(a: String, b: String) -> bool {
+        let eq = Eq::by(|s: &String| s.to_lowercase());
+        let hash = Hash::by(|s: &String| s.to_lowercase());
+        !eq.eqv(&a, &b) || hash.hash(&a) == hash.hash(&b)
+    }
+
+    #[quickcheck]
+    fn natural_is_deterministic(a: Vec<i32>) -> bool {
+        Hash::natural().hash(&a) == Hash::natural().hash(&a.clone())
+    }
+}
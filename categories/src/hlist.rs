@@ -1,6 +1,8 @@
 use std::fmt::Display;
 use std::marker::PhantomData;
 
+use crate::{impl_hlist_tuple_conversions, Empty, Monoid, Semigroup};
+
 /// HList型クラスは、異なる型の要素を持つヘテロジニアスリスト（異種リスト）を表します。
 ///
 /// HListは、コンパイル時に型安全な方法で異なる型の要素を格納するためのデータ構造です。
@@ -39,6 +41,9 @@ use std::marker::PhantomData;
 /// assert_eq!(list.tail.tail.head, true);
 /// ```
 pub trait HList: Sized {
+    /// 要素の数です。
+    const LEN: usize;
+
     /// 新しい要素をHListの先頭に追加します。
     ///
     /// # 引数
@@ -73,7 +78,9 @@ pub trait HList: Sized {
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct HNil;
 
-impl HList for HNil {}
+impl HList for HNil {
+    const LEN: usize = 0;
+}
 
 /// HListの要素を表す型です。
 ///
@@ -101,7 +108,9 @@ pub struct HCons<H, T: HList> {
     pub tail: T,
 }
 
-impl<H, T: HList> HList for HCons<H, T> {}
+impl<H, T: HList> HList for HCons<H, T> {
+    const LEN: usize = 1 + T::LEN;
+}
 
 impl<H: Display, T: HList + Display> Display for HCons<H, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -139,7 +148,7 @@ impl Display for HNil {
 macro_rules! Hlist {
     () => { $crate::HNil };
     ($x:ty) => { $crate::HCons<$x, $crate::HNil> };
-    ($x:ty, $($y:ty),*) => { $crate::HCons<$x, Hlist!($($y),*)> };
+    ($x:ty, $($y:ty),*) => { $crate::HCons<$x, $crate::Hlist!($($y),*)> };
 }
 
 /// HListを作成するマクロです。
@@ -159,8 +168,8 @@ macro_rules! Hlist {
 #[macro_export]
 macro_rules! hlist {
     () => { $crate::HNil };
-    ($x:expr) => { $crate::HCons { head: $x, tail: $crate::HNil } };
-    ($x:expr, $($y:expr),*) => { $crate::HCons { head: $x, tail: hlist!($($y),*) } };
+    ($x:expr $(,)?) => { $crate::HCons { head: $x, tail: $crate::HNil } };
+    ($x:expr, $($y:expr),+ $(,)?) => { $crate::HCons { head: $x, tail: $crate::hlist!($($y),+) } };
 }
 
 /// HListに対するShow型クラスの実装
@@ -205,10 +214,541 @@ impl crate::Show for HNil {
     }
 }
 
+impl HNil {
+    pub fn len(&self) -> usize {
+        Self::LEN
+    }
+
+    pub fn is_empty(&self) -> bool {
+        true
+    }
+
+    pub fn append<RHS: HList>(self, rhs: RHS) -> RHS {
+        rhs
+    }
+
+    pub fn reverse(self) -> HNil {
+        self
+    }
+
+    pub fn map_poly<P>(self, _: P) -> HNil {
+        self
+    }
+
+    pub fn fold_poly<P, Acc>(self, _: P, init: Acc) -> Acc {
+        init
+    }
+
+    pub fn zip(self, _: HNil) -> HNil {
+        self
+    }
+}
+
+impl<H, T: HList> HCons<H, T> {
+    /// 型`U`の要素への参照を返します。
+    ///
+    /// 要素の位置`I`は型推論で決まるため、`get::<U, _>()`のように指定します。
+    /// 同じ型の要素が複数ある場合は位置を推論できず、コンパイルエラーになります。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use rust_fp_categories::hlist;
+    ///
+    /// let record = hlist!["alice", 20u32, true];
+    /// assert_eq!(*record.get::<u32, _>(), 20);
+    /// ```
+    pub fn get<U, I>(&self) -> &U
+    where
+        Self: Selector<U, I>,
+    {
+        Selector::get(self)
+    }
+
+    /// 型`U`の要素への可変参照を返します。
+    pub fn get_mut<U, I>(&mut self) -> &mut U
+    where
+        Self: Selector<U, I>,
+    {
+        Selector::get_mut(self)
+    }
+
+    /// 型`U`の要素を取り出し、残りの要素からなるHListとの組を返します。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use rust_fp_categories::hlist;
+    ///
+    /// let (age, rest) = hlist!["alice", 20u32, true].pluck::<u32, _>();
+    /// assert_eq!(age, 20);
+    /// assert_eq!(rest, hlist!["alice", true]);
+    /// ```
+    pub fn pluck<U, I>(self) -> (U, <Self as Plucker<U, I>>::Remainder)
+    where
+        Self: Plucker<U, I>,
+    {
+        Plucker::pluck(self)
+    }
+
+    /// 要素を並べ替えて`Target`のHListを作り、残りの要素からなるHListとの組を返します。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use rust_fp_categories::{hlist, Hlist};
+    ///
+    /// let (reordered, rest): (Hlist!(bool, &str), _) = hlist!["alice", 20u32, true].sculpt();
+    /// assert_eq!(reordered, hlist![true, "alice"]);
+    /// assert_eq!(rest, hlist![20u32]);
+    /// ```
+    pub fn sculpt<Target, Indices>(self) -> (Target, <Self as Sculptor<Target, Indices>>::Remainder)
+    where
+        Self: Sculptor<Target, Indices>,
+    {
+        Sculptor::sculpt(self)
+    }
+
+    pub fn len(&self) -> usize {
+        Self::LEN
+    }
+
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// `rhs`の要素を後ろに連結したHListを返します。
+    pub fn append<RHS: HList>(self, rhs: RHS) -> <Self as Append<RHS>>::Output
+    where
+        Self: Append<RHS>,
+    {
+        Append::append(self, rhs)
+    }
+
+    /// 要素を逆順に並べたHListを返します。
+    pub fn reverse(self) -> <Self as ReverseOnto<HNil>>::Output
+    where
+        Self: ReverseOnto<HNil>,
+    {
+        self.reverse_onto(HNil)
+    }
+
+    /// 各要素に`Poly`を適用したHListを返します。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use rust_fp_categories::{hlist, ShowPoly};
+    ///
+    /// let shown = hlist![1, "two", true].map_poly(ShowPoly);
+    /// assert_eq!(shown, hlist!["1".to_string(), "two".to_string(), "true".to_string()]);
+    /// ```
+    pub fn map_poly<P>(self, p: P) -> <Self as MapPoly<P>>::Output
+    where
+        Self: MapPoly<P>,
+    {
+        MapPoly::map_poly(self, &p)
+    }
+
+    /// `(累積値, 要素)`を受け取る`Poly`で、各要素を左から畳み込みます。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use rust_fp_categories::{hlist, Poly};
+    ///
+    /// struct Validate;
+    ///
+    /// impl Poly<(Vec<String>, &str)> for Validate {
+    ///     type Output = Vec<String>;
+    ///
+    ///     fn call(&self, (mut errors, name): (Vec<String>, &str)) -> Vec<String> {
+    ///         if name.is_empty() {
+    ///             errors.push("name is empty".to_string());
+    ///         }
+    ///         errors
+    ///     }
+    /// }
+    ///
+    /// impl Poly<(Vec<String>, u32)> for Validate {
+    ///     type Output = Vec<String>;
+    ///
+    ///     fn call(&self, (mut errors, age): (Vec<String>, u32)) -> Vec<String> {
+    ///         if age > 150 {
+    ///             errors.push(format!("age {} is too large", age));
+    ///         }
+    ///         errors
+    ///     }
+    /// }
+    ///
+    /// let errors = hlist!["", 200u32].fold_poly(Validate, vec![]);
+    /// assert_eq!(errors, vec!["name is empty".to_string(), "age 200 is too large".to_string()]);
+    /// ```
+    pub fn fold_poly<P, Acc>(self, p: P, init: Acc) -> Acc
+    where
+        Self: FoldPoly<P, Acc>,
+    {
+        FoldPoly::fold_poly(self, &p, init)
+    }
+
+    /// 同じ長さのHListと、要素ごとの組にまとめます。
+    pub fn zip<Other>(self, other: Other) -> <Self as Zip<Other>>::Output
+    where
+        Self: Zip<Other>,
+    {
+        Zip::zip(self, other)
+    }
+
+    /// `Semigroup`の要素を持つHListを、要素ごとに`combine`して1つにまとめます。
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use rust_fp_categories::hlist;
+    ///
+    /// let rows = vec![hlist![1, "b".to_string()], hlist![2, "c".to_string()]];
+    /// let summary = hlist![0, "a".to_string()].combine_all(rows);
+    /// let (count, names): (i32, String) = summary.into();
+    /// assert_eq!((count, names), (3, "abc".to_string()));
+    /// ```
+    pub fn combine_all<I>(self, rest: I) -> Self
+    where
+        Self: Semigroup,
+        I: IntoIterator<Item = Self>,
+    {
+        rest.into_iter().fold(self, Semigroup::combine)
+    }
+}
+
+/// 対象の要素が先頭にあることを表す型レベルの位置です。
+pub struct Here;
+
+/// 対象の要素が先頭より後ろ(`I`が示す位置)にあることを表す型レベルの位置です。
+pub struct There<I>(PhantomData<I>);
+
+/// 位置`I`にある型`T`の要素を参照する型クラスです。
+pub trait Selector<T, I> {
+    fn get(&self) -> &T;
+
+    fn get_mut(&mut self) -> &mut T;
+}
+
+impl<T, Tail: HList> Selector<T, Here> for HCons<T, Tail> {
+    fn get(&self) -> &T {
+        &self.head
+    }
+
+    fn get_mut(&mut self) -> &mut T {
+        &mut self.head
+    }
+}
+
+impl<H, T, Tail: HList + Selector<T, I>, I> Selector<T, There<I>> for HCons<H, Tail> {
+    fn get(&self) -> &T {
+        Selector::get(&self.tail)
+    }
+
+    fn get_mut(&mut self) -> &mut T {
+        Selector::get_mut(&mut self.tail)
+    }
+}
+
+/// 位置`I`にある型`T`の要素を取り出す型クラスです。
+pub trait Plucker<T, I> {
+    type Remainder: HList;
+
+    fn pluck(self) -> (T, Self::Remainder);
+}
+
+impl<T, Tail: HList> Plucker<T, Here> for HCons<T, Tail> {
+    type Remainder = Tail;
+
+    fn pluck(self) -> (T, Tail) {
+        (self.head, self.tail)
+    }
+}
+
+impl<H, T, Tail: HList + Plucker<T, I>, I> Plucker<T, There<I>> for HCons<H, Tail> {
+    type Remainder = HCons<H, <Tail as Plucker<T, I>>::Remainder>;
+
+    fn pluck(self) -> (T, Self::Remainder) {
+        let (target, remainder) = Plucker::pluck(self.tail);
+        (
+            target,
+            HCons {
+                head: self.head,
+                tail: remainder,
+            },
+        )
+    }
+}
+
+/// 要素を並べ替えて`Target`を作る型クラスです。`Indices`は各要素の位置を並べたHListです。
+pub trait Sculptor<Target, Indices> {
+    type Remainder;
+
+    fn sculpt(self) -> (Target, Self::Remainder);
+}
+
+impl<Source> Sculptor<HNil, HNil> for Source {
+    type Remainder = Source;
+
+    fn sculpt(self) -> (HNil, Source) {
+        (HNil, self)
+    }
+}
+
+impl<THead, TTail, SHead, STail, IHead, ITail> Sculptor<HCons<THead, TTail>, HCons<IHead, ITail>>
+    for HCons<SHead, STail>
+where
+    TTail: HList,
+    STail: HList,
+    ITail: HList,
+    HCons<SHead, STail>: Plucker<THead, IHead>,
+    <HCons<SHead, STail> as Plucker<THead, IHead>>::Remainder: Sculptor<TTail, ITail>,
+{
+    type Remainder = <<HCons<SHead, STail> as Plucker<THead, IHead>>::Remainder as Sculptor<
+        TTail,
+        ITail,
+    >>::Remainder;
+
+    fn sculpt(self) -> (HCons<THead, TTail>, Self::Remainder) {
+        let (head, remainder) = Plucker::pluck(self);
+        let (tail, remainder) = Sculptor::sculpt(remainder);
+        (HCons { head, tail }, remainder)
+    }
+}
+
+/// HListの後ろに`RHS`を連結する型クラスです。
+pub trait Append<RHS: HList> {
+    type Output: HList;
+
+    fn append(self, rhs: RHS) -> Self::Output;
+}
+
+impl<RHS: HList> Append<RHS> for HNil {
+    type Output = RHS;
+
+    fn append(self, rhs: RHS) -> RHS {
+        rhs
+    }
+}
+
+impl<H, T: HList + Append<RHS>, RHS: HList> Append<RHS> for HCons<H, T> {
+    type Output = HCons<H, <T as Append<RHS>>::Output>;
+
+    fn append(self, rhs: RHS) -> Self::Output {
+        HCons {
+            head: self.head,
+            tail: Append::append(self.tail, rhs),
+        }
+    }
+}
+
+/// HListを逆順にして`Acc`の前に連結する型クラスです。
+pub trait ReverseOnto<Acc: HList> {
+    type Output: HList;
+
+    fn reverse_onto(self, acc: Acc) -> Self::Output;
+}
+
+impl<Acc: HList> ReverseOnto<Acc> for HNil {
+    type Output = Acc;
+
+    fn reverse_onto(self, acc: Acc) -> Acc {
+        acc
+    }
+}
+
+impl<H, T: HList + ReverseOnto<HCons<H, Acc>>, Acc: HList> ReverseOnto<Acc> for HCons<H, T> {
+    type Output = <T as ReverseOnto<HCons<H, Acc>>>::Output;
+
+    fn reverse_onto(self, acc: Acc) -> Self::Output {
+        self.tail.reverse_onto(HCons {
+            head: self.head,
+            tail: acc,
+        })
+    }
+}
+
+/// Polyは、引数の型ごとに異なる処理を行う多相関数を表す型クラスです。
+///
+/// クロージャは単一の引数型しか扱えないため、HListの各要素に適用する関数は
+/// 要素の型ごとに`Poly`を実装した型として定義します。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{hlist, Poly};
+///
+/// struct Double;
+///
+/// impl Poly<i32> for Double {
+///     type Output = i32;
+///
+///     fn call(&self, a: i32) -> i32 {
+///         a * 2
+///     }
+/// }
+///
+/// impl Poly<String> for Double {
+///     type Output = String;
+///
+///     fn call(&self, a: String) -> String {
+///         a.repeat(2)
+///     }
+/// }
+///
+/// let doubled = hlist![21, "ab".to_string()].map_poly(Double);
+/// assert_eq!(doubled, hlist![42, "abab".to_string()]);
+/// ```
+pub trait Poly<A> {
+    type Output;
+
+    fn call(&self, a: A) -> Self::Output;
+}
+
+/// 各要素を`Show::show`で文字列に変換する`Poly`です。
+pub struct ShowPoly;
+
+impl<A: crate::Show> Poly<A> for ShowPoly {
+    type Output = String;
+
+    fn call(&self, a: A) -> String {
+        a.show()
+    }
+}
+
+/// HListの各要素に`Poly`を適用する型クラスです。
+pub trait MapPoly<P> {
+    type Output: HList;
+
+    fn map_poly(self, p: &P) -> Self::Output;
+}
+
+impl<P> MapPoly<P> for HNil {
+    type Output = HNil;
+
+    fn map_poly(self, _: &P) -> HNil {
+        HNil
+    }
+}
+
+impl<P: Poly<H>, H, T: HList + MapPoly<P>> MapPoly<P> for HCons<H, T> {
+    type Output = HCons<P::Output, <T as MapPoly<P>>::Output>;
+
+    fn map_poly(self, p: &P) -> Self::Output {
+        HCons {
+            head: p.call(self.head),
+            tail: self.tail.map_poly(p),
+        }
+    }
+}
+
+/// `(累積値, 要素)`を受け取る`Poly`で、HListを左から畳み込む型クラスです。
+pub trait FoldPoly<P, Acc> {
+    fn fold_poly(self, p: &P, init: Acc) -> Acc;
+}
+
+impl<P, Acc> FoldPoly<P, Acc> for HNil {
+    fn fold_poly(self, _: &P, init: Acc) -> Acc {
+        init
+    }
+}
+
+impl<P: Poly<(Acc, H), Output = Acc>, Acc, H, T: HList + FoldPoly<P, Acc>> FoldPoly<P, Acc>
+    for HCons<H, T>
+{
+    fn fold_poly(self, p: &P, init: Acc) -> Acc {
+        let acc = p.call((init, self.head));
+        self.tail.fold_poly(p, acc)
+    }
+}
+
+/// 同じ長さの2つのHListを、要素の組のHListにまとめる型クラスです。
+pub trait Zip<Other> {
+    type Output: HList;
+
+    fn zip(self, other: Other) -> Self::Output;
+}
+
+impl Zip<HNil> for HNil {
+    type Output = HNil;
+
+    fn zip(self, _: HNil) -> HNil {
+        HNil
+    }
+}
+
+impl<H1, T1: HList + Zip<T2>, H2, T2: HList> Zip<HCons<H2, T2>> for HCons<H1, T1> {
+    type Output = HCons<(H1, H2), <T1 as Zip<T2>>::Output>;
+
+    fn zip(self, other: HCons<H2, T2>) -> Self::Output {
+        HCons {
+            head: (self.head, other.head),
+            tail: self.tail.zip(other.tail),
+        }
+    }
+}
+
+impl Semigroup for HNil {
+    fn combine(self, _: Self) -> Self {
+        HNil
+    }
+}
+
+impl<H: Semigroup, T: HList + Semigroup> Semigroup for HCons<H, T> {
+    fn combine(self, other: Self) -> Self {
+        HCons {
+            head: self.head.combine(other.head),
+            tail: self.tail.combine(other.tail),
+        }
+    }
+}
+
+impl Empty for HNil {
+    fn empty() -> Self {
+        HNil
+    }
+
+    fn is_empty(&self) -> bool {
+        true
+    }
+}
+
+impl<H: Empty, T: HList + Empty> Empty for HCons<H, T> {
+    fn empty() -> Self {
+        HCons {
+            head: H::empty(),
+            tail: T::empty(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        Empty::is_empty(&self.head) && Empty::is_empty(&self.tail)
+    }
+}
+
+impl Monoid for HNil {}
+
+impl<H: Monoid, T: HList + Monoid> Monoid for HCons<H, T> {}
+
+impl From<()> for HNil {
+    fn from(_: ()) -> Self {
+        HNil
+    }
+}
+
+impl From<HNil> for () {
+    fn from(_: HNil) -> Self {}
+}
+
+impl_hlist_tuple_conversions!();
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Show;
+    use crate::{Foldable, Show};
 
     #[test]
     fn test_prepend() {
@@ -243,6 +783,153 @@ mod tests {
         assert_eq!(format!("{}", list), "hello :: 42 :: true :: HNil");
     }
 
+    #[test]
+    fn test_get_by_type() {
+        let mut record = hlist!["alice", 20u32, true];
+        assert_eq!(*record.get::<&str, _>(), "alice");
+        assert!(*record.get::<bool, _>());
+        *record.get_mut::<u32, _>() += 1;
+        assert_eq!(record, hlist!["alice", 21u32, true]);
+    }
+
+    #[test]
+    fn test_pluck() {
+        let (flag, rest) = hlist!["alice", 20u32, true].pluck::<bool, _>();
+        assert!(flag);
+        assert_eq!(rest, hlist!["alice", 20u32]);
+
+        let (name, rest) = rest.pluck::<&str, _>();
+        assert_eq!(name, "alice");
+        assert_eq!(rest, hlist![20u32]);
+    }
+
+    #[test]
+    fn test_sculpt() {
+        let list = hlist![1u8, "two", 3.0f64, 'c'];
+        let (reordered, rest) = list.clone().sculpt::<Hlist!(char, u8, &str), _>();
+        assert_eq!(reordered, hlist!['c', 1u8, "two"]);
+        assert_eq!(rest, hlist![3.0f64]);
+
+        let (all, rest) = list.sculpt::<Hlist!(f64, char, &str, u8), _>();
+        assert_eq!(all, hlist![3.0f64, 'c', "two", 1u8]);
+        assert_eq!(rest, HNil);
+    }
+
+    #[test]
+    fn test_len_append_reverse() {
+        let list = hlist![1, "two", 3.0];
+        assert_eq!(list.len(), 3);
+        assert_eq!(HNil.len(), 0);
+        assert!(HNil.is_empty());
+
+        let appended = list.append(hlist!['x', true]);
+        assert_eq!(appended, hlist![1, "two", 3.0, 'x', true]);
+        assert_eq!(appended.len(), 5);
+        assert_eq!(HNil.append(hlist![1]), hlist![1]);
+
+        assert_eq!(appended.reverse(), hlist![true, 'x', 3.0, "two", 1]);
+        assert_eq!(HNil.reverse(), HNil);
+    }
+
+    #[test]
+    fn test_tuple_conversions() {
+        let list: Hlist!(i32, &str, bool) = (1, "a", true).into();
+        assert_eq!(list, hlist![1, "a", true]);
+        let tuple: (i32, &str, bool) = list.into();
+        assert_eq!(tuple, (1, "a", true));
+
+        let single: Hlist!(i32) = (7,).into();
+        assert_eq!(<(i32,)>::from(single), (7,));
+
+        let twelve = HCons::from((
+            1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, "twelve",
+        ));
+        assert_eq!(twelve.len(), 12);
+        assert_eq!(*twelve.get::<&str, _>(), "twelve");
+        type Twelve<'a> = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, &'a str);
+        let back: Twelve = twelve.into();
+        assert_eq!(back.11, "twelve");
+
+        let empty: HNil = ().into();
+        let unit: () = empty.into();
+        assert_eq!(unit, ());
+    }
+
+    struct Describe;
+
+    impl Poly<i32> for Describe {
+        type Output = String;
+
+        fn call(&self, a: i32) -> String {
+            format!("int {}", a)
+        }
+    }
+
+    impl Poly<&str> for Describe {
+        type Output = usize;
+
+        fn call(&self, a: &str) -> usize {
+            a.len()
+        }
+    }
+
+    impl Poly<(usize, i32)> for Describe {
+        type Output = usize;
+
+        fn call(&self, (acc, a): (usize, i32)) -> usize {
+            acc + a as usize
+        }
+    }
+
+    impl Poly<(usize, &str)> for Describe {
+        type Output = usize;
+
+        fn call(&self, (acc, a): (usize, &str)) -> usize {
+            acc + a.len()
+        }
+    }
+
+    #[test]
+    fn test_map_poly() {
+        let mapped = hlist![1, "abc", 2].map_poly(Describe);
+        assert_eq!(
+            mapped,
+            hlist!["int 1".to_string(), 3usize, "int 2".to_string()]
+        );
+        assert_eq!(HNil.map_poly(Describe), HNil);
+
+        let shown = hlist![1.5, 'x', "y"].map_poly(ShowPoly);
+        let (a, b, c): (String, String, String) = shown.into();
+        assert_eq!((a.as_str(), b.as_str(), c.as_str()), ("1.5", "x", "y"));
+    }
+
+    #[test]
+    fn test_fold_poly() {
+        assert_eq!(hlist![1, "abc", 2].fold_poly(Describe, 10), 16);
+        assert_eq!(HNil.fold_poly(Describe, 10), 10);
+    }
+
+    #[test]
+    fn test_zip() {
+        let zipped = hlist![1, "a"].zip(hlist![true, 'b']);
+        assert_eq!(zipped, hlist![(1, true), ("a", 'b')]);
+        assert_eq!(HNil.zip(HNil), HNil);
+    }
+
+    #[test]
+    fn test_semigroup_and_combine_all() {
+        let summary = hlist![1, vec!["a"], Some(2)].combine_all(vec![
+            hlist![2, vec!["b"], None],
+            hlist![3, vec!["c"], Some(4)],
+        ]);
+        assert_eq!(summary, hlist![6, vec!["a", "b", "c"], Some(6)]);
+
+        type Row = Hlist!(i32, String);
+        let rows: Vec<Row> = vec![hlist![1, "x".to_string()], hlist![2, "y".to_string()]];
+        assert_eq!(rows.combine_all(), hlist![3, "xy".to_string()]);
+        assert!(Empty::is_empty(&Row::empty()));
+    }
+
     #[test]
     fn test_show() {
         let list = hlist!["hello", 42, true];
@@ -0,0 +1,556 @@
+//! 型クラスの法則を、利用者が定義したインスタンスに対して検査するためのモジュールです。
+//!
+//! `laws`フィーチャーを有効にした場合にのみ利用できます。
+//!
+//! ```toml
+//! [dev-dependencies]
+//! rust-fp-categories = { version = "0.0.5", features = ["laws"] }
+//! ```
+//!
+//! 法則ごとに、与えられた入力で1回だけ検査する関数(`semigroup_associativity`など)と、
+//! `quickcheck::Arbitrary`で生成した入力で繰り返し検査する関数(`check_semigroup`など)を用意しています。
+//! 値の比較には`eq::Eq`のインスタンスを使うため、`PartialEq`を実装していない型も検査できます。
+//! 法則が成り立たない場合は、失敗した法則の名前と、縮小(shrink)した入力を`LawViolation`で返します。
+//!
+//! # 例
+//!
+//! ```
+//! use rust_fp_categories::eq::Eq;
+//! use rust_fp_categories::laws;
+//!
+//! assert!(laws::check_monoid::<String>(&Eq::natural()).is_ok());
+//! assert!(laws::check_monad::<Option<i32>, _, _, _>(
+//!     |x: &i32| x.checked_add(1),
+//!     |x: &i32| if x % 2 == 0 { Some(x / 2) } else { None },
+//!     &Eq::natural(),
+//! )
+//! .is_ok());
+//!
+//! // 結合律を満たさない「減算」は、違反した法則と入力が報告される
+//! #[derive(Debug, Clone, PartialEq)]
+//! struct Diff(i8);
+//!
+//! impl rust_fp_categories::Semigroup for Diff {
+//!     fn combine(self, other: Self) -> Self {
+//!         Diff(self.0.wrapping_sub(other.0))
+//!     }
+//! }
+//!
+//! let violation = laws::semigroup_associativity(Diff(1), Diff(2), Diff(3), &Eq::natural())
+//!     .unwrap_err();
+//! assert_eq!(violation.law(), "semigroup associativity");
+//! assert_eq!(violation.input(), "a = Diff(1), b = Diff(2), c = Diff(3)");
+//! ```
+
+use std::error::Error;
+use std::fmt::{self, Debug};
+
+use quickcheck::{Arbitrary, StdThreadGen};
+
+use crate::eq::Eq;
+use crate::{ApplicativeFamily, Apply, Bind, Foldable, Functor, Monad, Monoid, Pure, Semigroup};
+
+/// `check`が生成する入力の数です。
+pub const DEFAULT_TESTS: usize = 100;
+
+/// 入力を生成する際のサイズ(`Vec`の長さや数値の範囲の目安)です。
+pub const DEFAULT_SIZE: usize = 100;
+
+/// 法則が成り立たなかったことを表すエラーです。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LawViolation {
+    law: &'static str,
+    input: String,
+}
+
+impl LawViolation {
+    pub fn new(law: &'static str, input: String) -> Self {
+        LawViolation { law, input }
+    }
+
+    /// 成り立たなかった法則の名前を返します。
+    pub fn law(&self) -> &'static str {
+        self.law
+    }
+
+    /// 法則が成り立たなかった入力を`Debug`形式で返します。
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl fmt::Display for LawViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} law does not hold for {}", self.law, self.input)
+    }
+}
+
+impl Error for LawViolation {}
+
+pub type LawResult = Result<(), LawViolation>;
+
+fn verify<F>(holds: bool, law: &'static str, input: F) -> LawResult
+where
+    F: FnOnce() -> String,
+{
+    if holds {
+        Ok(())
+    } else {
+        Err(LawViolation::new(law, input()))
+    }
+}
+
+/// `Arbitrary`で生成した`DEFAULT_TESTS`個の入力で`property`を検査します。
+///
+/// 失敗した場合は、入力を縮小しながら失敗し続ける最小の入力を探し、その結果を返します。
+pub fn check<T, P>(property: P) -> LawResult
+where
+    T: Arbitrary,
+    P: Fn(T) -> LawResult,
+{
+    check_n(DEFAULT_TESTS, property)
+}
+
+/// 入力の数を指定して`property`を検査します。
+pub fn check_n<T, P>(tests: usize, property: P) -> LawResult
+where
+    T: Arbitrary,
+    P: Fn(T) -> LawResult,
+{
+    let mut gen = StdThreadGen::new(DEFAULT_SIZE);
+    for _ in 0..tests {
+        let input = T::arbitrary(&mut gen);
+        if let Err(violation) = property(input.clone()) {
+            return Err(shrink(input, violation, &property));
+        }
+    }
+    Ok(())
+}
+
+fn shrink<T, P>(mut input: T, mut violation: LawViolation, property: &P) -> LawViolation
+where
+    T: Arbitrary,
+    P: Fn(T) -> LawResult,
+{
+    'search: loop {
+        for candidate in input.shrink() {
+            if let Err(smaller) = property(candidate.clone()) {
+                input = candidate;
+                violation = smaller;
+                continue 'search;
+            }
+        }
+        return violation;
+    }
+}
+
+// --- Semigroup
+
+/// `(a combine b) combine c == a combine (b combine c)`
+pub fn semigroup_associativity<A>(a: A, b: A, c: A, eq: &Eq<A>) -> LawResult
+where
+    A: Semigroup + Clone + Debug + 'static,
+{
+    let input = || format!("a = {:?}, b = {:?}, c = {:?}", a, b, c);
+    let left = a.clone().combine(b.clone()).combine(c.clone());
+    let right = a.clone().combine(b.clone().combine(c.clone()));
+    verify(eq.eqv(&left, &right), "semigroup associativity", input)
+}
+
+pub fn check_semigroup<A>(eq: &Eq<A>) -> LawResult
+where
+    A: Semigroup + Arbitrary + Debug,
+{
+    check(|(a, b, c): (A, A, A)| semigroup_associativity(a, b, c, eq))
+}
+
+// --- Monoid
+
+/// `empty combine a == a`
+pub fn monoid_left_identity<A>(a: A, eq: &Eq<A>) -> LawResult
+where
+    A: Monoid + Clone + Debug + 'static,
+{
+    let combined = A::empty().combine(a.clone());
+    verify(eq.eqv(&combined, &a), "monoid left identity", || {
+        format!("a = {:?}", a)
+    })
+}
+
+/// `a combine empty == a`
+pub fn monoid_right_identity<A>(a: A, eq: &Eq<A>) -> LawResult
+where
+    A: Monoid + Clone + Debug + 'static,
+{
+    let combined = a.clone().combine(A::empty());
+    verify(eq.eqv(&combined, &a), "monoid right identity", || {
+        format!("a = {:?}", a)
+    })
+}
+
+/// `Semigroup`の法則に加えて、`Monoid`の単位元の法則を検査します。
+pub fn check_monoid<A>(eq: &Eq<A>) -> LawResult
+where
+    A: Monoid + Arbitrary + Debug,
+{
+    check_semigroup(eq)?;
+    check(|a: A| monoid_left_identity(a, eq))?;
+    check(|a: A| monoid_right_identity(a, eq))
+}
+
+// --- Functor
+
+/// `fa.fmap(|x| x) == fa`
+pub fn functor_identity<F, A>(fa: F, eq: &Eq<F>) -> LawResult
+where
+    F: Functor<Elm = A, M<A> = F> + Clone + Debug + 'static,
+    A: Clone,
+{
+    let mapped = fa.clone().fmap(|x| x.clone());
+    verify(eq.eqv(&mapped, &fa), "functor identity", || {
+        format!("fa = {:?}", fa)
+    })
+}
+
+/// `fa.fmap(f).fmap(g) == fa.fmap(|x| g(&f(x)))`
+pub fn functor_composition<F, A, G, H>(fa: F, f: G, g: H, eq: &Eq<F>) -> LawResult
+where
+    F: Functor<Elm = A, M<A> = F> + Clone + Debug + 'static,
+    A: Clone,
+    G: Fn(&A) -> A,
+    H: Fn(&A) -> A,
+{
+    let left = fa.clone().fmap(&f).fmap(&g);
+    let right = fa.clone().fmap(|x| g(&f(x)));
+    verify(eq.eqv(&left, &right), "functor composition", || {
+        format!("fa = {:?}", fa)
+    })
+}
+
+pub fn check_functor<F, A, G, H>(f: G, g: H, eq: &Eq<F>) -> LawResult
+where
+    F: Functor<Elm = A, M<A> = F> + Arbitrary + Debug,
+    A: Clone,
+    G: Fn(&A) -> A,
+    H: Fn(&A) -> A,
+{
+    check(|fa: F| functor_identity(fa, eq))?;
+    check(|fa: F| functor_composition(fa, &f, &g, eq))
+}
+
+// --- Apply
+
+/// `fa.ap(pure(f))`です。`Apply::M`から関数の型を推論できないため、ここで型を固定します。
+fn ap_pure<F, A, G>(fa: F, f: G) -> F
+where
+    F: ApplicativeFamily + Apply<Elm = A, M<A> = F>,
+    A: Clone,
+    G: Clone + Fn(&A) -> A,
+{
+    fa.ap::<A, G>(F::pure_of(f))
+}
+
+/// `fa.ap(pure(f)).ap(pure(g)) == fa.ap(pure(|x| g(&f(x))))`
+///
+/// `Apply`だけでは関数をコンテナに持ち上げられないため、`ApplicativeFamily::pure_of`を使います。
+pub fn apply_composition<F, A, G, H>(fa: F, f: G, g: H, eq: &Eq<F>) -> LawResult
+where
+    F: ApplicativeFamily + Apply<Elm = A, M<A> = F> + Clone + Debug + 'static,
+    A: Clone,
+    G: Clone + Fn(&A) -> A,
+    H: Clone + Fn(&A) -> A,
+{
+    let left = ap_pure(ap_pure(fa.clone(), f.clone()), g.clone());
+    let right = ap_pure(fa.clone(), move |x: &A| g(&f(x)));
+    verify(eq.eqv(&left, &right), "apply composition", || {
+        format!("fa = {:?}", fa)
+    })
+}
+
+pub fn check_apply<F, A, G, H>(f: G, g: H, eq: &Eq<F>) -> LawResult
+where
+    F: ApplicativeFamily + Apply<Elm = A, M<A> = F> + Arbitrary + Debug,
+    A: Clone,
+    G: Clone + Fn(&A) -> A,
+    H: Clone + Fn(&A) -> A,
+{
+    check(|fa: F| apply_composition(fa, f.clone(), g.clone(), eq))
+}
+
+// --- Applicative
+
+/// `fa.ap(pure(|x| x)) == fa`
+pub fn applicative_identity<F, A>(fa: F, eq: &Eq<F>) -> LawResult
+where
+    F: ApplicativeFamily + Apply<Elm = A, M<A> = F> + Clone + Debug + 'static,
+    A: Clone,
+{
+    let applied = ap_pure(fa.clone(), |x: &A| x.clone());
+    verify(eq.eqv(&applied, &fa), "applicative identity", || {
+        format!("fa = {:?}", fa)
+    })
+}
+
+/// `pure(a).ap(pure(f)) == pure(f(&a))`
+pub fn applicative_homomorphism<F, A, G>(a: A, f: G, eq: &Eq<F>) -> LawResult
+where
+    F: ApplicativeFamily + Apply<Elm = A, M<A> = F> + 'static,
+    A: Clone + Debug,
+    G: Clone + Fn(&A) -> A,
+{
+    let left = ap_pure(F::pure_of(a.clone()), f.clone());
+    let right = F::pure_of(f(&a));
+    verify(eq.eqv(&left, &right), "applicative homomorphism", || {
+        format!("a = {:?}", a)
+    })
+}
+
+/// `pure(a).ap(fs) == fs.map(|f| f(&a))`
+pub fn applicative_interchange<F, A, G>(a: A, fs: <F as Apply>::M<G>, eq: &Eq<F>) -> LawResult
+where
+    F: ApplicativeFamily + Apply<Elm = A, M<A> = F> + 'static,
+    A: Clone + Debug,
+    G: Clone + Fn(&A) -> A,
+    <F as Apply>::M<G>: Clone,
+{
+    let left = F::pure_of(a.clone()).ap::<A, G>(fs.clone());
+    let right = F::map_of(fs, |f: G| f(&a));
+    verify(eq.eqv(&left, &right), "applicative interchange", || {
+        format!("a = {:?}", a)
+    })
+}
+
+/// `fa.ap(pure(f)) == fa.fmap(f)`
+pub fn applicative_map<F, A, G>(fa: F, f: G, eq: &Eq<F>) -> LawResult
+where
+    F: ApplicativeFamily
+        + Apply<Elm = A, M<A> = F>
+        + Functor<Elm = A, M<A> = F>
+        + Clone
+        + Debug
+        + 'static,
+    A: Clone,
+    G: Clone + Fn(&A) -> A,
+{
+    let applied = ap_pure(fa.clone(), f.clone());
+    let mapped = fa.clone().fmap(f);
+    verify(eq.eqv(&applied, &mapped), "applicative map", || {
+        format!("fa = {:?}", fa)
+    })
+}
+
+/// `Apply`の法則に加えて、`Applicative`の法則を検査します。
+///
+/// 交換律に使う関数のコンテナは、生成した`fa`の各要素を`f`に置き換えて作ります。
+pub fn check_applicative<F, A, G>(f: G, eq: &Eq<F>) -> LawResult
+where
+    F: ApplicativeFamily
+        + Apply<Elm = A, M<A> = F>
+        + Functor<Elm = A, M<A> = F>
+        + Arbitrary
+        + Debug,
+    A: Arbitrary + Debug,
+    G: Clone + Fn(&A) -> A,
+    <F as Apply>::M<G>: Clone,
+{
+    check_apply(f.clone(), f.clone(), eq)?;
+    check(|fa: F| applicative_identity(fa, eq))?;
+    check(|a: A| applicative_homomorphism(a, f.clone(), eq))?;
+    check(|(a, fa): (A, F)| {
+        let fs = F::map_of(fa, |_: A| f.clone());
+        applicative_interchange::<F, A, G>(a, fs, eq)
+    })?;
+    check(|fa: F| applicative_map(fa, f.clone(), eq))
+}
+
+// --- Bind
+
+/// `fa.bind(f).bind(g) == fa.bind(|x| f(x).bind(g))`
+pub fn bind_associativity<F, A, G, H>(fa: F, f: G, g: H, eq: &Eq<F>) -> LawResult
+where
+    F: Bind<Elm = A, M<A> = F> + Clone + Debug + 'static,
+    A: Clone,
+    G: Fn(&A) -> F,
+    H: Fn(&A) -> F,
+{
+    let left = fa.clone().bind::<A, _>(&f).bind::<A, _>(&g);
+    let right = fa.clone().bind::<A, _>(|x| f(x).bind::<A, _>(&g));
+    verify(eq.eqv(&left, &right), "bind associativity", || {
+        format!("fa = {:?}", fa)
+    })
+}
+
+pub fn check_bind<F, A, G, H>(f: G, g: H, eq: &Eq<F>) -> LawResult
+where
+    F: Bind<Elm = A, M<A> = F> + Arbitrary + Debug,
+    A: Clone,
+    G: Fn(&A) -> F,
+    H: Fn(&A) -> F,
+{
+    check(|fa: F| bind_associativity(fa, &f, &g, eq))
+}
+
+// --- Monad
+
+/// `pure(a).bind(f) == f(&a)`
+pub fn monad_left_identity<F, A, G>(a: A, f: G, eq: &Eq<F>) -> LawResult
+where
+    F: Monad + Pure<Elm = A, M<A> = F> + Bind<Elm = A, M<A> = F> + 'static,
+    A: Clone + Debug,
+    G: Fn(&A) -> F,
+{
+    let left = <F as Pure>::pure(a.clone()).bind::<A, _>(&f);
+    let right = f(&a);
+    verify(eq.eqv(&left, &right), "monad left identity", || {
+        format!("a = {:?}", a)
+    })
+}
+
+/// `fa.bind(pure) == fa`
+pub fn monad_right_identity<F, A>(fa: F, eq: &Eq<F>) -> LawResult
+where
+    F: Monad + Pure<Elm = A, M<A> = F> + Bind<Elm = A, M<A> = F> + Clone + Debug + 'static,
+    A: Clone,
+{
+    let bound = fa.clone().bind::<A, _>(|x| <F as Pure>::pure(x.clone()));
+    verify(eq.eqv(&bound, &fa), "monad right identity", || {
+        format!("fa = {:?}", fa)
+    })
+}
+
+/// `Bind`の法則に加えて、`Monad`の単位元の法則を検査します。
+pub fn check_monad<F, A, G, H>(f: G, g: H, eq: &Eq<F>) -> LawResult
+where
+    F: Monad + Pure<Elm = A, M<A> = F> + Bind<Elm = A, M<A> = F> + Arbitrary + Debug,
+    A: Arbitrary + Debug,
+    G: Fn(&A) -> F,
+    H: Fn(&A) -> F,
+{
+    check_bind(&f, &g, eq)?;
+    check(|a: A| monad_left_identity(a, &f, eq))?;
+    check(|fa: F| monad_right_identity(fa, eq))
+}
+
+// --- Foldable
+
+/// `fold_left`と`fold_right`が同じ順序で要素を走査することを検査します。
+pub fn foldable_consistency<F, A>(fa: F, eq: &Eq<A>) -> LawResult
+where
+    F: Foldable<Elm = A> + Debug,
+    A: Clone + 'static,
+{
+    let left = fa.fold_left(Vec::new(), |mut acc, a| {
+        acc.push(a.clone());
+        acc
+    });
+    let mut right = fa.fold_right(Vec::new(), |a, mut acc| {
+        acc.push(a.clone());
+        acc
+    });
+    right.reverse();
+    let holds = left.len() == right.len() && left.iter().zip(&right).all(|(l, r)| eq.eqv(l, r));
+    verify(holds, "foldable consistency", || format!("fa = {:?}", fa))
+}
+
+pub fn check_foldable<F, A>(eq: &Eq<A>) -> LawResult
+where
+    F: Foldable<Elm = A> + Arbitrary + Debug,
+    A: Clone + 'static,
+{
+    check(|fa: F| foldable_consistency(fa, eq))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::eq::Eq;
+    use crate::laws::{self, LawViolation};
+    use crate::{Semigroup, Validated, Writer};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Diff(u32);
+
+    impl Semigroup for Diff {
+        fn combine(self, other: Self) -> Self {
+            Diff(self.0.wrapping_sub(other.0))
+        }
+    }
+
+    impl quickcheck::Arbitrary for Diff {
+        fn arbitrary<G: quickcheck::Gen>(g: &mut G) -> Self {
+            Diff(u32::arbitrary(g))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            Box::new(self.0.shrink().map(Diff))
+        }
+    }
+
+    #[test]
+    fn test_lawful_instances() {
+        assert_eq!(laws::check_monoid::<Vec<i32>>(&Eq::natural()), Ok(()));
+        assert_eq!(laws::check_monoid::<i64>(&Eq::natural()), Ok(()));
+        assert_eq!(
+            laws::check_functor::<Option<i32>, _, _, _>(
+                |x| x.wrapping_add(1),
+                |x| x.wrapping_mul(3),
+                &Eq::natural()
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            laws::check_applicative::<Result<i32, String>, _, _>(
+                |x| x.wrapping_sub(7),
+                &Eq::natural()
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            laws::check_monad::<Vec<i32>, _, _, _>(
+                |x| vec![*x, x.wrapping_add(1)],
+                |x| if x % 3 == 0 { vec![] } else { vec![*x] },
+                &Eq::natural()
+            ),
+            Ok(())
+        );
+        assert_eq!(laws::check_foldable::<Vec<i32>, _>(&Eq::natural()), Ok(()));
+    }
+
+    #[test]
+    fn test_lawful_data_types() {
+        let tell = |x: &i32| Writer::new(vec![*x], x.wrapping_mul(2));
+        let half = |x: &i32| Writer::new(vec![-1], x / 2);
+        let eq = Eq::natural();
+        assert_eq!(laws::monad_left_identity(7, tell, &eq), Ok(()));
+        assert_eq!(
+            laws::monad_right_identity(Writer::new(vec![1, 2], 3), &eq),
+            Ok(())
+        );
+        assert_eq!(
+            laws::bind_associativity(Writer::new(vec![0], 5), tell, half, &eq),
+            Ok(())
+        );
+        assert_eq!(
+            laws::applicative_interchange::<Validated<Vec<String>, i32>, _, fn(&i32) -> i32>(
+                1,
+                Validated::Invalid(vec!["e".to_string()]),
+                &Eq::natural()
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_violation_reports_law_and_shrunk_input() {
+        let violation = laws::check_semigroup::<Diff>(&Eq::natural()).unwrap_err();
+        assert_eq!(violation.law(), "semigroup associativity");
+        let expected = LawViolation::new(
+            "semigroup associativity",
+            "a = Diff(0), b = Diff(0), c = Diff(1)".to_string(),
+        );
+        assert_eq!(violation, expected);
+        assert_eq!(
+            violation.to_string(),
+            "semigroup associativity law does not hold for a = Diff(0), b = Diff(0), c = Diff(1)"
+        );
+    }
+}
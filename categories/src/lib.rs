@@ -1,3 +1,5 @@
+extern crate self as rust_fp_categories;
+
 #[cfg(test)]
 extern crate quickcheck;
 
@@ -6,41 +8,92 @@ extern crate quickcheck;
 extern crate quickcheck_macros;
 
 // 型クラス階層の基本構造については hierarchy.md を参照してください
+mod alternative;
 mod applicative;
 mod apply;
 pub mod r#async;
+mod bifunctor;
 mod bind;
+mod collections;
 mod common;
 pub mod common_optimized;
+mod comonad;
+mod contravariant;
+mod coproduct;
 mod empty;
+pub mod eq;
 mod foldable;
 mod for_yield;
+mod free;
 mod functor;
+mod generic;
+pub mod hash;
 mod hlist;
+#[cfg(feature = "laws")]
+pub mod laws;
 mod macros;
 mod monad;
+mod monad_error;
 mod monoid;
+mod newtypes;
+mod non_empty_vec;
+pub mod optics;
+mod option_t;
+pub mod order;
+mod profunctor;
 mod pure;
+mod reader;
+mod reader_t;
+mod result_t;
 mod semigroup;
 mod show;
+mod state;
+mod state_t;
 mod tailrec;
+mod traverse;
+mod validated;
+mod writer;
+mod writer_t;
 
+pub use alternative::*;
 pub use applicative::*;
 pub use apply::*;
+pub use bifunctor::*;
 pub use bind::*;
 pub use common::*;
+pub use comonad::*;
+pub use contravariant::*;
+pub use coproduct::*;
 // pub use common_optimized as common_opt;
 pub use empty::*;
 pub use foldable::*;
 pub use for_yield::*;
+pub use free::*;
 pub use functor::*;
+pub use generic::*;
 pub use hlist::*;
 pub use monad::*;
+pub use monad_error::*;
 pub use monoid::*;
+pub use newtypes::*;
+pub use non_empty_vec::*;
+pub use option_t::*;
+pub use profunctor::*;
 pub use pure::*;
+pub use reader::*;
+pub use reader_t::*;
+pub use result_t::*;
 pub use semigroup::*;
 pub use show::*;
+pub use state::*;
+pub use state_t::*;
 pub use tailrec::*;
+pub use traverse::*;
+pub use validated::*;
+pub use writer::*;
+pub use writer_t::*;
+
+pub use rust_fp_derive::{Foldable, Functor, Generic, Monoid, Semigroup, Show};
 
 #[cfg(test)]
 mod tests {
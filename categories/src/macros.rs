@@ -934,3 +934,124 @@ macro_rules! impl_show_for_numeric {
         }
     };
 }
+
+/// Implements Empty and Monoid for the numeric newtypes Sum, Product, Min and Max
+#[macro_export]
+macro_rules! impl_monoid_for_numeric_newtypes {
+    ($($t:ty => $zero:expr, $one:expr, $lowest:expr, $highest:expr;)*) => {
+        $(
+            impl Empty for Sum<$t> {
+                fn empty() -> Self {
+                    Sum($zero)
+                }
+                fn is_empty(&self) -> bool {
+                    self.0 == $zero
+                }
+            }
+            impl Monoid for Sum<$t> {}
+
+            impl Empty for Product<$t> {
+                fn empty() -> Self {
+                    Product($one)
+                }
+                fn is_empty(&self) -> bool {
+                    self.0 == $one
+                }
+            }
+            impl Monoid for Product<$t> {}
+
+            impl Empty for Min<$t> {
+                fn empty() -> Self {
+                    Min($highest)
+                }
+                fn is_empty(&self) -> bool {
+                    self.0 == $highest
+                }
+            }
+            impl Monoid for Min<$t> {}
+
+            impl Empty for Max<$t> {
+                fn empty() -> Self {
+                    Max($lowest)
+                }
+                fn is_empty(&self) -> bool {
+                    self.0 == $lowest
+                }
+            }
+            impl Monoid for Max<$t> {}
+        )*
+    };
+}
+
+/// Implements Semigroup, Empty and Monoid component-wise for tuples up to arity 12
+#[macro_export]
+macro_rules! impl_monoid_for_tuples {
+    () => {
+        impl_monoid_for_tuples!(A 0);
+        impl_monoid_for_tuples!(A 0, B 1);
+        impl_monoid_for_tuples!(A 0, B 1, C 2);
+        impl_monoid_for_tuples!(A 0, B 1, C 2, D 3);
+        impl_monoid_for_tuples!(A 0, B 1, C 2, D 3, E 4);
+        impl_monoid_for_tuples!(A 0, B 1, C 2, D 3, E 4, F 5);
+        impl_monoid_for_tuples!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+        impl_monoid_for_tuples!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+        impl_monoid_for_tuples!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+        impl_monoid_for_tuples!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+        impl_monoid_for_tuples!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+        impl_monoid_for_tuples!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+    };
+    ($($t:ident $i:tt),+) => {
+        impl<$($t: Semigroup),+> Semigroup for ($($t,)+) {
+            fn combine(self, other: Self) -> Self {
+                ($(self.$i.combine(other.$i),)+)
+            }
+        }
+        impl<$($t: Empty),+> Empty for ($($t,)+) {
+            fn empty() -> Self {
+                ($($t::empty(),)+)
+            }
+            fn is_empty(&self) -> bool {
+                true $(&& self.$i.is_empty())+
+            }
+        }
+        impl<$($t: Monoid),+> Monoid for ($($t,)+) {}
+    };
+}
+
+/// Implements From conversions between tuples and HLists up to arity 12
+#[macro_export]
+macro_rules! impl_hlist_tuple_conversions {
+    () => {
+        impl_hlist_tuple_conversions!(A a 0);
+        impl_hlist_tuple_conversions!(A a 0, B b 1);
+        impl_hlist_tuple_conversions!(A a 0, B b 1, C c 2);
+        impl_hlist_tuple_conversions!(A a 0, B b 1, C c 2, D d 3);
+        impl_hlist_tuple_conversions!(A a 0, B b 1, C c 2, D d 3, E e 4);
+        impl_hlist_tuple_conversions!(A a 0, B b 1, C c 2, D d 3, E e 4, F f 5);
+        impl_hlist_tuple_conversions!(A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6);
+        impl_hlist_tuple_conversions!(A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6, H h 7);
+        impl_hlist_tuple_conversions!(A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6, H h 7, I i 8);
+        impl_hlist_tuple_conversions!(A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6, H h 7, I i 8, J j 9);
+        impl_hlist_tuple_conversions!(A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6, H h 7, I i 8, J j 9, K k 10);
+        impl_hlist_tuple_conversions!(A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6, H h 7, I i 8, J j 9, K k 10, L l 11);
+    };
+    ($($t:ident $v:ident $i:tt),+) => {
+        impl<$($t),+> From<($($t,)+)> for $crate::Hlist!($($t),+) {
+            fn from(tuple: ($($t,)+)) -> Self {
+                $crate::hlist![$(tuple.$i),+]
+            }
+        }
+
+        impl<$($t),+> From<$crate::Hlist!($($t),+)> for ($($t,)+) {
+            fn from(list: $crate::Hlist!($($t),+)) -> Self {
+                let rest = list;
+                $(
+                    let $v = rest.head;
+                    let rest = rest.tail;
+                )+
+                let $crate::HNil = rest;
+                ($($v,)+)
+            }
+        }
+    };
+}
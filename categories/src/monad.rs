@@ -1,6 +1,6 @@
 use std::rc::Rc;
 
-use crate::{Applicative, Bind};
+use crate::{Applicative, ApplicativeFamily, Apply, Bind};
 
 /// Monadは、ApplicativeとBindを組み合わせた型クラスです。
 ///
@@ -42,6 +42,53 @@ impl<A: Clone> Monad for Option<A> {}
 impl<A: Clone, E> Monad for Result<A, E> {}
 impl<A: Clone> Monad for Vec<A> {}
 
+/// MonadFamilyは、Monadの型コンストラクタ`M<_>`全体に対する`bind`を提供する型クラスです。
+///
+/// `ApplicativeFamily`と同じく、`Apply::M<U>`に対して直接`bind`を提供します。
+/// `OptionT`などのモナド変換子は、内側のモナドをこの型クラスで受け取ります。
+/// `Self`は型コンストラクタの代表として使われ、要素の型には意味がありません。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::MonadFamily;
+///
+/// let result = <Vec<()> as MonadFamily>::bind_of(vec![1, 2], |n| vec![n, n * 10]);
+/// assert_eq!(result, vec![1, 10, 2, 20]);
+/// ```
+pub trait MonadFamily: ApplicativeFamily + Monad {
+    fn bind_of<U: Clone, V: Clone, F>(fu: <Self as Apply>::M<U>, f: F) -> <Self as Apply>::M<V>
+    where
+        F: Fn(U) -> <Self as Apply>::M<V>;
+}
+
+impl<A: Clone> MonadFamily for Option<A> {
+    fn bind_of<U: Clone, V: Clone, F>(fu: Option<U>, f: F) -> Option<V>
+    where
+        F: Fn(U) -> Option<V>,
+    {
+        fu.and_then(f)
+    }
+}
+
+impl<A: Clone, E> MonadFamily for Result<A, E> {
+    fn bind_of<U: Clone, V: Clone, F>(fu: Result<U, E>, f: F) -> Result<V, E>
+    where
+        F: Fn(U) -> Result<V, E>,
+    {
+        fu.and_then(f)
+    }
+}
+
+impl<A: Clone> MonadFamily for Vec<A> {
+    fn bind_of<U: Clone, V: Clone, F>(fu: Vec<U>, f: F) -> Vec<V>
+    where
+        F: Fn(U) -> Vec<V>,
+    {
+        fu.into_iter().flat_map(f).collect()
+    }
+}
+
 #[cfg(test)]
 mod laws {
     use crate::{Bind, Pure};
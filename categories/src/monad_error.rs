@@ -0,0 +1,272 @@
+use crate::{Applicative, Apply, Bind, Monad};
+
+/// ApplicativeErrorは、エラーの送出と回復を提供する型クラスです。
+///
+/// # 型クラス階層における位置
+///
+/// ApplicativeErrorはApplicativeを拡張した型クラスで、MonadErrorの基礎となります：
+/// ```text
+///               Applicative
+///                  /    \
+///                 v      v
+///   ApplicativeError    Monad
+///                  \    /
+///                   v  v
+///                MonadError
+/// ```
+///
+/// # ApplicativeErrorの法則
+///
+/// 1. 送出したエラーは`handle_error_with`で捕捉される
+///    ```rust,ignore
+///    F::raise_error(e).handle_error_with(f) == f(&e)
+///    ```
+///
+/// 2. 成功した値は`handle_error_with`の影響を受けない
+///    ```rust,ignore
+///    F::pure(a).handle_error_with(f) == F::pure(a)
+///    ```
+///
+/// 3. `attempt`はエラーを値として取り出す
+///    ```rust,ignore
+///    F::raise_error(e).attempt() == F::pure(Err(e))
+///    ```
+///
+/// # 型パラメータ
+///
+/// * `E` - エラーの型
+///
+/// # メソッド
+///
+/// * `raise_error` - エラーを送出する
+/// * `handle_error_with` - エラーを別の計算で回復する
+/// * `attempt` - エラーを`Result`として値の側に取り出す
+/// * `recover` - エラーを値で回復する
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::ApplicativeError;
+///
+/// let result: Result<i32, String> = Result::raise_error("error".to_string());
+/// assert_eq!(result.clone().recover(|e| e.len() as i32), Ok(5));
+/// assert_eq!(result.attempt(), Ok(Err("error".to_string())));
+/// ```
+pub trait ApplicativeError<E>: Applicative {
+    fn raise_error(e: E) -> Self;
+
+    fn handle_error_with<F>(self, f: F) -> Self
+    where
+        F: Fn(&E) -> Self;
+
+    fn attempt(self) -> <Self as Apply>::M<Result<<Self as Apply>::Elm, E>>
+    where
+        <Self as Apply>::Elm: Clone,
+        E: Clone;
+
+    fn recover<F>(self, f: F) -> Self
+    where
+        F: Fn(&E) -> <Self as Apply>::Elm;
+}
+
+/// MonadErrorは、ApplicativeErrorとMonadを組み合わせた型クラスです。
+///
+/// # MonadErrorの法則
+///
+/// ApplicativeErrorの法則に加えて、以下の法則を満たす必要があります：
+///
+/// 1. 左零元：送出したエラーに対するbindはエラーを返す
+///    ```rust,ignore
+///    F::raise_error(e).bind(f) == F::raise_error(e)
+///    ```
+///
+/// # メソッド
+///
+/// * `ensure` - 値が条件を満たさない場合にエラーを送出する
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::MonadError;
+///
+/// let check = |n: i32| Ok::<i32, String>(n).ensure(|n| *n > 0, || "negative".to_string());
+/// assert_eq!(check(1), Ok(1));
+/// assert_eq!(check(-1), Err("negative".to_string()));
+/// ```
+pub trait MonadError<E>: Monad + ApplicativeError<E> {
+    fn ensure<P, G>(self, predicate: P, error: G) -> Self
+    where
+        P: Fn(&<Self as Bind>::Elm) -> bool,
+        G: FnOnce() -> E;
+}
+
+/// `Result<A, E>`に対するApplicativeErrorの実装
+impl<A: Clone, E> ApplicativeError<E> for Result<A, E> {
+    fn raise_error(e: E) -> Self {
+        Err(e)
+    }
+
+    fn handle_error_with<F>(self, f: F) -> Self
+    where
+        F: Fn(&E) -> Self,
+    {
+        match self {
+            Ok(a) => Ok(a),
+            Err(e) => f(&e),
+        }
+    }
+
+    fn attempt(self) -> Result<Result<A, E>, E> {
+        Ok(self)
+    }
+
+    fn recover<F>(self, f: F) -> Self
+    where
+        F: Fn(&E) -> A,
+    {
+        match self {
+            Ok(a) => Ok(a),
+            Err(e) => Ok(f(&e)),
+        }
+    }
+}
+
+impl<A: Clone, E> MonadError<E> for Result<A, E> {
+    fn ensure<P, G>(self, predicate: P, error: G) -> Self
+    where
+        P: Fn(&A) -> bool,
+        G: FnOnce() -> E,
+    {
+        match self {
+            Ok(a) if predicate(&a) => Ok(a),
+            Ok(_) => Err(error()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// `Option<A>`に対するApplicativeErrorの実装
+///
+/// `None`を`()`型のエラーとして扱います。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::ApplicativeError;
+///
+/// let value: Option<i32> = Option::raise_error(());
+/// assert_eq!(value.recover(|_| 0), Some(0));
+/// assert_eq!(Some(1).attempt(), Some(Ok(1)));
+/// ```
+impl<A: Clone> ApplicativeError<()> for Option<A> {
+    fn raise_error(_: ()) -> Self {
+        None
+    }
+
+    fn handle_error_with<F>(self, f: F) -> Self
+    where
+        F: Fn(&()) -> Self,
+    {
+        match self {
+            Some(a) => Some(a),
+            None => f(&()),
+        }
+    }
+
+    fn attempt(self) -> Option<Result<A, ()>> {
+        Some(self.ok_or(()))
+    }
+
+    fn recover<F>(self, f: F) -> Self
+    where
+        F: Fn(&()) -> A,
+    {
+        match self {
+            Some(a) => Some(a),
+            None => Some(f(&())),
+        }
+    }
+}
+
+impl<A: Clone> MonadError<()> for Option<A> {
+    fn ensure<P, G>(self, predicate: P, _error: G) -> Self
+    where
+        P: Fn(&A) -> bool,
+        G: FnOnce(),
+    {
+        self.filter(predicate)
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    mod result {
+        use crate::{ApplicativeError, Bind, MonadError, Pure};
+
+        #[quickcheck]
+        fn raise_error_handled(e: String) -> bool {
+            Result::<i32, String>::raise_error(e.clone()).handle_error_with(|e| Ok(e.len() as i32))
+                == Ok(e.len() as i32)
+        }
+
+        #[quickcheck]
+        fn pure_not_handled(n: i32) -> bool {
+            Result::<i32, String>::pure(n).handle_error_with(|_| Ok(0)) == Ok(n)
+        }
+
+        #[quickcheck]
+        fn raise_error_attempt(e: String) -> bool {
+            Result::<i32, String>::raise_error(e.clone()).attempt() == Ok(Err(e))
+        }
+
+        #[quickcheck]
+        fn raise_error_left_zero(e: String) -> bool {
+            Result::<i32, String>::raise_error(e.clone()).bind(|x| Ok::<i32, String>(*x + 1))
+                == Err(e)
+        }
+
+        #[quickcheck]
+        fn recover_consistency(n: Result<i32, String>) -> bool {
+            n.clone().recover(|e| e.len() as i32) == n.handle_error_with(|e| Ok(e.len() as i32))
+        }
+
+        #[quickcheck]
+        fn ensure_consistency(n: Result<i32, String>) -> bool {
+            let expected = n.clone().bind(|x| {
+                if *x > 0 {
+                    Ok(*x)
+                } else {
+                    Err("non positive".to_string())
+                }
+            });
+            n.ensure(|x| *x > 0, || "non positive".to_string()) == expected
+        }
+    }
+
+    mod option {
+        use crate::{ApplicativeError, Bind, MonadError, Pure};
+
+        #[quickcheck]
+        fn raise_error_handled(n: i32) -> bool {
+            Option::<i32>::raise_error(()).handle_error_with(|_| Some(n)) == Some(n)
+        }
+
+        #[quickcheck]
+        fn pure_not_handled(n: i32) -> bool {
+            Option::<i32>::pure(n).handle_error_with(|_| None) == Some(n)
+        }
+
+        #[quickcheck]
+        fn raise_error_left_zero(n: i32) -> bool {
+            Option::<i32>::raise_error(())
+                .bind(|x| Some(*x + n))
+                .is_none()
+        }
+
+        #[quickcheck]
+        fn ensure_consistency(n: Option<i32>) -> bool {
+            let expected = n.bind(|x| if *x > 0 { Some(*x) } else { None });
+            n.ensure(|x| *x > 0, || ()) == expected
+        }
+    }
+}
@@ -1,16 +1,27 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
 use crate::{Empty, Semigroup};
 
 pub trait Monoid: Empty + Semigroup {}
 
-use crate::impl_marker_trait_for_numeric;
+use crate::{impl_marker_trait_for_numeric, impl_monoid_for_tuples};
 
 impl_marker_trait_for_numeric!(Monoid);
+impl_monoid_for_tuples!();
 
 impl<T: Clone> Monoid for Vec<T> {}
 impl Monoid for String {}
+impl Monoid for std::cmp::Ordering {}
+impl<A: Semigroup> Monoid for Option<A> {}
+impl<A, E: Monoid> Monoid for Result<A, E> {}
+impl<K: Eq + Hash, V: Semigroup, S: BuildHasher + Default> Monoid for HashMap<K, V, S> {}
+impl<K: Ord, V: Semigroup> Monoid for BTreeMap<K, V> {}
 
 #[cfg(test)]
 mod laws {
+    use std::collections::{BTreeMap, HashMap};
+
     use crate::{Empty, Semigroup};
 
     #[quickcheck]
@@ -22,4 +33,135 @@ mod laws {
     fn monoid_right_identity(n: i32) {
         assert_eq!(n.combine(i32::empty()), n)
     }
+
+    macro_rules! composite_laws {
+        ($name:ident, $t:ty) => {
+            mod $name {
+                use super::*;
+
+                #[quickcheck]
+                fn semigroup_associativity(a: $t, b: $t, c: $t) {
+                    assert_eq!(
+                        a.clone().combine(b.clone()).combine(c.clone()),
+                        a.combine(b.combine(c))
+                    )
+                }
+
+                #[quickcheck]
+                fn monoid_identity(a: $t) {
+                    assert_eq!(<$t>::empty().combine(a.clone()), a);
+                    assert_eq!(a.clone().combine(<$t>::empty()), a)
+                }
+            }
+        };
+    }
+
+    composite_laws!(option, Option<String>);
+    composite_laws!(result, Result<i32, String>);
+    composite_laws!(hash_map, HashMap<u8, String>);
+    composite_laws!(btree_map, BTreeMap<u8, Vec<i32>>);
+    composite_laws!(tuple, (i32, String, Option<Vec<u8>>));
+
+    #[derive(crate::Semigroup, crate::Monoid, Debug, PartialEq, Clone)]
+    struct Report<K: Ord> {
+        visits: u32,
+        pages: BTreeMap<K, u32>,
+        note: Option<String>,
+    }
+
+    #[test]
+    fn test_derived_semigroup_and_monoid() {
+        use crate::Foldable;
+
+        let report = |visits, page: &'static str, note: Option<&str>| Report {
+            visits,
+            pages: vec![(page, 1)].into_iter().collect(),
+            note: note.map(|n| n.to_string()),
+        };
+        let a = report(1, "/", Some("a"));
+        let b = report(2, "/docs", None);
+        let c = report(3, "/", Some("c"));
+
+        let total = vec![a.clone(), b, c].combine_all();
+        assert_eq!(total.visits, 6);
+        assert_eq!(
+            total.pages,
+            vec![("/", 2), ("/docs", 1)].into_iter().collect()
+        );
+        assert_eq!(total.note, Some("ac".to_string()));
+
+        let empty = Report::<&str>::empty();
+        assert!(empty.is_empty());
+        assert!(!a.is_empty());
+        assert_eq!(empty.clone().combine(a.clone()), a);
+        assert_eq!(a.clone().combine(empty), a);
+    }
+
+    #[test]
+    fn test_first_success() {
+        let failed: Result<i32, String> = Err("a".to_string());
+        assert_eq!(failed.clone().combine(Ok(1)).combine(Ok(2)), Ok(1));
+        assert_eq!(failed.combine(Err("b".to_string())), Err("ab".to_string()));
+    }
+
+    #[test]
+    fn test_key_wise_merge() {
+        let a: HashMap<&str, i32> = vec![("x", 1), ("y", 2)].into_iter().collect();
+        let b: HashMap<&str, i32> = vec![("y", 10), ("z", 3)].into_iter().collect();
+        let expected: HashMap<&str, i32> =
+            vec![("x", 1), ("y", 12), ("z", 3)].into_iter().collect();
+        assert_eq!(a.combine(b), expected);
+    }
+
+    #[test]
+    fn test_tuple12() {
+        type T = (
+            i32,
+            i32,
+            i32,
+            i32,
+            i32,
+            i32,
+            String,
+            String,
+            String,
+            String,
+            String,
+            Vec<u8>,
+        );
+        let a: T = (
+            1,
+            2,
+            3,
+            4,
+            5,
+            6,
+            "a".into(),
+            "b".into(),
+            "c".into(),
+            "d".into(),
+            "e".into(),
+            vec![1],
+        );
+        let b: T = (
+            1,
+            1,
+            1,
+            1,
+            1,
+            1,
+            "f".into(),
+            "g".into(),
+            "h".into(),
+            "i".into(),
+            "j".into(),
+            vec![2],
+        );
+        let combined = a.clone().combine(b);
+        assert_eq!(combined.5, 7);
+        assert_eq!(combined.10, "ej");
+        assert_eq!(combined.11, vec![1, 2]);
+        assert_eq!(T::empty().combine(a.clone()), a);
+        assert!(T::empty().is_empty());
+    }
 }
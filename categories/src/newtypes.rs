@@ -0,0 +1,415 @@
+use std::fmt;
+use std::ops::{Add, Mul};
+use std::rc::Rc;
+
+use crate::{impl_monoid_for_numeric_newtypes, Empty, Monoid, Semigroup, Show};
+
+/// 加算で結合する`Semigroup`/`Monoid`のラッパーです。
+///
+/// 数値型の`Semigroup`は加算に固定されているため、結合方法を使う場所ごとに選びたい場合は、
+/// `Sum`、`Product`、`Min`、`Max`などのラッパーで値を包んでから結合します。
+/// 単位元(`Empty`)は数値型に対して定義されています。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{Empty, Foldable, Max, Min, Semigroup, Sum};
+///
+/// let latencies = vec![120, 80, 200, 95];
+///
+/// let total = latencies.fold_left(Sum::empty(), |acc, ms| acc.combine(Sum(*ms)));
+/// let fastest = latencies.fold_left(Min::empty(), |acc, ms| acc.combine(Min(*ms)));
+/// let slowest = latencies.fold_left(Max::empty(), |acc, ms| acc.combine(Max(*ms)));
+///
+/// assert_eq!(total, Sum(495));
+/// assert_eq!(fastest, Min(80));
+/// assert_eq!(slowest, Max(200));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Sum<A>(pub A);
+
+/// 乗算で結合する`Semigroup`/`Monoid`のラッパーです。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Product<A>(pub A);
+
+/// 小さい方を残す`Semigroup`/`Monoid`のラッパーです。
+///
+/// 比較できない値(浮動小数点数の`NaN`など)や等しい値の場合は、左側の値を残します。
+/// 単位元は、数値型の最大値(浮動小数点数では正の無限大)です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Min<A>(pub A);
+
+/// 大きい方を残す`Semigroup`/`Monoid`のラッパーです。
+///
+/// 比較できない値(浮動小数点数の`NaN`など)や等しい値の場合は、左側の値を残します。
+/// 単位元は、数値型の最小値(浮動小数点数では負の無限大)です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Max<A>(pub A);
+
+/// 最初の値を残す`Semigroup`のラッパーです。
+///
+/// 単位元を持たないため`Monoid`ではありません。空の場合も扱うには`Option<First<A>>`を使います。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct First<A>(pub A);
+
+/// 最後の値を残す`Semigroup`のラッパーです。
+///
+/// 単位元を持たないため`Monoid`ではありません。空の場合も扱うには`Option<Last<A>>`を使います。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Last<A>(pub A);
+
+/// 論理和で結合する`Monoid`のラッパーです。単位元は`Any(false)`です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Any(pub bool);
+
+/// 論理積で結合する`Monoid`のラッパーです。単位元は`All(true)`です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct All(pub bool);
+
+/// 包んだ`Semigroup`/`Monoid`の結合順序を逆にするラッパーです。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{Dual, Semigroup};
+///
+/// let joined = Dual("a".to_string()).combine(Dual("b".to_string()));
+/// assert_eq!(joined, Dual("ba".to_string()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dual<A>(pub A);
+
+/// 関数合成で結合する`Monoid`のラッパーです。
+///
+/// `f.combine(g)`は、`g`を適用してから`f`を適用する関数になります。
+/// 単位元は恒等関数です。適用順序を逆にしたい場合は`Dual<Endo<A>>`を使います。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{Dual, Empty, Endo, Semigroup};
+///
+/// let add_one = Endo::new(|n: i32| n + 1);
+/// let double = Endo::new(|n: i32| n * 2);
+///
+/// assert_eq!(add_one.clone().combine(double.clone()).run(5), 11);
+/// assert_eq!(Dual(add_one).combine(Dual(double)).0.run(5), 12);
+/// assert_eq!(Endo::empty().run(5), 5);
+/// ```
+pub struct Endo<A> {
+    f: Option<Rc<dyn Fn(A) -> A>>,
+}
+
+impl<A: 'static> Endo<A> {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(A) -> A + 'static,
+    {
+        Endo {
+            f: Some(Rc::new(f)),
+        }
+    }
+
+    /// 関数を値に適用します。
+    pub fn run(&self, a: A) -> A {
+        match &self.f {
+            Some(f) => f(a),
+            None => a,
+        }
+    }
+}
+
+impl<A> Clone for Endo<A> {
+    fn clone(&self) -> Self {
+        Endo { f: self.f.clone() }
+    }
+}
+
+impl<A> fmt::Debug for Endo<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Endo")
+    }
+}
+
+// --- Semigroup
+
+impl<A: Add<Output = A>> Semigroup for Sum<A> {
+    fn combine(self, other: Self) -> Self {
+        Sum(self.0 + other.0)
+    }
+}
+
+impl<A: Mul<Output = A>> Semigroup for Product<A> {
+    fn combine(self, other: Self) -> Self {
+        Product(self.0 * other.0)
+    }
+}
+
+impl<A: PartialOrd> Semigroup for Min<A> {
+    fn combine(self, other: Self) -> Self {
+        if other.0 < self.0 {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl<A: PartialOrd> Semigroup for Max<A> {
+    fn combine(self, other: Self) -> Self {
+        if other.0 > self.0 {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl<A> Semigroup for First<A> {
+    fn combine(self, _other: Self) -> Self {
+        self
+    }
+}
+
+impl<A> Semigroup for Last<A> {
+    fn combine(self, other: Self) -> Self {
+        other
+    }
+}
+
+impl Semigroup for Any {
+    fn combine(self, other: Self) -> Self {
+        Any(self.0 || other.0)
+    }
+}
+
+impl Semigroup for All {
+    fn combine(self, other: Self) -> Self {
+        All(self.0 && other.0)
+    }
+}
+
+impl<A: Semigroup> Semigroup for Dual<A> {
+    fn combine(self, other: Self) -> Self {
+        Dual(other.0.combine(self.0))
+    }
+}
+
+impl<A: 'static> Semigroup for Endo<A> {
+    fn combine(self, other: Self) -> Self {
+        match (self.f, other.f) {
+            (None, g) => Endo { f: g },
+            (f, None) => Endo { f },
+            (Some(f), Some(g)) => Endo::new(move |a| f(g(a))),
+        }
+    }
+}
+
+// --- Monoid
+
+impl_monoid_for_numeric_newtypes! {
+    usize => 0, 1, usize::MIN, usize::MAX;
+    u8 => 0, 1, u8::MIN, u8::MAX;
+    u16 => 0, 1, u16::MIN, u16::MAX;
+    u32 => 0, 1, u32::MIN, u32::MAX;
+    u64 => 0, 1, u64::MIN, u64::MAX;
+    u128 => 0, 1, u128::MIN, u128::MAX;
+    isize => 0, 1, isize::MIN, isize::MAX;
+    i8 => 0, 1, i8::MIN, i8::MAX;
+    i16 => 0, 1, i16::MIN, i16::MAX;
+    i32 => 0, 1, i32::MIN, i32::MAX;
+    i64 => 0, 1, i64::MIN, i64::MAX;
+    i128 => 0, 1, i128::MIN, i128::MAX;
+    f32 => 0.0, 1.0, f32::NEG_INFINITY, f32::INFINITY;
+    f64 => 0.0, 1.0, f64::NEG_INFINITY, f64::INFINITY;
+}
+
+impl Empty for Any {
+    fn empty() -> Self {
+        Any(false)
+    }
+
+    fn is_empty(&self) -> bool {
+        !self.0
+    }
+}
+
+impl Monoid for Any {}
+
+impl Empty for All {
+    fn empty() -> Self {
+        All(true)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0
+    }
+}
+
+impl Monoid for All {}
+
+impl<A: Empty> Empty for Dual<A> {
+    fn empty() -> Self {
+        Dual(A::empty())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<A: Monoid> Monoid for Dual<A> {}
+
+impl<A> Empty for Endo<A> {
+    fn empty() -> Self {
+        Endo { f: None }
+    }
+
+    /// 恒等関数(`Endo::empty()`、またはそれどうしを結合したもの)の場合に`true`を返します。
+    fn is_empty(&self) -> bool {
+        self.f.is_none()
+    }
+}
+
+impl<A: 'static> Monoid for Endo<A> {}
+
+// --- Show
+
+macro_rules! impl_show_for_newtype {
+    ($($name:ident),*) => {
+        $(
+            impl<A: Show> Show for $name<A> {
+                type Elm = A;
+
+                fn show(self) -> String {
+                    format!("{}({})", stringify!($name), self.0.show())
+                }
+            }
+        )*
+    };
+}
+
+impl_show_for_newtype!(Sum, Product, Min, Max, First, Last, Dual);
+
+impl Show for Any {
+    type Elm = bool;
+
+    fn show(self) -> String {
+        format!("Any({})", self.0)
+    }
+}
+
+impl Show for All {
+    type Elm = bool;
+
+    fn show(self) -> String {
+        format!("All({})", self.0)
+    }
+}
+
+impl<A> Show for Endo<A> {
+    type Elm = A;
+
+    fn show(self) -> String {
+        "Endo(<function>)".to_string()
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::{
+        All, Any, Dual, Empty, Endo, First, Last, Max, Min, Monoid, Product, Semigroup, Sum,
+    };
+
+    fn identity<A: Monoid + Copy + PartialEq>(a: A) -> bool {
+        A::empty().combine(a) == a && a.combine(A::empty()) == a
+    }
+
+    fn dual_min(n: i16) -> Dual<Min<i16>> {
+        Dual(Min(n))
+    }
+
+    macro_rules! monoid_laws {
+        ($name:ident, $wrap:expr, $t:ty) => {
+            mod $name {
+                use super::*;
+
+                #[quickcheck]
+                fn semigroup_associativity(a: $t, b: $t, c: $t) -> bool {
+                    let (a, b, c) = ($wrap(a), $wrap(b), $wrap(c));
+                    a.combine(b).combine(c) == a.combine(b.combine(c))
+                }
+
+                #[quickcheck]
+                fn monoid_identity(a: $t) -> bool {
+                    identity($wrap(a))
+                }
+            }
+        };
+    }
+
+    monoid_laws!(sum, Sum, i64);
+    monoid_laws!(product, Product, i64);
+    monoid_laws!(min, Min, i32);
+    monoid_laws!(max, Max, i32);
+    monoid_laws!(any, Any, bool);
+    monoid_laws!(all, All, bool);
+    monoid_laws!(dual, dual_min, i16);
+
+    #[quickcheck]
+    fn first_last_associativity(a: u8, b: u8, c: u8) -> bool {
+        First(a).combine(First(b)).combine(First(c)) == First(a).combine(First(b).combine(First(c)))
+            && Last(a).combine(Last(b)).combine(Last(c))
+                == Last(a).combine(Last(b).combine(Last(c)))
+    }
+
+    #[quickcheck]
+    fn dual_reverses_combine(a: String, b: String) -> bool {
+        Dual(a.clone()).combine(Dual(b.clone())) == Dual(b.combine(a))
+    }
+
+    #[quickcheck]
+    fn endo_associativity(n: i32) -> bool {
+        let f = Endo::new(|x: i32| x.wrapping_add(3));
+        let g = Endo::new(|x: i32| x.wrapping_mul(5));
+        let h = Endo::new(|x: i32| x.wrapping_sub(7));
+        let left = f.clone().combine(g.clone()).combine(h.clone());
+        let right = f.combine(g.combine(h));
+        left.run(n) == right.run(n)
+    }
+
+    #[quickcheck]
+    fn endo_identity(n: i32) -> bool {
+        let f = Endo::new(|x: i32| x.wrapping_add(3));
+        Endo::empty().combine(f.clone()).run(n) == f.run(n)
+            && f.clone().combine(Endo::empty()).run(n) == f.run(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Dual, Empty, Endo, Foldable, Max, Min, Semigroup, Show, Sum};
+
+    #[test]
+    fn test_float_min_max_identity() {
+        let values = vec![1.5, -2.0, 0.25];
+        let min = values.fold_left(Min::empty(), |acc, x| acc.combine(Min(*x)));
+        let max = values.fold_left(Max::empty(), |acc, x| acc.combine(Max(*x)));
+        assert_eq!(min, Min(-2.0));
+        assert_eq!(max, Max(1.5));
+        assert!(Min::<f64>::empty().is_empty());
+    }
+
+    #[test]
+    fn test_endo_empty_stays_empty() {
+        let identity: Endo<i32> = Endo::empty().combine(Endo::empty());
+        assert!(identity.is_empty());
+        assert!(!Endo::new(|x: i32| x).is_empty());
+    }
+
+    #[test]
+    fn test_show() {
+        assert_eq!(Sum(3).show(), "Sum(3)");
+        assert_eq!(Dual(Max(2.5)).show(), "Dual(Max(2.5))");
+    }
+}
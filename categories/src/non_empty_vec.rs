@@ -0,0 +1,286 @@
+use std::ops::ControlFlow;
+
+use crate::{Applicative, Apply, Bind, Foldable, Functor, Monad, Pure, Semigroup};
+
+/// 少なくとも1つの要素を持つベクトルです。
+///
+/// 先頭要素を常に持つため、`head`は失敗しません。
+///
+/// `Apply`は、`Bind`と整合するように、関数と値のすべての組み合わせに適用します。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::NonEmptyVec;
+///
+/// let values = NonEmptyVec::new(1, vec![2, 3]);
+/// assert_eq!(*values.head(), 1);
+/// assert_eq!(values.tail(), &[2, 3]);
+/// assert_eq!(values.len(), 3);
+/// assert_eq!(values.reduce(), 6);
+///
+/// assert_eq!(NonEmptyVec::from_vec(vec![1, 2]), Some(NonEmptyVec::new(1, vec![2])));
+/// assert_eq!(NonEmptyVec::<i32>::from_vec(vec![]), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonEmptyVec<A> {
+    head: A,
+    tail: Vec<A>,
+}
+
+impl<A> NonEmptyVec<A> {
+    pub fn new(head: A, tail: Vec<A>) -> Self {
+        NonEmptyVec { head, tail }
+    }
+
+    pub fn singleton(head: A) -> Self {
+        NonEmptyVec::new(head, Vec::new())
+    }
+
+    /// `Vec`が空でなければ、`NonEmptyVec`に変換します。
+    pub fn from_vec(values: Vec<A>) -> Option<Self> {
+        let mut values = values.into_iter();
+        values
+            .next()
+            .map(|head| NonEmptyVec::new(head, values.collect()))
+    }
+
+    pub fn head(&self) -> &A {
+        &self.head
+    }
+
+    pub fn tail(&self) -> &[A] {
+        &self.tail
+    }
+
+    pub fn len(&self) -> usize {
+        self.tail.len() + 1
+    }
+
+    /// 常に`false`を返します。
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &A> {
+        std::iter::once(&self.head).chain(self.tail.iter())
+    }
+
+    pub fn to_vec(self) -> Vec<A> {
+        let mut result = Vec::with_capacity(self.len());
+        result.push(self.head);
+        result.extend(self.tail);
+        result
+    }
+
+    /// 初期値を使わずに、すべての要素を`Semigroup::combine`で結合します。
+    pub fn reduce(self) -> A
+    where
+        A: Semigroup,
+    {
+        self.tail
+            .into_iter()
+            .fold(self.head, |acc, a| acc.combine(a))
+    }
+}
+
+impl<A> From<NonEmptyVec<A>> for Vec<A> {
+    fn from(values: NonEmptyVec<A>) -> Self {
+        values.to_vec()
+    }
+}
+
+impl<A> Semigroup for NonEmptyVec<A> {
+    fn combine(self, other: Self) -> Self {
+        let mut tail = self.tail;
+        tail.push(other.head);
+        tail.extend(other.tail);
+        NonEmptyVec {
+            head: self.head,
+            tail,
+        }
+    }
+}
+
+impl<A> Functor for NonEmptyVec<A> {
+    type Elm = A;
+    type M<B: Clone> = NonEmptyVec<B>;
+
+    fn fmap<B: Clone, F>(self, f: F) -> NonEmptyVec<B>
+    where
+        F: Fn(&A) -> B,
+    {
+        NonEmptyVec {
+            head: f(&self.head),
+            tail: self.tail.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<A> Apply for NonEmptyVec<A> {
+    type Elm = A;
+    type M<B: Clone> = NonEmptyVec<B>;
+
+    fn ap<B: Clone, F>(self, fs: NonEmptyVec<F>) -> NonEmptyVec<B>
+    where
+        F: Clone + Fn(&A) -> B,
+    {
+        fs.bind(|f| NonEmptyVec {
+            head: f(&self.head),
+            tail: self.tail.iter().map(f).collect(),
+        })
+    }
+}
+
+impl<A: Clone> Pure for NonEmptyVec<A> {
+    type Elm = A;
+    type M<U: Clone> = NonEmptyVec<U>;
+
+    fn pure(value: A) -> NonEmptyVec<A> {
+        NonEmptyVec::singleton(value)
+    }
+
+    fn unit() -> NonEmptyVec<()> {
+        NonEmptyVec::singleton(())
+    }
+}
+
+impl<A> Bind for NonEmptyVec<A> {
+    type Elm = A;
+    type M<B: Clone> = NonEmptyVec<B>;
+
+    fn bind<B: Clone, F>(self, f: F) -> NonEmptyVec<B>
+    where
+        F: Fn(&A) -> NonEmptyVec<B>,
+    {
+        self.tail
+            .iter()
+            .fold(f(&self.head), |acc, a| acc.combine(f(a)))
+    }
+}
+
+impl<A: Clone> Applicative for NonEmptyVec<A> {}
+impl<A: Clone> Monad for NonEmptyVec<A> {}
+
+impl<A> Foldable for NonEmptyVec<A> {
+    type Elm = A;
+
+    fn fold_left<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(B, &A) -> B,
+    {
+        self.iter().fold(b, f)
+    }
+
+    fn fold_right<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(&A, B) -> B,
+    {
+        self.tail
+            .iter()
+            .rev()
+            .chain(std::iter::once(&self.head))
+            .fold(b, |b, a| f(a, b))
+    }
+
+    fn try_fold<B, C, F>(&self, b: B, f: F) -> ControlFlow<C, B>
+    where
+        F: Fn(B, &A) -> ControlFlow<C, B>,
+    {
+        self.iter().try_fold(b, f)
+    }
+
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<A: Clone> NonEmptyVec<A> {
+    /// 先頭要素を除いた残りが空でなければ、それを`NonEmptyVec`として返します。
+    pub(crate) fn tail_non_empty(&self) -> Option<NonEmptyVec<A>> {
+        self.tail
+            .split_first()
+            .map(|(head, tail)| NonEmptyVec::new(head.clone(), tail.to_vec()))
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::{Apply, Bind, Foldable, Functor, NonEmptyVec, Pure, Semigroup};
+
+    fn f(n: &i32) -> NonEmptyVec<i32> {
+        NonEmptyVec::new(*n, vec![n.wrapping_mul(2)])
+    }
+
+    fn g(n: &i32) -> NonEmptyVec<i32> {
+        NonEmptyVec::singleton(n.wrapping_sub(1))
+    }
+
+    #[quickcheck]
+    fn functor_identity(head: i32, tail: Vec<i32>) -> bool {
+        let xs = NonEmptyVec::new(head, tail);
+        xs.clone().fmap(|x| *x) == xs
+    }
+
+    #[quickcheck]
+    fn applicative_identity(head: i32, tail: Vec<i32>) -> bool {
+        let xs = NonEmptyVec::new(head, tail);
+        xs.clone().ap(NonEmptyVec::pure(|x: &i32| *x)) == xs
+    }
+
+    #[quickcheck]
+    fn ap_consistent_with_bind(head: i32, tail: Vec<i32>) -> bool {
+        let xs = NonEmptyVec::new(head, tail);
+        let fs: NonEmptyVec<fn(&i32) -> i32> =
+            NonEmptyVec::new(|x| x.wrapping_add(1), vec![|x| x.wrapping_mul(3)]);
+        xs.clone().ap(fs.clone()) == fs.bind(|h| xs.clone().fmap(h))
+    }
+
+    #[quickcheck]
+    fn monad_left_identity(n: i32) -> bool {
+        NonEmptyVec::pure(n).bind(f) == f(&n)
+    }
+
+    #[quickcheck]
+    fn monad_right_identity(head: i32, tail: Vec<i32>) -> bool {
+        let xs = NonEmptyVec::new(head, tail);
+        xs.clone().bind(|x| NonEmptyVec::pure(*x)) == xs
+    }
+
+    #[quickcheck]
+    fn monad_associativity(head: i32, tail: Vec<i32>) -> bool {
+        let xs = NonEmptyVec::new(head, tail);
+        xs.clone().bind(f).bind(g) == xs.bind(|x| f(x).bind(g))
+    }
+
+    #[quickcheck]
+    fn semigroup_associativity(a: Vec<i32>, b: Vec<i32>, c: Vec<i32>) -> bool {
+        let a = NonEmptyVec::new(0, a);
+        let b = NonEmptyVec::new(1, b);
+        let c = NonEmptyVec::new(2, c);
+        a.clone().combine(b.clone()).combine(c.clone()) == a.combine(b.combine(c))
+    }
+
+    #[quickcheck]
+    fn reduce_matches_fold(head: i64, tail: Vec<i64>) -> bool {
+        let xs = NonEmptyVec::new(head, tail.clone());
+        xs.reduce() == tail.iter().fold(head, |acc, x| acc.wrapping_add(*x))
+    }
+
+    #[quickcheck]
+    fn fold_right_order(head: i32, tail: Vec<i32>) -> bool {
+        let xs = NonEmptyVec::new(head, tail);
+        xs.fold_right(Vec::new(), |x, mut acc| {
+            acc.insert(0, *x);
+            acc
+        }) == xs.clone().to_vec()
+    }
+
+    #[quickcheck]
+    fn from_vec_round_trip(values: Vec<i32>) -> bool {
+        match NonEmptyVec::from_vec(values.clone()) {
+            Some(xs) => Vec::from(xs) == values,
+            None => values.is_empty(),
+        }
+    }
+}
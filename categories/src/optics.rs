@@ -0,0 +1,876 @@
+//! 不変なデータ構造の一部に焦点を当てて、値の取得と更新を行う光学系(Optics)です。
+//!
+//! * `Iso` - 2つの型の間の可逆な変換
+//! * `Lens` - 必ず存在する1つの部分(構造体のフィールドなど)
+//! * `Prism` - 存在するとは限らない、列挙型の1つのバリアント
+//! * `Optional` - 存在するとは限らない1つの部分(キーに対応する値など)
+//! * `Traversal` - 0個以上の部分(コレクションの全要素など)
+//!
+//! すべての光学系は`Compose::and_then`で互いに合成でき、合成結果は両者を表現できる
+//! 最も具体的な光学系になります(例: `Lens`と`Prism`の合成は`Optional`)。
+//!
+//! # 例
+//!
+//! ```
+//! use rust_fp_categories::lens;
+//! use rust_fp_categories::optics::{self, Compose};
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! struct Address {
+//!     city: String,
+//! }
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! struct Person {
+//!     name: String,
+//!     address: Option<Address>,
+//! }
+//!
+//! let city = lens!(Person, address)
+//!     .and_then(optics::some())
+//!     .and_then(lens!(Address, city));
+//!
+//! let alice = Person {
+//!     name: "alice".to_string(),
+//!     address: Some(Address { city: "Tokyo".to_string() }),
+//! };
+//! let moved = city.set(alice, "Osaka".to_string());
+//! assert_eq!(city.get_option(&moved), Some("Osaka".to_string()));
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::rc::Rc;
+
+use crate::{Foldable, Functor};
+
+type GetOption<S, A> = Rc<dyn Fn(&S) -> Option<A>>;
+type GetAll<S, A> = Rc<dyn Fn(&S) -> Vec<A>>;
+type Modify<S, A> = Rc<dyn Fn(S, &dyn Fn(A) -> A) -> S>;
+
+/// 2つの型`S`と`A`の間の可逆な変換です。
+///
+/// `get`と`reverse_get`は互いに逆関数である必要があります。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::optics::Iso;
+///
+/// let celsius = Iso::new(|f: &f64| (f - 32.0) / 1.8, |c: f64| c * 1.8 + 32.0);
+/// assert_eq!(celsius.get(&212.0), 100.0);
+/// assert_eq!(celsius.modify(32.0, |c| c + 10.0), 50.0);
+/// ```
+pub struct Iso<S, A> {
+    get: Rc<dyn Fn(&S) -> A>,
+    reverse_get: Rc<dyn Fn(A) -> S>,
+}
+
+impl<S: 'static, A: 'static> Iso<S, A> {
+    pub fn new<G, R>(get: G, reverse_get: R) -> Self
+    where
+        G: Fn(&S) -> A + 'static,
+        R: Fn(A) -> S + 'static,
+    {
+        Iso {
+            get: Rc::new(get),
+            reverse_get: Rc::new(reverse_get),
+        }
+    }
+
+    pub fn get(&self, s: &S) -> A {
+        (self.get)(s)
+    }
+
+    pub fn reverse_get(&self, a: A) -> S {
+        (self.reverse_get)(a)
+    }
+
+    pub fn modify<F>(&self, s: S, f: F) -> S
+    where
+        F: FnOnce(A) -> A,
+    {
+        self.reverse_get(f(self.get(&s)))
+    }
+
+    /// 変換の向きを逆にした`Iso`を返します。
+    pub fn reverse(self) -> Iso<A, S>
+    where
+        A: Clone,
+    {
+        let get = self.get;
+        let reverse_get = self.reverse_get;
+        Iso::new(move |a: &A| reverse_get(a.clone()), move |s: S| get(&s))
+    }
+}
+
+/// 構造`S`の中に必ず存在する部分`A`に焦点を当てます。
+///
+/// `lens!`マクロで構造体のフィールドに対する`Lens`を作成できます。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::optics::Lens;
+///
+/// let first = Lens::new(|p: &(i32, String)| p.0, |p: (i32, String), a| (a, p.1));
+/// assert_eq!(first.get(&(1, "a".to_string())), 1);
+/// assert_eq!(first.modify((1, "a".to_string()), |n| n + 1), (2, "a".to_string()));
+/// ```
+pub struct Lens<S, A> {
+    get: Rc<dyn Fn(&S) -> A>,
+    set: Rc<dyn Fn(S, A) -> S>,
+}
+
+impl<S: 'static, A: 'static> Lens<S, A> {
+    pub fn new<G, F>(get: G, set: F) -> Self
+    where
+        G: Fn(&S) -> A + 'static,
+        F: Fn(S, A) -> S + 'static,
+    {
+        Lens {
+            get: Rc::new(get),
+            set: Rc::new(set),
+        }
+    }
+
+    pub fn get(&self, s: &S) -> A {
+        (self.get)(s)
+    }
+
+    pub fn set(&self, s: S, a: A) -> S {
+        (self.set)(s, a)
+    }
+
+    pub fn modify<F>(&self, s: S, f: F) -> S
+    where
+        F: FnOnce(A) -> A,
+    {
+        let a = self.get(&s);
+        self.set(s, f(a))
+    }
+}
+
+/// 列挙型`S`の1つのバリアント`A`に焦点を当てます。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::optics;
+///
+/// let ok = optics::ok::<i32, String>();
+/// assert_eq!(ok.get_option(&Ok(1)), Some(1));
+/// assert_eq!(ok.modify(Err("e".to_string()), |n| n + 1), Err("e".to_string()));
+/// assert_eq!(ok.reverse_get(2), Ok(2));
+/// ```
+pub struct Prism<S, A> {
+    get_option: GetOption<S, A>,
+    reverse_get: Rc<dyn Fn(A) -> S>,
+}
+
+impl<S: 'static, A: 'static> Prism<S, A> {
+    pub fn new<G, R>(get_option: G, reverse_get: R) -> Self
+    where
+        G: Fn(&S) -> Option<A> + 'static,
+        R: Fn(A) -> S + 'static,
+    {
+        Prism {
+            get_option: Rc::new(get_option),
+            reverse_get: Rc::new(reverse_get),
+        }
+    }
+
+    pub fn get_option(&self, s: &S) -> Option<A> {
+        (self.get_option)(s)
+    }
+
+    pub fn reverse_get(&self, a: A) -> S {
+        (self.reverse_get)(a)
+    }
+
+    /// バリアントが一致する場合だけ値を置き換えます。
+    pub fn set(&self, s: S, a: A) -> S {
+        match self.get_option(&s) {
+            Some(_) => self.reverse_get(a),
+            None => s,
+        }
+    }
+
+    pub fn modify<F>(&self, s: S, f: F) -> S
+    where
+        F: FnOnce(A) -> A,
+    {
+        match self.get_option(&s) {
+            Some(a) => self.reverse_get(f(a)),
+            None => s,
+        }
+    }
+}
+
+/// 構造`S`の中に存在するとは限らない1つの部分`A`に焦点を当てます。
+///
+/// # 例
+///
+/// ```
+/// use std::collections::HashMap;
+/// use rust_fp_categories::optics;
+///
+/// let alice = optics::index::<HashMap<&str, i32>, _, _>("alice");
+/// let scores: HashMap<&str, i32> = vec![("alice", 1)].into_iter().collect();
+/// assert_eq!(alice.modify(scores, |n| n + 10)["alice"], 11);
+/// assert_eq!(alice.get_option(&HashMap::new()), None);
+/// ```
+pub struct Optional<S, A> {
+    get_option: GetOption<S, A>,
+    set: Rc<dyn Fn(S, A) -> S>,
+}
+
+impl<S: 'static, A: 'static> Optional<S, A> {
+    pub fn new<G, F>(get_option: G, set: F) -> Self
+    where
+        G: Fn(&S) -> Option<A> + 'static,
+        F: Fn(S, A) -> S + 'static,
+    {
+        Optional {
+            get_option: Rc::new(get_option),
+            set: Rc::new(set),
+        }
+    }
+
+    pub fn get_option(&self, s: &S) -> Option<A> {
+        (self.get_option)(s)
+    }
+
+    /// 焦点が存在する場合だけ値を置き換えます。
+    pub fn set(&self, s: S, a: A) -> S {
+        (self.set)(s, a)
+    }
+
+    pub fn modify<F>(&self, s: S, f: F) -> S
+    where
+        F: FnOnce(A) -> A,
+    {
+        match self.get_option(&s) {
+            Some(a) => self.set(s, f(a)),
+            None => s,
+        }
+    }
+}
+
+/// 構造`S`の中の0個以上の部分`A`に焦点を当てます。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::optics;
+///
+/// let each = optics::each::<Vec<i32>, i32>();
+/// assert_eq!(each.modify(vec![1, 2, 3], |n| n * 2), vec![2, 4, 6]);
+/// assert_eq!(each.get_all(&vec![1, 2]), vec![1, 2]);
+/// ```
+pub struct Traversal<S, A> {
+    get_all: GetAll<S, A>,
+    modify: Modify<S, A>,
+}
+
+impl<S: 'static, A: 'static> Traversal<S, A> {
+    pub fn new<G, M>(get_all: G, modify: M) -> Self
+    where
+        G: Fn(&S) -> Vec<A> + 'static,
+        M: Fn(S, &dyn Fn(A) -> A) -> S + 'static,
+    {
+        Traversal {
+            get_all: Rc::new(get_all),
+            modify: Rc::new(modify),
+        }
+    }
+
+    pub fn get_all(&self, s: &S) -> Vec<A> {
+        (self.get_all)(s)
+    }
+
+    pub fn modify<F>(&self, s: S, f: F) -> S
+    where
+        F: Fn(A) -> A,
+    {
+        (self.modify)(s, &f)
+    }
+
+    /// すべての焦点を同じ値で置き換えます。
+    pub fn set(&self, s: S, a: A) -> S
+    where
+        A: Clone,
+    {
+        self.modify(s, move |_| a.clone())
+    }
+}
+
+macro_rules! impl_clone_and_debug_for_optics {
+    ($($name:ident { $($field:ident),* }),*) => {
+        $(
+            impl<S, A> Clone for $name<S, A> {
+                fn clone(&self) -> Self {
+                    $name {
+                        $($field: Rc::clone(&self.$field)),*
+                    }
+                }
+            }
+
+            impl<S, A> fmt::Debug for $name<S, A> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, stringify!($name))
+                }
+            }
+        )*
+    };
+}
+
+impl_clone_and_debug_for_optics!(
+    Iso { get, reverse_get },
+    Lens { get, set },
+    Prism {
+        get_option,
+        reverse_get
+    },
+    Optional { get_option, set },
+    Traversal { get_all, modify }
+);
+
+impl<S: 'static, A: 'static> From<Iso<S, A>> for Lens<S, A> {
+    fn from(iso: Iso<S, A>) -> Self {
+        let get = Rc::clone(&iso.get);
+        Lens::new(move |s: &S| get(s), move |_, a| iso.reverse_get(a))
+    }
+}
+
+impl<S: 'static, A: 'static> From<Iso<S, A>> for Prism<S, A> {
+    fn from(iso: Iso<S, A>) -> Self {
+        let get = Rc::clone(&iso.get);
+        Prism::new(move |s: &S| Some(get(s)), move |a| iso.reverse_get(a))
+    }
+}
+
+impl<S: 'static, A: 'static> From<Iso<S, A>> for Optional<S, A> {
+    fn from(iso: Iso<S, A>) -> Self {
+        Optional::from(Lens::from(iso))
+    }
+}
+
+impl<S: 'static, A: 'static> From<Iso<S, A>> for Traversal<S, A> {
+    fn from(iso: Iso<S, A>) -> Self {
+        Traversal::from(Optional::from(iso))
+    }
+}
+
+impl<S: 'static, A: 'static> From<Lens<S, A>> for Optional<S, A> {
+    fn from(lens: Lens<S, A>) -> Self {
+        let get = Rc::clone(&lens.get);
+        Optional::new(move |s: &S| Some(get(s)), move |s, a| lens.set(s, a))
+    }
+}
+
+impl<S: 'static, A: 'static> From<Lens<S, A>> for Traversal<S, A> {
+    fn from(lens: Lens<S, A>) -> Self {
+        Traversal::from(Optional::from(lens))
+    }
+}
+
+impl<S: 'static, A: 'static> From<Prism<S, A>> for Optional<S, A> {
+    fn from(prism: Prism<S, A>) -> Self {
+        let get_option = Rc::clone(&prism.get_option);
+        Optional::new(move |s: &S| get_option(s), move |s, a| prism.set(s, a))
+    }
+}
+
+impl<S: 'static, A: 'static> From<Prism<S, A>> for Traversal<S, A> {
+    fn from(prism: Prism<S, A>) -> Self {
+        Traversal::from(Optional::from(prism))
+    }
+}
+
+impl<S: 'static, A: 'static> From<Optional<S, A>> for Traversal<S, A> {
+    fn from(optional: Optional<S, A>) -> Self {
+        let get_option = Rc::clone(&optional.get_option);
+        Traversal::new(
+            move |s: &S| get_option(s).into_iter().collect(),
+            move |s, f| optional.modify(s, f),
+        )
+    }
+}
+
+/// 光学系どうしの合成を表す型クラスです。
+///
+/// `self`で`S`の中の`A`に焦点を当て、続けて`other`で`A`の中の`B`に焦点を当てます。
+/// 結果の型`Output`は、両者を表現できる最も具体的な光学系です。
+///
+/// | `self` \ `other` | Iso | Lens | Prism | Optional | Traversal |
+/// |---|---|---|---|---|---|
+/// | Iso | Iso | Lens | Prism | Optional | Traversal |
+/// | Lens | Lens | Lens | Optional | Optional | Traversal |
+/// | Prism | Prism | Optional | Prism | Optional | Traversal |
+/// | Optional | Optional | Optional | Optional | Optional | Traversal |
+/// | Traversal | Traversal | Traversal | Traversal | Traversal | Traversal |
+pub trait Compose<Other> {
+    type Output;
+
+    fn and_then(self, other: Other) -> Self::Output;
+}
+
+impl<S: 'static, A: 'static, B: 'static> Compose<Iso<A, B>> for Iso<S, A> {
+    type Output = Iso<S, B>;
+
+    fn and_then(self, other: Iso<A, B>) -> Iso<S, B> {
+        let reverse_get = Rc::clone(&self.reverse_get);
+        let other_get = Rc::clone(&other.get);
+        Iso::new(
+            move |s: &S| other_get(&self.get(s)),
+            move |b| reverse_get(other.reverse_get(b)),
+        )
+    }
+}
+
+impl<S: 'static, A: 'static, B: 'static> Compose<Lens<A, B>> for Lens<S, A> {
+    type Output = Lens<S, B>;
+
+    fn and_then(self, other: Lens<A, B>) -> Lens<S, B> {
+        let get = Rc::clone(&self.get);
+        let other_get = Rc::clone(&other.get);
+        Lens::new(
+            move |s: &S| other_get(&get(s)),
+            move |s, b| {
+                let a = self.get(&s);
+                self.set(s, other.set(a, b))
+            },
+        )
+    }
+}
+
+impl<S: 'static, A: 'static, B: 'static> Compose<Prism<A, B>> for Prism<S, A> {
+    type Output = Prism<S, B>;
+
+    fn and_then(self, other: Prism<A, B>) -> Prism<S, B> {
+        let reverse_get = Rc::clone(&self.reverse_get);
+        let other_get_option = Rc::clone(&other.get_option);
+        Prism::new(
+            move |s: &S| self.get_option(s).and_then(|a| other_get_option(&a)),
+            move |b| reverse_get(other.reverse_get(b)),
+        )
+    }
+}
+
+impl<S: 'static, A: 'static, B: 'static> Compose<Optional<A, B>> for Optional<S, A> {
+    type Output = Optional<S, B>;
+
+    fn and_then(self, other: Optional<A, B>) -> Optional<S, B> {
+        let get_option = Rc::clone(&self.get_option);
+        let other_get_option = Rc::clone(&other.get_option);
+        Optional::new(
+            move |s: &S| get_option(s).and_then(|a| other_get_option(&a)),
+            move |s, b| match self.get_option(&s) {
+                Some(a) => self.set(s, other.set(a, b)),
+                None => s,
+            },
+        )
+    }
+}
+
+impl<S: 'static, A: 'static, B: 'static> Compose<Traversal<A, B>> for Traversal<S, A> {
+    type Output = Traversal<S, B>;
+
+    fn and_then(self, other: Traversal<A, B>) -> Traversal<S, B> {
+        let get_all = Rc::clone(&self.get_all);
+        let other_get_all = Rc::clone(&other.get_all);
+        Traversal::new(
+            move |s: &S| get_all(s).iter().flat_map(|a| other_get_all(a)).collect(),
+            move |s, f| self.modify(s, |a| other.modify(a, f)),
+        )
+    }
+}
+
+// 異なる種類の光学系の合成は、両者を`Output`に変換してから同じ種類どうしで合成します。
+macro_rules! impl_compose_via {
+    ($($left:ident + $right:ident => $output:ident),* $(,)?) => {
+        $(
+            impl<S: 'static, A: 'static, B: 'static> Compose<$right<A, B>> for $left<S, A> {
+                type Output = $output<S, B>;
+
+                fn and_then(self, other: $right<A, B>) -> $output<S, B> {
+                    $output::from(self).and_then($output::from(other))
+                }
+            }
+        )*
+    };
+}
+
+impl_compose_via!(
+    Iso + Lens => Lens,
+    Iso + Prism => Prism,
+    Iso + Optional => Optional,
+    Iso + Traversal => Traversal,
+    Lens + Iso => Lens,
+    Lens + Prism => Optional,
+    Lens + Optional => Optional,
+    Lens + Traversal => Traversal,
+    Prism + Iso => Prism,
+    Prism + Lens => Optional,
+    Prism + Optional => Optional,
+    Prism + Traversal => Traversal,
+    Optional + Iso => Optional,
+    Optional + Lens => Optional,
+    Optional + Prism => Optional,
+    Optional + Traversal => Traversal,
+    Traversal + Iso => Traversal,
+    Traversal + Lens => Traversal,
+    Traversal + Prism => Traversal,
+    Traversal + Optional => Traversal,
+);
+
+/// キーで要素を取り出し、既存の要素を置き換えられるコンテナを表す型クラスです。
+///
+/// `index`で作る`Optional`が使用します。
+pub trait Indexed<K, V> {
+    fn get_at(&self, key: &K) -> Option<V>;
+
+    /// `key`に要素が存在する場合だけ置き換えます。
+    fn replace_at(self, key: &K, value: V) -> Self
+    where
+        Self: Sized;
+}
+
+/// キーに対する要素の追加と削除ができるコンテナを表す型クラスです。
+///
+/// `at`で作る`Lens`が使用します。
+pub trait At<K, V>: Indexed<K, V> {
+    fn insert_at(self, key: K, value: V) -> Self;
+
+    fn remove_at(self, key: &K) -> Self;
+}
+
+impl<V: Clone> Indexed<usize, V> for Vec<V> {
+    fn get_at(&self, key: &usize) -> Option<V> {
+        self.get(*key).cloned()
+    }
+
+    fn replace_at(mut self, key: &usize, value: V) -> Self {
+        if let Some(slot) = self.get_mut(*key) {
+            *slot = value;
+        }
+        self
+    }
+}
+
+impl<K: Eq + Hash, V: Clone, S: BuildHasher> Indexed<K, V> for HashMap<K, V, S> {
+    fn get_at(&self, key: &K) -> Option<V> {
+        self.get(key).cloned()
+    }
+
+    fn replace_at(mut self, key: &K, value: V) -> Self {
+        if let Some(slot) = self.get_mut(key) {
+            *slot = value;
+        }
+        self
+    }
+}
+
+impl<K: Eq + Hash, V: Clone, S: BuildHasher> At<K, V> for HashMap<K, V, S> {
+    fn insert_at(mut self, key: K, value: V) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    fn remove_at(mut self, key: &K) -> Self {
+        self.remove(key);
+        self
+    }
+}
+
+impl<K: Ord, V: Clone> Indexed<K, V> for BTreeMap<K, V> {
+    fn get_at(&self, key: &K) -> Option<V> {
+        self.get(key).cloned()
+    }
+
+    fn replace_at(mut self, key: &K, value: V) -> Self {
+        if let Some(slot) = self.get_mut(key) {
+            *slot = value;
+        }
+        self
+    }
+}
+
+impl<K: Ord, V: Clone> At<K, V> for BTreeMap<K, V> {
+    fn insert_at(mut self, key: K, value: V) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    fn remove_at(mut self, key: &K) -> Self {
+        self.remove(key);
+        self
+    }
+}
+
+/// `Option`の`Some`に焦点を当てる`Prism`を作成します。
+pub fn some<A: Clone + 'static>() -> Prism<Option<A>, A> {
+    Prism::new(|s: &Option<A>| s.clone(), Some)
+}
+
+/// `Result`の`Ok`に焦点を当てる`Prism`を作成します。
+pub fn ok<A: Clone + 'static, E: 'static>() -> Prism<Result<A, E>, A> {
+    Prism::new(|s: &Result<A, E>| s.as_ref().ok().cloned(), Ok)
+}
+
+/// `Result`の`Err`に焦点を当てる`Prism`を作成します。
+pub fn err<A: 'static, E: Clone + 'static>() -> Prism<Result<A, E>, E> {
+    Prism::new(|s: &Result<A, E>| s.as_ref().err().cloned(), Err)
+}
+
+/// `key`に対応する要素に焦点を当てる`Optional`を作成します。
+///
+/// 要素が存在しない場合、`set`は何もしません。
+pub fn index<C, K, V>(key: K) -> Optional<C, V>
+where
+    C: Indexed<K, V> + 'static,
+    K: Clone + 'static,
+    V: 'static,
+{
+    let set_key = key.clone();
+    Optional::new(
+        move |c: &C| c.get_at(&key),
+        move |c: C, v| c.replace_at(&set_key, v),
+    )
+}
+
+/// `key`に対応する要素の有無に焦点を当てる`Lens`を作成します。
+///
+/// `None`を設定すると要素を削除し、`Some`を設定すると要素を追加または置き換えます。
+///
+/// # 例
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use rust_fp_categories::optics;
+///
+/// let apple = optics::at::<BTreeMap<String, u32>, _, _>("apple".to_string());
+/// let stock = apple.set(BTreeMap::new(), Some(3));
+/// assert_eq!(apple.get(&stock), Some(3));
+/// assert!(apple.set(stock, None).is_empty());
+/// ```
+pub fn at<C, K, V>(key: K) -> Lens<C, Option<V>>
+where
+    C: At<K, V> + 'static,
+    K: Clone + 'static,
+    V: 'static,
+{
+    let set_key = key.clone();
+    Lens::new(
+        move |c: &C| c.get_at(&key),
+        move |c: C, v| match v {
+            Some(v) => c.insert_at(set_key.clone(), v),
+            None => c.remove_at(&set_key),
+        },
+    )
+}
+
+/// `Functor`かつ`Foldable`であるコンテナの全要素に焦点を当てる`Traversal`を作成します。
+pub fn each<S, A>() -> Traversal<S, A>
+where
+    S: Functor<Elm = A, M<A> = S> + Foldable<Elm = A> + 'static,
+    A: Clone + 'static,
+{
+    Traversal::new(
+        |s: &S| Foldable::to_vec(s),
+        |s: S, f| s.fmap(|a: &A| f(a.clone())),
+    )
+}
+
+/// 構造体のフィールドに焦点を当てる`Lens`を作成するマクロです。
+///
+/// `lens!(型, フィールド)`の形式で使用します。`lens!(型, a.b.c)`のように
+/// ネストしたフィールドを指定することもできます。フィールドの型は`Clone`を実装している必要があります。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::lens;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Line {
+///     start: Point,
+///     end: Point,
+/// }
+///
+/// let start_x = lens!(Line, start.x);
+/// let line = Line { start: Point { x: 0, y: 0 }, end: Point { x: 5, y: 5 } };
+/// assert_eq!(start_x.modify(line, |x| x + 1).start, Point { x: 1, y: 0 });
+/// ```
+#[macro_export]
+macro_rules! lens {
+    ($s:ty, $($field:ident).+) => {
+        $crate::optics::Lens::new(
+            |s: &$s| s.$($field).+.clone(),
+            |mut s: $s, a| {
+                s.$($field).+ = a;
+                s
+            },
+        )
+    };
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::optics::{self, Compose, Iso, Lens};
+
+    fn second() -> Lens<(i32, String), String> {
+        Lens::new(
+            |s: &(i32, String)| s.1.clone(),
+            |s: (i32, String), a| (s.0, a),
+        )
+    }
+
+    #[quickcheck]
+    fn lens_get_set(n: i32, s: String) -> bool {
+        let pair = (n, s);
+        second().set(pair.clone(), second().get(&pair)) == pair
+    }
+
+    #[quickcheck]
+    fn lens_set_get(n: i32, s: String, a: String) -> bool {
+        second().get(&second().set((n, s), a.clone())) == a
+    }
+
+    #[quickcheck]
+    fn lens_set_set(n: i32, s: String, a: String, b: String) -> bool {
+        let lens = second();
+        lens.set(lens.set((n, s.clone()), a), b.clone()) == lens.set((n, s), b)
+    }
+
+    #[quickcheck]
+    fn prism_round_trip(value: Option<i32>, a: i32) -> bool {
+        let some = optics::some::<i32>();
+        value.and_then(|v| some.get_option(&some.reverse_get(v))) == value
+            && some.get_option(&some.reverse_get(a)) == Some(a)
+    }
+
+    #[quickcheck]
+    fn prism_modify_identity(value: Result<i32, String>) -> bool {
+        let ok = optics::ok::<i32, String>().and_then(Iso::new(|n: &i32| *n, |n| n));
+        ok.modify(value.clone(), |n| n) == value
+    }
+
+    #[quickcheck]
+    fn iso_round_trip(n: i32) -> bool {
+        let iso = Iso::new(|n: &i32| n.to_string(), |s: String| s.parse().unwrap());
+        iso.reverse_get(iso.get(&n)) == n && iso.reverse().get(&n.to_string()) == n
+    }
+
+    #[quickcheck]
+    fn traversal_modify_composition(values: Vec<i32>) -> bool {
+        let each = optics::each::<Vec<i32>, i32>();
+        let f = |n: i32| n.wrapping_add(1);
+        let g = |n: i32| n.wrapping_mul(3);
+        each.modify(each.modify(values.clone(), f), g) == each.modify(values, |n| g(f(n)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::optics::{self, Compose, Iso, Prism, Traversal};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Zip(String);
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Address {
+        city: String,
+        zip: Zip,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Person {
+        name: String,
+        addresses: Vec<Address>,
+        tags: HashMap<String, i32>,
+    }
+
+    fn alice() -> Person {
+        Person {
+            name: "alice".to_string(),
+            addresses: vec![
+                Address {
+                    city: "Tokyo".to_string(),
+                    zip: Zip("100".to_string()),
+                },
+                Address {
+                    city: "Osaka".to_string(),
+                    zip: Zip("530".to_string()),
+                },
+            ],
+            tags: vec![("age".to_string(), 20)].into_iter().collect(),
+        }
+    }
+
+    #[test]
+    fn test_lens_macro_with_nested_fields() {
+        let first_city = lens!(Person, addresses).and_then(optics::index::<Vec<Address>, _, _>(0));
+        let first_city = first_city.and_then(lens!(Address, city));
+        let updated = first_city.set(alice(), "Kyoto".to_string());
+        assert_eq!(updated.addresses[0].city, "Kyoto");
+        assert_eq!(updated.addresses[1], alice().addresses[1]);
+    }
+
+    #[test]
+    fn test_traversal_into_every_address() {
+        let zip = Iso::new(|z: &Zip| z.0.clone(), Zip);
+        let zips = lens!(Person, addresses)
+            .and_then(optics::each::<Vec<Address>, Address>())
+            .and_then(lens!(Address, zip))
+            .and_then(zip);
+        assert_eq!(zips.get_all(&alice()), vec!["100", "530"]);
+        let updated = zips.modify(alice(), |z| format!("{}-0000", z));
+        assert_eq!(updated.addresses[1].zip, Zip("530-0000".to_string()));
+    }
+
+    #[test]
+    fn test_at_and_index_on_map() {
+        let age = lens!(Person, tags)
+            .and_then(optics::at::<HashMap<String, i32>, _, _>("age".to_string()));
+        assert_eq!(age.get(&alice()), Some(20));
+        assert!(age.set(alice(), None).tags.is_empty());
+
+        let height = lens!(Person, tags).and_then(optics::index::<HashMap<String, i32>, _, _>(
+            "height".to_string(),
+        ));
+        assert_eq!(height.set(alice(), 170), alice());
+    }
+
+    #[test]
+    fn test_prism_composition() {
+        let nested: Prism<Option<Result<i32, String>>, i32> = optics::some().and_then(optics::ok());
+        assert_eq!(nested.get_option(&Some(Ok(1))), Some(1));
+        assert_eq!(nested.get_option(&Some(Err("e".to_string()))), None);
+        assert_eq!(nested.reverse_get(2), Some(Ok(2)));
+
+        let error: Prism<Result<i32, String>, usize> =
+            optics::err().and_then(Iso::new(|s: &String| s.len(), |n: usize| "e".repeat(n)));
+        assert_eq!(error.set(Err("abc".to_string()), 2), Err("ee".to_string()));
+    }
+
+    #[test]
+    fn test_everything_composes_into_traversal() {
+        let values: Traversal<Vec<Option<i32>>, i32> =
+            optics::each::<Vec<Option<i32>>, Option<i32>>().and_then(optics::some());
+        assert_eq!(
+            values.set(vec![Some(1), None, Some(3)], 0),
+            vec![Some(0), None, Some(0)]
+        );
+    }
+}
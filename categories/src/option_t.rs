@@ -0,0 +1,282 @@
+use std::fmt;
+
+use crate::{Applicative, ApplicativeFamily, Apply, Bind, Functor, Monad, MonadFamily, Pure};
+
+/// OptionTは、内側のモナドの中で値が存在しない可能性を扱うモナド変換子です。
+///
+/// `M<Option<A>>`を包み、`bind`は内側のモナドの作用を保ったまま`None`で計算を打ち切ります。
+/// 内側のモナドは`MonadFamily`を実装した代表の型で指定します(例: `Vec<()>`)。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{for_yield, Bind, OptionT, Pure};
+///
+/// type Search = OptionT<Vec<()>, i32>;
+///
+/// let result = for_yield! {
+///     bind a = Search::lift(vec![1, 2]);
+///     bind b = if a % 2 == 0 { Search::none() } else { Search::some(a * 10) };
+///     yield Search::pure(a + b)
+/// };
+/// assert_eq!(result.run(), vec![Some(11), None]);
+/// ```
+pub struct OptionT<M: Apply, A: Clone> {
+    value: <M as Apply>::M<Option<A>>,
+}
+
+impl<M: MonadFamily, A: Clone> OptionT<M, A> {
+    pub fn new(value: <M as Apply>::M<Option<A>>) -> Self {
+        OptionT { value }
+    }
+
+    /// 値を持つ計算を作成します。
+    pub fn some(value: A) -> Self {
+        OptionT::new(M::pure_of(Some(value)))
+    }
+
+    /// 値を持たない計算を作成します。
+    pub fn none() -> Self {
+        OptionT::new(M::pure_of(None))
+    }
+
+    /// 内側のモナドの計算を持ち上げます。
+    pub fn lift(ma: <M as Apply>::M<A>) -> Self {
+        OptionT::new(M::map_of(ma, Some))
+    }
+
+    /// 包んでいる`M<Option<A>>`を返します。
+    pub fn run(self) -> <M as Apply>::M<Option<A>> {
+        self.value
+    }
+
+    /// 値が存在しない場合に`default`で置き換え、内側のモナドの計算を返します。
+    pub fn get_or_else(self, default: A) -> <M as Apply>::M<A> {
+        M::map_of(self.value, move |a: Option<A>| {
+            a.unwrap_or_else(|| default.clone())
+        })
+    }
+}
+
+impl<M: Apply, A: Clone> Clone for OptionT<M, A>
+where
+    <M as Apply>::M<Option<A>>: Clone,
+{
+    fn clone(&self) -> Self {
+        OptionT {
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl<M: Apply, A: Clone> PartialEq for OptionT<M, A>
+where
+    <M as Apply>::M<Option<A>>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<M: Apply, A: Clone> fmt::Debug for OptionT<M, A>
+where
+    <M as Apply>::M<Option<A>>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("OptionT").field(&self.value).finish()
+    }
+}
+
+impl<M: MonadFamily, A: Clone> Functor for OptionT<M, A> {
+    type Elm = A;
+    type M<B: Clone> = OptionT<M, B>;
+
+    fn fmap<B: Clone, F>(self, f: F) -> OptionT<M, B>
+    where
+        F: Fn(&A) -> B,
+    {
+        OptionT::new(M::map_of(self.value, |a: Option<A>| a.map(|a| f(&a))))
+    }
+}
+
+impl<M: MonadFamily, A: Clone> Pure for OptionT<M, A> {
+    type Elm = A;
+    type M<U: Clone> = OptionT<M, U>;
+
+    fn pure(value: A) -> OptionT<M, A> {
+        OptionT::some(value)
+    }
+
+    fn unit() -> OptionT<M, ()> {
+        OptionT::some(())
+    }
+}
+
+impl<M: MonadFamily, A: Clone> Apply for OptionT<M, A>
+where
+    <M as Apply>::M<Option<A>>: Clone,
+{
+    type Elm = A;
+    type M<U: Clone> = OptionT<M, U>;
+
+    fn ap<B: Clone, F>(self, fs: OptionT<M, F>) -> OptionT<M, B>
+    where
+        F: Clone + Fn(&A) -> B,
+    {
+        let value = self.value;
+        OptionT::new(M::bind_of(fs.value, |f: Option<F>| match f {
+            Some(f) => M::map_of(value.clone(), move |a: Option<A>| a.map(|a| f(&a))),
+            None => M::pure_of(None),
+        }))
+    }
+}
+
+impl<M: MonadFamily, A: Clone> Bind for OptionT<M, A> {
+    type Elm = A;
+    type M<U: Clone> = OptionT<M, U>;
+
+    fn bind<B: Clone, F>(self, f: F) -> OptionT<M, B>
+    where
+        F: Fn(&A) -> OptionT<M, B>,
+    {
+        OptionT::new(M::bind_of(self.value, |a: Option<A>| match a {
+            Some(a) => f(&a).value,
+            None => M::pure_of(None),
+        }))
+    }
+}
+
+impl<M: MonadFamily, A: Clone> Applicative for OptionT<M, A> where <M as Apply>::M<Option<A>>: Clone {}
+
+impl<M: MonadFamily, A: Clone> Monad for OptionT<M, A> where <M as Apply>::M<Option<A>>: Clone {}
+
+impl<M: MonadFamily, A: Clone> ApplicativeFamily for OptionT<M, A>
+where
+    <M as Apply>::M<Option<A>>: Clone,
+{
+    fn pure_of<U: Clone>(value: U) -> OptionT<M, U> {
+        OptionT::some(value)
+    }
+
+    fn map_of<U: Clone, V: Clone, F>(fu: OptionT<M, U>, f: F) -> OptionT<M, V>
+    where
+        F: Fn(U) -> V,
+    {
+        OptionT::new(M::map_of(fu.value, |u: Option<U>| u.map(&f)))
+    }
+
+    fn map2<U: Clone, V: Clone, F>(fu: OptionT<M, U>, fa: Self, f: F) -> OptionT<M, V>
+    where
+        F: Fn(U, A) -> V,
+    {
+        let value = fa.value;
+        OptionT::new(M::bind_of(fu.value, |u: Option<U>| match u {
+            Some(u) => M::map_of(value.clone(), |a: Option<A>| a.map(|a| f(u.clone(), a))),
+            None => M::pure_of(None),
+        }))
+    }
+}
+
+impl<M: MonadFamily, A: Clone> MonadFamily for OptionT<M, A>
+where
+    <M as Apply>::M<Option<A>>: Clone,
+{
+    fn bind_of<U: Clone, V: Clone, F>(fu: OptionT<M, U>, f: F) -> OptionT<M, V>
+    where
+        F: Fn(U) -> OptionT<M, V>,
+    {
+        OptionT::new(M::bind_of(fu.value, |u: Option<U>| match u {
+            Some(u) => f(u).value,
+            None => M::pure_of(None),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::{Apply, Bind, Functor, OptionT, Pure};
+
+    type T = OptionT<Vec<()>, i32>;
+
+    fn f(n: &i32) -> T {
+        OptionT::new(vec![Some(*n), None, Some(n.wrapping_add(1))])
+    }
+
+    fn g(n: &i32) -> T {
+        if n % 3 == 0 {
+            OptionT::none()
+        } else {
+            OptionT::lift(vec![n.wrapping_mul(2), n.wrapping_sub(1)])
+        }
+    }
+
+    #[quickcheck]
+    fn functor_identity(values: Vec<Option<i32>>) -> bool {
+        let m = T::new(values);
+        m.clone().fmap(|x| *x) == m
+    }
+
+    #[quickcheck]
+    fn applicative_identity(values: Vec<Option<i32>>) -> bool {
+        let m = T::new(values);
+        m.clone().ap(OptionT::pure(|x: &i32| *x)) == m
+    }
+
+    #[quickcheck]
+    fn monad_left_identity(n: i32) -> bool {
+        T::pure(n).bind(f) == f(&n)
+    }
+
+    #[quickcheck]
+    fn monad_right_identity(values: Vec<Option<i32>>) -> bool {
+        let m = T::new(values);
+        m.clone().bind(|x| T::pure(*x)) == m
+    }
+
+    #[quickcheck]
+    fn monad_associativity(values: Vec<Option<i32>>) -> bool {
+        let m = T::new(values);
+        m.clone().bind(f).bind(g) == m.bind(|x| f(x).bind(g))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{for_yield, Bind, OptionT, Pure, ResultT};
+
+    #[test]
+    fn test_none_short_circuits_but_keeps_inner_effects() {
+        type T = OptionT<Result<(), String>, i32>;
+
+        let result = for_yield! {
+            bind a = T::some(1);
+            bind b = T::none();
+            yield T::pure(a + b)
+        };
+        assert_eq!(result.run(), Ok(None));
+
+        let failed = T::lift(Err("boom".to_string())).bind(|a| T::pure(a + 1));
+        assert_eq!(failed.run(), Err("boom".to_string()));
+    }
+
+    #[test]
+    fn test_get_or_else() {
+        let m = OptionT::<Vec<()>, i32>::new(vec![Some(1), None]);
+        assert_eq!(m.get_or_else(0), vec![1, 0]);
+    }
+
+    #[test]
+    fn test_stack_over_result_t() {
+        type Inner = ResultT<Vec<()>, String, ()>;
+        type T = OptionT<Inner, i32>;
+
+        let result = T::lift(ResultT::lift(vec![1, 2])).bind(|a| {
+            if *a == 1 {
+                T::none()
+            } else {
+                T::pure(a * 100)
+            }
+        });
+        assert_eq!(result.run().run(), vec![Ok(None), Ok(Some(200))]);
+    }
+}
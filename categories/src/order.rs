@@ -0,0 +1,218 @@
+//! 全順序を、型に対する実装ではなく明示的なインスタンス値として扱う型クラスです。
+//!
+//! `eq`モジュールと揃えるため、このモジュールもクレートのルートには再エクスポートしていません。
+//! `rust_fp_categories::order::Order`として参照してください。
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::eq::Eq;
+use crate::{Contravariant, Semigroup};
+
+/// 2つの値の順序を判定するインスタンスです。
+///
+/// `Ordering`は`Monoid`であり、`and_then`はその`combine`で2つの順序を辞書式に連結します。
+/// `pfds`の集合のように`PartialOrd`を要求されることなく、任意のキーで並べ替えることができます。
+///
+/// # Orderの法則
+///
+/// 1. 反対称律: `compare(a, b) == compare(b, a).reverse()`
+/// 2. 推移律: `compare(a, b) != Greater && compare(b, c) != Greater`ならば`compare(a, c) != Greater`
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::order::Order;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Employee {
+///     dept: String,
+///     age: u32,
+/// }
+///
+/// let employee = |dept: &str, age| Employee { dept: dept.to_string(), age };
+/// let mut employees = vec![employee("sales", 30), employee("dev", 25), employee("dev", 40)];
+///
+/// // 部署の昇順、同じ部署の中では年齢の降順
+/// let order = Order::by(|e: &Employee| e.dept.clone())
+///     .and_then(Order::by(|e: &Employee| e.age).reverse());
+/// order.sort(&mut employees);
+///
+/// assert_eq!(
+///     employees,
+///     vec![employee("dev", 40), employee("dev", 25), employee("sales", 30)]
+/// );
+/// assert_eq!(order.min(&employees[0], &employees[2]).dept, "dev");
+/// ```
+pub struct Order<A> {
+    f: OrderFn<A>,
+}
+
+type OrderFn<A> = Rc<dyn Fn(&A, &A) -> Ordering>;
+
+impl<A: 'static> Order<A> {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&A, &A) -> Ordering + 'static,
+    {
+        Order { f: Rc::new(f) }
+    }
+
+    /// `Ord`による順序を使うインスタンスを作成します。
+    pub fn natural() -> Self
+    where
+        A: Ord,
+    {
+        Order::new(|x: &A, y: &A| x.cmp(y))
+    }
+
+    /// 値から取り出したキーの順序で比較するインスタンスを作成します。
+    pub fn by<B, F>(key: F) -> Self
+    where
+        B: Ord,
+        F: Fn(&A) -> B + 'static,
+    {
+        Order::new(move |x, y| key(x).cmp(&key(y)))
+    }
+
+    pub fn compare(&self, x: &A, y: &A) -> Ordering {
+        (self.f)(x, y)
+    }
+
+    pub fn eqv(&self, x: &A, y: &A) -> bool {
+        self.compare(x, y) == Ordering::Equal
+    }
+
+    pub fn lt(&self, x: &A, y: &A) -> bool {
+        self.compare(x, y) == Ordering::Less
+    }
+
+    pub fn gt(&self, x: &A, y: &A) -> bool {
+        self.compare(x, y) == Ordering::Greater
+    }
+
+    /// 小さい方を返します。等しい場合は`x`を返します。
+    pub fn min<'a>(&self, x: &'a A, y: &'a A) -> &'a A {
+        if self.gt(x, y) {
+            y
+        } else {
+            x
+        }
+    }
+
+    /// 大きい方を返します。等しい場合は`y`を返します。
+    pub fn max<'a>(&self, x: &'a A, y: &'a A) -> &'a A {
+        if self.gt(x, y) {
+            x
+        } else {
+            y
+        }
+    }
+
+    /// 順序を逆にしたインスタンスを返します。
+    pub fn reverse(self) -> Self {
+        Order::new(move |x, y| self.compare(y, x))
+    }
+
+    /// この順序で等しい場合に、`other`の順序で比較するインスタンスを返します。
+    pub fn and_then(self, other: Self) -> Self {
+        Order::new(move |x, y| self.compare(x, y).combine(other.compare(x, y)))
+    }
+
+    /// 順序が等しいことを等価性とするインスタンスを返します。
+    pub fn to_eq(self) -> Eq<A> {
+        Eq::new(move |x, y| self.eqv(x, y))
+    }
+
+    /// スライスをこの順序で安定ソートします。
+    pub fn sort(&self, values: &mut [A]) {
+        values.sort_by(|x, y| self.compare(x, y));
+    }
+}
+
+impl<A> Clone for Order<A> {
+    fn clone(&self) -> Self {
+        Order {
+            f: Rc::clone(&self.f),
+        }
+    }
+}
+
+impl<A> fmt::Debug for Order<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Order")
+    }
+}
+
+impl<A: 'static> Contravariant for Order<A> {
+    type Elm = A;
+    type M<B: 'static> = Order<B>;
+
+    fn contramap<B: 'static, F>(self, f: F) -> Order<B>
+    where
+        F: Fn(&B) -> A + 'static,
+    {
+        Order::new(move |x, y| self.compare(&f(x), &f(y)))
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use std::cmp::Ordering;
+
+    use crate::order::Order;
+    use crate::{Empty, Semigroup};
+
+    fn ordering(n: i8) -> Ordering {
+        n.cmp(&0)
+    }
+
+    fn lexicographic() -> Order<(i32, i32)> {
+        Order::by(|p: &(i32, i32)| p.0).and_then(Order::by(|p: &(i32, i32)| p.1).reverse())
+    }
+
+    #[quickcheck]
+    fn ordering_monoid_identity(n: i8) -> bool {
+        let o = ordering(n);
+        Ordering::empty().combine(o) == o && o.combine(Ordering::empty()) == o
+    }
+
+    #[quickcheck]
+    fn ordering_monoid_associativity(a: i8, b: i8, c: i8) -> bool {
+        let (a, b, c) = (ordering(a), ordering(b), ordering(c));
+        a.combine(b).combine(c) == a.combine(b.combine(c))
+    }
+
+    #[quickcheck]
+    fn order_antisymmetry(a: (i32, i32), b: (i32, i32)) -> bool {
+        let order = lexicographic();
+        order.compare(&a, &b) == order.compare(&b, &a).reverse()
+    }
+
+    #[quickcheck]
+    fn order_transitivity(a: (i32, i32), b: (i32, i32), c: (i32, i32)) -> bool {
+        let order = lexicographic();
+        order.gt(&a, &b) || order.gt(&b, &c) || !order.gt(&a, &c)
+    }
+
+    #[quickcheck]
+    fn natural_agrees_with_ord(a: i32, b: i32) -> bool {
+        Order::natural().compare(&a, &b) == a.cmp(&b)
+    }
+
+    #[quickcheck]
+    fn min_max(a: i32, b: i32) -> bool {
+        let order = Order::natural();
+        *order.min(&a, &b) == a.min(b) && *order.max(&a, &b) == a.max(b)
+    }
+
+    #[quickcheck]
+    fn reverse_sorts_descending(values: Vec<i32>) -> bool {
+        let mut sorted = values.clone();
+        Order::natural().reverse().sort(&mut sorted);
+        let mut expected = values;
+        expected.sort_by(|a, b| b.cmp(a));
+        sorted == expected
+    }
+}
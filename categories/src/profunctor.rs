@@ -0,0 +1,172 @@
+use std::fmt;
+use std::rc::Rc;
+
+/// Profunctorは、入力側を反変に、出力側を共変に変換する型クラスです。
+///
+/// 関数のように「値を受け取り、値を返す」型に対して、入力の前処理と出力の後処理を
+/// 同時に追加することができます。
+///
+/// # 型クラス階層における位置
+///
+/// Profunctorは独立した型クラスであり、他の型クラスとの直接的な階層関係はありません。
+/// 入力側は`Contravariant`、出力側は`Functor`と同じように振る舞います。
+///
+/// # Profunctorの法則
+///
+/// 1. 恒等関数を適用しても振る舞いは変わらない（恒等法則）
+///    ```rust,ignore
+///    x.dimap(|a| a.clone(), |b| b.clone()) == x
+///    ```
+///
+/// 2. 関数の合成は、個別に適用した結果と同じ（合成法則）
+///    ```rust,ignore
+///    x.dimap(f1, g1).dimap(f2, g2) == x.dimap(|c| f1(&f2(c)), |b| g2(&g1(b)))
+///    ```
+///
+/// # 型パラメータ
+///
+/// * `In` - 入力の型
+/// * `Out` - 出力の型
+/// * `M<C, D>` - 変換後の型（Cは新しい入力の型、Dは新しい出力の型）
+///
+/// # メソッド
+///
+/// * `dimap` - 入力と出力の両方を変換する
+/// * `lmap` - 入力のみを変換する
+/// * `rmap` - 出力のみを変換する
+pub trait Profunctor: Sized {
+    type In;
+    type Out;
+    type M<C: 'static, D: 'static>;
+
+    fn dimap<C: 'static, D: 'static, F, G>(self, f: F, g: G) -> Self::M<C, D>
+    where
+        F: Fn(&C) -> Self::In + 'static,
+        G: Fn(&Self::Out) -> D + 'static;
+
+    fn lmap<C: 'static, F>(self, f: F) -> Self::M<C, Self::Out>
+    where
+        Self::Out: Clone + 'static,
+        F: Fn(&C) -> Self::In + 'static,
+    {
+        self.dimap(f, |b: &Self::Out| b.clone())
+    }
+
+    fn rmap<D: 'static, G>(self, g: G) -> Self::M<Self::In, D>
+    where
+        Self::In: Clone + 'static,
+        G: Fn(&Self::Out) -> D + 'static,
+    {
+        self.dimap(|a: &Self::In| a.clone(), g)
+    }
+}
+
+/// 共有可能な関数を表す型です。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{Func, Profunctor};
+///
+/// let length = Func::new(|s: &String| s.len());
+/// let describe = length.dimap(|n: &i32| n.to_string(), |len| format!("{} digits", len));
+///
+/// assert_eq!(describe.apply(&12345), "5 digits");
+/// ```
+pub struct Func<A, B> {
+    f: Rc<dyn Fn(&A) -> B>,
+}
+
+impl<A: 'static, B: 'static> Func<A, B> {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&A) -> B + 'static,
+    {
+        Func { f: Rc::new(f) }
+    }
+
+    pub fn apply(&self, value: &A) -> B {
+        (self.f)(value)
+    }
+
+    pub fn and_then<C: 'static>(self, other: Func<B, C>) -> Func<A, C> {
+        Func::new(move |a| other.apply(&self.apply(a)))
+    }
+}
+
+impl<A, B> Clone for Func<A, B> {
+    fn clone(&self) -> Self {
+        Func {
+            f: Rc::clone(&self.f),
+        }
+    }
+}
+
+impl<A, B> fmt::Debug for Func<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Func")
+    }
+}
+
+impl<A: 'static, B: 'static> Profunctor for Func<A, B> {
+    type In = A;
+    type Out = B;
+    type M<C: 'static, D: 'static> = Func<C, D>;
+
+    fn dimap<C: 'static, D: 'static, F, G>(self, f: F, g: G) -> Func<C, D>
+    where
+        F: Fn(&C) -> A + 'static,
+        G: Fn(&B) -> D + 'static,
+    {
+        Func::new(move |c| g(&self.apply(&f(c))))
+    }
+
+    fn lmap<C: 'static, F>(self, f: F) -> Func<C, B>
+    where
+        F: Fn(&C) -> A + 'static,
+    {
+        Func::new(move |c| self.apply(&f(c)))
+    }
+
+    fn rmap<D: 'static, G>(self, g: G) -> Func<A, D>
+    where
+        G: Fn(&B) -> D + 'static,
+    {
+        Func::new(move |a| g(&self.apply(a)))
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::{Func, Profunctor};
+
+    fn double() -> Func<i32, i64> {
+        Func::new(|n: &i32| *n as i64 * 2)
+    }
+
+    #[quickcheck]
+    fn profunctor_identity(n: i32) -> bool {
+        double().dimap(|a: &i32| *a, |b: &i64| *b).apply(&n) == double().apply(&n)
+    }
+
+    #[quickcheck]
+    fn profunctor_composition(n: i16) -> bool {
+        let f1: fn(&i16) -> i32 = |x| *x as i32 + 1;
+        let g1: fn(&i64) -> String = |x| x.to_string();
+        let f2: fn(&u8) -> i16 = |x| *x as i16;
+        let g2: fn(&String) -> usize = |s| s.len();
+        let n = (n % 256).unsigned_abs() as u8;
+        double().dimap(f1, g1).dimap(f2, g2).apply(&n)
+            == double()
+                .dimap(move |c: &u8| f1(&f2(c)), move |b: &i64| g2(&g1(b)))
+                .apply(&n)
+    }
+
+    #[quickcheck]
+    fn dimap_consistency(n: i32) -> bool {
+        let f: fn(&i16) -> i32 = |x| *x as i32;
+        let g: fn(&i64) -> String = |x| x.to_string();
+        let n = n as i16;
+        double().dimap(f, g).apply(&n) == double().lmap(f).rmap(g).apply(&n)
+    }
+}
@@ -0,0 +1,181 @@
+use std::fmt;
+use std::rc::Rc;
+
+use crate::Pure;
+
+/// Readerは、共有された環境`R`を読み取って値`A`を計算するモナドです。
+///
+/// 設定やクライアントなどの依存を、関数の引数として明示的に受け渡すことなく
+/// 計算の連鎖全体に注入することができます。環境は`run`を呼び出すときに一度だけ渡します。
+///
+/// # 型クラスとの関係
+///
+/// `fmap`、`ap`、`bind`は`'static`な関数を受け取る固有メソッドとして提供し、`Pure`だけを型クラスとして実装しています。
+/// 理由は[型クラス階層の「関数を保持する型と型クラス」](https://github.com/j5ik2o/rust-fp/blob/main/categories/src/hierarchy.md)を参照してください。
+/// `for_yield!`の`move;`モードと組み合わせることができます。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{for_yield, Pure, Reader};
+///
+/// #[derive(Clone)]
+/// struct Config {
+///     host: String,
+///     port: u16,
+/// }
+///
+/// let endpoint = for_yield! {
+///     move;
+///     bind host = Reader::asks(|c: &Config| c.host.clone());
+///     bind port = Reader::asks(|c: &Config| c.port);
+///     yield Reader::pure(format!("{}:{}", host, port))
+/// };
+///
+/// let config = Config { host: "localhost".to_string(), port: 8080 };
+/// assert_eq!(endpoint.run(&config), "localhost:8080");
+/// ```
+pub struct Reader<R, A> {
+    run: Rc<dyn Fn(&R) -> A>,
+}
+
+impl<R: 'static, A: 'static> Reader<R, A> {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&R) -> A + 'static,
+    {
+        Reader { run: Rc::new(f) }
+    }
+
+    /// 環境の一部を取り出す計算を作成します。
+    pub fn asks<F>(f: F) -> Self
+    where
+        F: Fn(&R) -> A + 'static,
+    {
+        Reader::new(f)
+    }
+
+    /// 環境を渡して計算を実行します。
+    pub fn run(&self, env: &R) -> A {
+        (self.run)(env)
+    }
+
+    /// 変更した環境で計算を実行する新しい計算を返します。
+    pub fn local<F>(self, f: F) -> Self
+    where
+        F: Fn(&R) -> R + 'static,
+    {
+        Reader::new(move |r| self.run(&f(r)))
+    }
+
+    pub fn fmap<B: 'static, F>(self, f: F) -> Reader<R, B>
+    where
+        F: Fn(&A) -> B + 'static,
+    {
+        Reader::new(move |r| f(&self.run(r)))
+    }
+
+    pub fn ap<B: 'static, F>(self, fs: Reader<R, F>) -> Reader<R, B>
+    where
+        F: Fn(&A) -> B + 'static,
+    {
+        Reader::new(move |r| fs.run(r)(&self.run(r)))
+    }
+
+    pub fn bind<B: 'static, F>(self, f: F) -> Reader<R, B>
+    where
+        F: Fn(&A) -> Reader<R, B> + 'static,
+    {
+        Reader::new(move |r| f(&self.run(r)).run(r))
+    }
+}
+
+impl<R: Clone + 'static> Reader<R, R> {
+    /// 環境そのものを返す計算を作成します。
+    pub fn ask() -> Self {
+        Reader::new(|r: &R| r.clone())
+    }
+}
+
+impl<R, A> Clone for Reader<R, A> {
+    fn clone(&self) -> Self {
+        Reader {
+            run: Rc::clone(&self.run),
+        }
+    }
+}
+
+impl<R, A> fmt::Debug for Reader<R, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Reader")
+    }
+}
+
+impl<R: 'static, A: Clone + 'static> Pure for Reader<R, A> {
+    type Elm = A;
+    type M<U: Clone> = Reader<R, U>;
+
+    fn pure(value: A) -> Reader<R, A> {
+        Reader::new(move |_| value.clone())
+    }
+
+    fn unit() -> Reader<R, ()> {
+        Reader::new(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::{Pure, Reader};
+
+    fn f(n: &i64) -> Reader<i64, i64> {
+        let n = *n;
+        Reader::asks(move |r: &i64| n.wrapping_mul(*r))
+    }
+
+    fn g(n: &i64) -> Reader<i64, i64> {
+        let n = *n;
+        Reader::asks(move |r: &i64| n.wrapping_add(*r))
+    }
+
+    #[quickcheck]
+    fn monad_left_identity(n: i64, env: i64) -> bool {
+        Reader::pure(n).bind(f).run(&env) == f(&n).run(&env)
+    }
+
+    #[quickcheck]
+    fn monad_right_identity(env: i64) -> bool {
+        let m = Reader::asks(|r: &i64| r.wrapping_sub(3));
+        m.clone().bind(|x| Reader::pure(*x)).run(&env) == m.run(&env)
+    }
+
+    #[quickcheck]
+    fn monad_associativity(env: i64) -> bool {
+        let m = Reader::<i64, i64>::ask();
+        m.clone().bind(f).bind(g).run(&env) == m.bind(|x| f(x).bind(g)).run(&env)
+    }
+
+    #[quickcheck]
+    fn functor_identity(env: i64) -> bool {
+        let m = Reader::<i64, i64>::ask();
+        m.clone().fmap(|x| *x).run(&env) == m.run(&env)
+    }
+
+    #[quickcheck]
+    fn ap_consistency(env: i64) -> bool {
+        let m = Reader::<i64, i64>::ask();
+        let fs = Reader::asks(|r: &i64| {
+            let r = *r;
+            move |x: &i64| x.wrapping_add(r)
+        });
+        m.clone().ap(fs).run(&env) == m.fmap(|x| x.wrapping_add(*x)).run(&env)
+    }
+
+    #[quickcheck]
+    fn local_modifies_environment(env: i64) -> bool {
+        Reader::<i64, i64>::ask()
+            .local(|r| r.wrapping_add(1))
+            .run(&env)
+            == env.wrapping_add(1)
+    }
+}
@@ -0,0 +1,207 @@
+use std::fmt;
+use std::rc::Rc;
+
+use crate::{Apply, MonadFamily, Pure};
+
+type Computation<R, M, A> = Rc<dyn Fn(&R) -> <M as Apply>::M<A>>;
+
+/// ReaderTは、内側のモナドの計算に共有された環境の読み取りを重ねたモナド変換子です。
+///
+/// 環境`R`を受け取り、`M<A>`を返す関数を包みます。設定に依存し、かつ失敗する計算などを記述できます。
+/// 内側のモナドは`MonadFamily`を実装した代表の型で指定します(例: `Result<(), E>`)。
+///
+/// # 型クラスとの関係
+///
+/// `Reader`と同じく、`fmap`、`ap`、`bind`は固有メソッドとして提供しています([型クラス階層の「関数を保持する型と型クラス」](https://github.com/j5ik2o/rust-fp/blob/main/categories/src/hierarchy.md))。
+///
+/// # 例
+///
+/// ```
+/// use std::collections::HashMap;
+/// use rust_fp_categories::{for_yield, Pure, ReaderT};
+///
+/// type Lookup = ReaderT<HashMap<String, i32>, Result<(), String>, i32>;
+///
+/// fn lookup(key: &'static str) -> Lookup {
+///     ReaderT::new(move |env: &HashMap<String, i32>| {
+///         env.get(key).copied().ok_or(format!("{} is missing", key))
+///     })
+/// }
+///
+/// let total = for_yield! {
+///     move;
+///     bind a = lookup("a");
+///     bind b = lookup("b");
+///     yield Lookup::pure(a + b)
+/// };
+///
+/// let mut env = HashMap::new();
+/// env.insert("a".to_string(), 1);
+/// assert_eq!(total.run(&env), Err("b is missing".to_string()));
+/// env.insert("b".to_string(), 2);
+/// assert_eq!(total.run(&env), Ok(3));
+/// ```
+pub struct ReaderT<R, M: Apply, A: Clone> {
+    run: Computation<R, M, A>,
+}
+
+impl<R: 'static, M: MonadFamily + 'static, A: Clone + 'static> ReaderT<R, M, A> {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&R) -> <M as Apply>::M<A> + 'static,
+    {
+        ReaderT { run: Rc::new(f) }
+    }
+
+    /// 環境の一部を取り出す計算を作成します。
+    pub fn asks<F>(f: F) -> Self
+    where
+        F: Fn(&R) -> A + 'static,
+    {
+        ReaderT::new(move |r| M::pure_of(f(r)))
+    }
+
+    /// 内側のモナドの計算を持ち上げます。環境は参照しません。
+    pub fn lift(ma: <M as Apply>::M<A>) -> Self
+    where
+        <M as Apply>::M<A>: Clone + 'static,
+    {
+        ReaderT::new(move |_| ma.clone())
+    }
+
+    /// 環境を渡して計算を実行します。
+    pub fn run(&self, env: &R) -> <M as Apply>::M<A> {
+        (self.run)(env)
+    }
+
+    /// 変更した環境で計算を実行する新しい計算を返します。
+    pub fn local<F>(self, f: F) -> Self
+    where
+        F: Fn(&R) -> R + 'static,
+    {
+        ReaderT::new(move |r| self.run(&f(r)))
+    }
+
+    pub fn fmap<B: Clone + 'static, F>(self, f: F) -> ReaderT<R, M, B>
+    where
+        F: Fn(&A) -> B + 'static,
+    {
+        ReaderT::new(move |r| M::map_of(self.run(r), |a: A| f(&a)))
+    }
+
+    pub fn ap<B: Clone + 'static, F>(self, fs: ReaderT<R, M, F>) -> ReaderT<R, M, B>
+    where
+        F: Clone + Fn(&A) -> B + 'static,
+    {
+        fs.bind(move |f| {
+            let f = f.clone();
+            self.clone().fmap(move |a| f(a))
+        })
+    }
+
+    pub fn bind<B: Clone + 'static, F>(self, f: F) -> ReaderT<R, M, B>
+    where
+        F: Fn(&A) -> ReaderT<R, M, B> + 'static,
+    {
+        ReaderT::new(move |r| M::bind_of(self.run(r), |a: A| f(&a).run(r)))
+    }
+}
+
+impl<R: Clone + 'static, M: MonadFamily + 'static> ReaderT<R, M, R> {
+    /// 環境そのものを返す計算を作成します。
+    pub fn ask() -> Self {
+        ReaderT::asks(|r: &R| r.clone())
+    }
+}
+
+impl<R, M: Apply, A: Clone> Clone for ReaderT<R, M, A> {
+    fn clone(&self) -> Self {
+        ReaderT {
+            run: Rc::clone(&self.run),
+        }
+    }
+}
+
+impl<R, M: Apply, A: Clone> fmt::Debug for ReaderT<R, M, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ReaderT")
+    }
+}
+
+impl<R: 'static, M: MonadFamily + 'static, A: Clone + 'static> Pure for ReaderT<R, M, A> {
+    type Elm = A;
+    type M<U: Clone> = ReaderT<R, M, U>;
+
+    fn pure(value: A) -> ReaderT<R, M, A> {
+        ReaderT::new(move |_| M::pure_of(value.clone()))
+    }
+
+    fn unit() -> ReaderT<R, M, ()> {
+        ReaderT::new(|_| M::pure_of(()))
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::{Pure, ReaderT};
+
+    type T = ReaderT<i64, Option<()>, i64>;
+
+    fn f(n: &i64) -> T {
+        let n = *n;
+        ReaderT::new(move |r: &i64| n.checked_mul(*r))
+    }
+
+    fn g(n: &i64) -> T {
+        let n = *n;
+        ReaderT::new(move |r: &i64| n.checked_add(*r))
+    }
+
+    #[quickcheck]
+    fn functor_identity(env: i64) -> bool {
+        let m = T::ask();
+        m.clone().fmap(|x| *x).run(&env) == m.run(&env)
+    }
+
+    #[quickcheck]
+    fn ap_consistency(env: i64) -> bool {
+        let m = T::ask();
+        let fs = ReaderT::<i64, Option<()>, _>::pure(|x: &i64| x.wrapping_sub(1));
+        m.clone().ap(fs).run(&env) == m.fmap(|x| x.wrapping_sub(1)).run(&env)
+    }
+
+    #[quickcheck]
+    fn monad_left_identity(n: i64, env: i64) -> bool {
+        T::pure(n).bind(f).run(&env) == f(&n).run(&env)
+    }
+
+    #[quickcheck]
+    fn monad_right_identity(env: i64) -> bool {
+        let m = T::ask();
+        m.clone().bind(|x| T::pure(*x)).run(&env) == m.run(&env)
+    }
+
+    #[quickcheck]
+    fn monad_associativity(env: i64) -> bool {
+        let m = T::ask();
+        m.clone().bind(f).bind(g).run(&env) == m.bind(|x| f(x).bind(g)).run(&env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{for_yield, Pure, ReaderT};
+
+    #[test]
+    fn test_lift_and_local() {
+        type T = ReaderT<i32, Vec<()>, i32>;
+
+        let program = for_yield! {
+            move;
+            bind a = T::lift(vec![1, 2]);
+            bind r = T::ask().local(|r| r * 10);
+            yield T::pure(a + r)
+        };
+        assert_eq!(program.run(&3), vec![31, 32]);
+    }
+}
@@ -0,0 +1,285 @@
+use std::fmt;
+
+use crate::{Applicative, ApplicativeFamily, Apply, Bind, Functor, Monad, MonadFamily, Pure};
+
+/// ResultTは、内側のモナドの中で失敗する可能性のある計算を扱うモナド変換子です。
+///
+/// `M<Result<A, E>>`を包み、`bind`は内側のモナドの作用を保ったまま最初の`Err`で計算を打ち切ります。
+/// 内側のモナドは`MonadFamily`を実装した代表の型で指定します(例: `Vec<()>`)。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{for_yield, Bind, Pure, ResultT, Writer};
+///
+/// type Step = ResultT<Writer<Vec<String>, ()>, String, i32>;
+///
+/// fn parse(s: &str) -> Step {
+///     let log = Writer::new(vec![format!("parse {}", s)], s.parse::<i32>().map_err(|e| e.to_string()));
+///     ResultT::new(log)
+/// }
+///
+/// let result = for_yield! {
+///     bind a = parse("1");
+///     bind b = parse("x");
+///     yield Step::pure(a + b)
+/// };
+/// let (log, value) = result.run().run();
+/// assert_eq!(log, vec!["parse 1".to_string(), "parse x".to_string()]);
+/// assert!(value.is_err());
+/// ```
+pub struct ResultT<M: Apply, E: Clone, A: Clone> {
+    value: <M as Apply>::M<Result<A, E>>,
+}
+
+impl<M: MonadFamily, E: Clone, A: Clone> ResultT<M, E, A> {
+    pub fn new(value: <M as Apply>::M<Result<A, E>>) -> Self {
+        ResultT { value }
+    }
+
+    /// 成功した計算を作成します。
+    pub fn ok(value: A) -> Self {
+        ResultT::new(M::pure_of(Ok(value)))
+    }
+
+    /// 失敗した計算を作成します。
+    pub fn err(error: E) -> Self {
+        ResultT::new(M::pure_of(Err(error)))
+    }
+
+    /// 内側のモナドの計算を持ち上げます。
+    pub fn lift(ma: <M as Apply>::M<A>) -> Self {
+        ResultT::new(M::map_of(ma, Ok))
+    }
+
+    /// 包んでいる`M<Result<A, E>>`を返します。
+    pub fn run(self) -> <M as Apply>::M<Result<A, E>> {
+        self.value
+    }
+
+    /// エラーの値を変換します。
+    pub fn map_err<E2: Clone, F>(self, f: F) -> ResultT<M, E2, A>
+    where
+        F: Fn(E) -> E2,
+    {
+        ResultT::new(M::map_of(self.value, |a: Result<A, E>| a.map_err(&f)))
+    }
+}
+
+impl<M: Apply, E: Clone, A: Clone> Clone for ResultT<M, E, A>
+where
+    <M as Apply>::M<Result<A, E>>: Clone,
+{
+    fn clone(&self) -> Self {
+        ResultT {
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl<M: Apply, E: Clone, A: Clone> PartialEq for ResultT<M, E, A>
+where
+    <M as Apply>::M<Result<A, E>>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<M: Apply, E: Clone, A: Clone> fmt::Debug for ResultT<M, E, A>
+where
+    <M as Apply>::M<Result<A, E>>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ResultT").field(&self.value).finish()
+    }
+}
+
+impl<M: MonadFamily, E: Clone, A: Clone> Functor for ResultT<M, E, A> {
+    type Elm = A;
+    type M<B: Clone> = ResultT<M, E, B>;
+
+    fn fmap<B: Clone, F>(self, f: F) -> ResultT<M, E, B>
+    where
+        F: Fn(&A) -> B,
+    {
+        ResultT::new(M::map_of(self.value, |a: Result<A, E>| a.map(|a| f(&a))))
+    }
+}
+
+impl<M: MonadFamily, E: Clone, A: Clone> Pure for ResultT<M, E, A> {
+    type Elm = A;
+    type M<U: Clone> = ResultT<M, E, U>;
+
+    fn pure(value: A) -> ResultT<M, E, A> {
+        ResultT::ok(value)
+    }
+
+    fn unit() -> ResultT<M, E, ()> {
+        ResultT::ok(())
+    }
+}
+
+impl<M: MonadFamily, E: Clone, A: Clone> Apply for ResultT<M, E, A>
+where
+    <M as Apply>::M<Result<A, E>>: Clone,
+{
+    type Elm = A;
+    type M<U: Clone> = ResultT<M, E, U>;
+
+    fn ap<B: Clone, F>(self, fs: ResultT<M, E, F>) -> ResultT<M, E, B>
+    where
+        F: Clone + Fn(&A) -> B,
+    {
+        let value = self.value;
+        ResultT::new(M::bind_of(fs.value, |f: Result<F, E>| match f {
+            Ok(f) => M::map_of(value.clone(), move |a: Result<A, E>| a.map(|a| f(&a))),
+            Err(e) => M::pure_of(Err(e)),
+        }))
+    }
+}
+
+impl<M: MonadFamily, E: Clone, A: Clone> Bind for ResultT<M, E, A> {
+    type Elm = A;
+    type M<U: Clone> = ResultT<M, E, U>;
+
+    fn bind<B: Clone, F>(self, f: F) -> ResultT<M, E, B>
+    where
+        F: Fn(&A) -> ResultT<M, E, B>,
+    {
+        ResultT::new(M::bind_of(self.value, |a: Result<A, E>| match a {
+            Ok(a) => f(&a).value,
+            Err(e) => M::pure_of(Err(e)),
+        }))
+    }
+}
+
+impl<M: MonadFamily, E: Clone, A: Clone> Applicative for ResultT<M, E, A> where
+    <M as Apply>::M<Result<A, E>>: Clone
+{
+}
+
+impl<M: MonadFamily, E: Clone, A: Clone> Monad for ResultT<M, E, A> where
+    <M as Apply>::M<Result<A, E>>: Clone
+{
+}
+
+impl<M: MonadFamily, E: Clone, A: Clone> ApplicativeFamily for ResultT<M, E, A>
+where
+    <M as Apply>::M<Result<A, E>>: Clone,
+{
+    fn pure_of<U: Clone>(value: U) -> ResultT<M, E, U> {
+        ResultT::ok(value)
+    }
+
+    fn map_of<U: Clone, V: Clone, F>(fu: ResultT<M, E, U>, f: F) -> ResultT<M, E, V>
+    where
+        F: Fn(U) -> V,
+    {
+        ResultT::new(M::map_of(fu.value, |u: Result<U, E>| u.map(&f)))
+    }
+
+    fn map2<U: Clone, V: Clone, F>(fu: ResultT<M, E, U>, fa: Self, f: F) -> ResultT<M, E, V>
+    where
+        F: Fn(U, A) -> V,
+    {
+        let value = fa.value;
+        ResultT::new(M::bind_of(fu.value, |u: Result<U, E>| match u {
+            Ok(u) => M::map_of(value.clone(), |a: Result<A, E>| a.map(|a| f(u.clone(), a))),
+            Err(e) => M::pure_of(Err(e)),
+        }))
+    }
+}
+
+impl<M: MonadFamily, E: Clone, A: Clone> MonadFamily for ResultT<M, E, A>
+where
+    <M as Apply>::M<Result<A, E>>: Clone,
+{
+    fn bind_of<U: Clone, V: Clone, F>(fu: ResultT<M, E, U>, f: F) -> ResultT<M, E, V>
+    where
+        F: Fn(U) -> ResultT<M, E, V>,
+    {
+        ResultT::new(M::bind_of(fu.value, |u: Result<U, E>| match u {
+            Ok(u) => f(u).value,
+            Err(e) => M::pure_of(Err(e)),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::{Apply, Bind, Functor, Pure, ResultT};
+
+    type T = ResultT<Option<()>, String, i32>;
+
+    fn f(n: &i32) -> T {
+        if n % 2 == 0 {
+            ResultT::err(format!("even {}", n))
+        } else {
+            ResultT::ok(n.wrapping_mul(3))
+        }
+    }
+
+    fn g(n: &i32) -> T {
+        if n % 5 == 0 {
+            ResultT::new(None)
+        } else {
+            ResultT::ok(n.wrapping_add(1))
+        }
+    }
+
+    #[quickcheck]
+    fn functor_identity(value: Option<Result<i32, String>>) -> bool {
+        let m = T::new(value);
+        m.clone().fmap(|x| *x) == m
+    }
+
+    #[quickcheck]
+    fn applicative_identity(value: Option<Result<i32, String>>) -> bool {
+        let m = T::new(value);
+        m.clone().ap(ResultT::pure(|x: &i32| *x)) == m
+    }
+
+    #[quickcheck]
+    fn monad_left_identity(n: i32) -> bool {
+        T::pure(n).bind(f) == f(&n)
+    }
+
+    #[quickcheck]
+    fn monad_right_identity(value: Option<Result<i32, String>>) -> bool {
+        let m = T::new(value);
+        m.clone().bind(|x| T::pure(*x)) == m
+    }
+
+    #[quickcheck]
+    fn monad_associativity(value: Option<Result<i32, String>>) -> bool {
+        let m = T::new(value);
+        m.clone().bind(f).bind(g) == m.bind(|x| f(x).bind(g))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{for_yield, Bind, Pure, ResultT};
+
+    #[test]
+    fn test_err_short_circuits_each_branch() {
+        type T = ResultT<Vec<()>, String, i32>;
+
+        let result = for_yield! {
+            bind a = T::lift(vec![1, 2, 3]);
+            bind b = if *a == 2 { T::err("two".to_string()) } else { T::pure(a * 10) };
+            yield T::pure(a + b)
+        };
+        assert_eq!(result.run(), vec![Ok(11), Err("two".to_string()), Ok(33)]);
+    }
+
+    #[test]
+    fn test_map_err() {
+        let m = ResultT::<Option<()>, i32, i32>::err(404);
+        assert_eq!(
+            m.map_err(|code| format!("status {}", code)).run(),
+            Some(Err("status 404".to_string()))
+        );
+    }
+}
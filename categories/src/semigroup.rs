@@ -1,3 +1,7 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
 pub trait Semigroup {
     fn combine(self, other: Self) -> Self;
 }
@@ -19,3 +23,61 @@ impl Semigroup for String {
         format!("{}{}", self, other)
     }
 }
+
+impl Semigroup for Ordering {
+    fn combine(self, other: Self) -> Self {
+        self.then(other)
+    }
+}
+
+/// `Some`どうしは中身を結合し、`None`は単位元として扱います。
+impl<A: Semigroup> Semigroup for Option<A> {
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.combine(b)),
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+}
+
+/// 最初の`Ok`を返します。どちらも`Err`の場合はエラーを結合します。
+impl<A, E: Semigroup> Semigroup for Result<A, E> {
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Ok(a), _) => Ok(a),
+            (Err(_), Ok(b)) => Ok(b),
+            (Err(e1), Err(e2)) => Err(e1.combine(e2)),
+        }
+    }
+}
+
+/// キーごとにマージします。両方にあるキーの値は`combine`で結合します。
+impl<K: Eq + Hash, V: Semigroup, S: BuildHasher> Semigroup for HashMap<K, V, S> {
+    fn combine(self, other: Self) -> Self {
+        let mut merged = self;
+        for (k, v) in other {
+            let value = match merged.remove(&k) {
+                Some(current) => current.combine(v),
+                None => v,
+            };
+            merged.insert(k, value);
+        }
+        merged
+    }
+}
+
+/// キーごとにマージします。両方にあるキーの値は`combine`で結合します。
+impl<K: Ord, V: Semigroup> Semigroup for BTreeMap<K, V> {
+    fn combine(self, other: Self) -> Self {
+        let mut merged = self;
+        for (k, v) in other {
+            let value = match merged.remove(&k) {
+                Some(current) => current.combine(v),
+                None => v,
+            };
+            merged.insert(k, value);
+        }
+        merged
+    }
+}
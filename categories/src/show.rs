@@ -263,6 +263,47 @@ mod tests {
     use super::*;
     use std::rc::Rc;
 
+    #[derive(crate::Show)]
+    struct Account<A> {
+        owner: String,
+        balance: A,
+        tags: Vec<&'static str>,
+    }
+
+    #[derive(crate::Show)]
+    struct Id(u32, char);
+
+    #[derive(crate::Show)]
+    struct Anonymous;
+
+    #[derive(crate::Show)]
+    enum Event {
+        Opened { id: u32 },
+        Renamed(String, String),
+        Closed,
+    }
+
+    #[test]
+    fn test_derived_show() {
+        let account = Account {
+            owner: "alice".to_string(),
+            balance: Some(10),
+            tags: vec!["vip"],
+        };
+        assert_eq!(
+            account.show(),
+            "Account { owner: alice, balance: Some(10), tags: [vip] }"
+        );
+        assert_eq!(Id(7, 'x').show(), "Id(7, x)");
+        assert_eq!(Anonymous.show(), "Anonymous");
+        assert_eq!(Event::Opened { id: 1 }.show(), "Opened { id: 1 }");
+        assert_eq!(
+            Event::Renamed("a".to_string(), "b".to_string()).show(),
+            "Renamed(a, b)"
+        );
+        assert_eq!(Event::Closed.show(), "Closed");
+    }
+
     #[test]
     fn test_show_for_rc() {
         let value = Rc::new(5);
@@ -0,0 +1,354 @@
+use std::any::Any;
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::{tail_rec, Pure, RecursionState};
+
+type Value = Box<dyn Any>;
+type Transition<S> = Rc<dyn Fn(S) -> (S, Value)>;
+type Continuation<S> = Rc<dyn Fn(Value) -> Next<S>>;
+
+/// 状態遷移の連鎖を表す内部表現です。
+///
+/// `bind`はクロージャをネストさせる代わりにこの木を組み立て、`run`がループで解釈します。
+/// そのため、`bind`の連鎖がどれだけ長くてもスタックを消費しません。
+enum Op<S> {
+    Transition(Transition<S>),
+    Bind(Option<Rc<Op<S>>>, Continuation<S>),
+}
+
+/// 継続が返す、次に解釈する計算または確定した値です。
+enum Next<S> {
+    Op(Rc<Op<S>>),
+    Value(Value),
+}
+
+impl<S> Drop for Op<S> {
+    // 深くネストした木を再帰的に解放するとスタックが溢れるため、ループで解放します。
+    fn drop(&mut self) {
+        let mut next = match self {
+            Op::Bind(inner, _) => inner.take(),
+            Op::Transition(_) => None,
+        };
+        while let Some(op) = next {
+            next = match Rc::try_unwrap(op) {
+                Ok(mut op) => match &mut op {
+                    Op::Bind(inner, _) => inner.take(),
+                    Op::Transition(_) => None,
+                },
+                Err(_) => None,
+            };
+        }
+    }
+}
+
+/// Stateは、状態`S`を受け取り、新しい状態と値`A`を返す状態遷移を表すモナドです。
+///
+/// パーサー、ID生成器、シミュレーションなど、状態を更新しながら進む計算を
+/// 可変な変数を使わずに記述することができます。
+///
+/// # スタック安全性
+///
+/// `bind`で連鎖した計算は、`tailrec`のトランポリンを使って解釈されます。
+/// 100万回の`bind`を連鎖させてもスタックオーバーフローは発生しません。
+///
+/// # 型クラスとの関係
+///
+/// `Reader`と同じく、`fmap`、`ap`、`bind`は固有メソッドとして提供し、`Pure`だけを型クラスとして実装しています。
+/// 理由は[型クラス階層の「関数を保持する型と型クラス」](https://github.com/j5ik2o/rust-fp/blob/main/categories/src/hierarchy.md)を参照してください。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{for_yield, Pure, State};
+///
+/// fn next_id() -> State<u32, u32> {
+///     State::get().bind(|id: &u32| {
+///         let id = *id;
+///         State::put(id + 1).fmap(move |_| id)
+///     })
+/// }
+///
+/// let ids = for_yield! {
+///     move;
+///     bind a = next_id();
+///     bind b = next_id();
+///     bind c = next_id();
+///     yield State::pure(vec![a, b, c])
+/// };
+///
+/// assert_eq!(ids.run(10), (13, vec![10, 11, 12]));
+/// ```
+pub struct State<S, A> {
+    op: Rc<Op<S>>,
+    _marker: PhantomData<A>,
+}
+
+impl<S: 'static, A: 'static> State<S, A> {
+    fn from_op(op: Op<S>) -> Self {
+        State {
+            op: Rc::new(op),
+            _marker: PhantomData,
+        }
+    }
+
+    /// 状態遷移関数から計算を作成します。
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(S) -> (S, A) + 'static,
+    {
+        State::from_op(Op::Transition(Rc::new(move |s| {
+            let (s, a) = f(s);
+            (s, Box::new(a) as Value)
+        })))
+    }
+
+    /// 状態から値を取り出す計算を作成します。状態は変更しません。
+    pub fn inspect<F>(f: F) -> Self
+    where
+        F: Fn(&S) -> A + 'static,
+    {
+        State::new(move |s| {
+            let a = f(&s);
+            (s, a)
+        })
+    }
+
+    /// 初期状態を渡して計算を実行し、最終的な状態と値の組を返します。
+    pub fn run(&self, initial: S) -> (S, A) {
+        let (state, value) = tail_rec(
+            (initial, Next::Op(Rc::clone(&self.op)), Vec::new()),
+            |(s, next, mut continuations): (S, Next<S>, Vec<Continuation<S>>)| match next {
+                Next::Op(op) => match &*op {
+                    Op::Bind(inner, k) => {
+                        continuations.push(Rc::clone(k));
+                        let inner = inner.as_ref().expect("bind must have an inner computation");
+                        RecursionState::Continue((s, Next::Op(Rc::clone(inner)), continuations))
+                    }
+                    Op::Transition(f) => {
+                        let (s, value) = f(s);
+                        RecursionState::Continue((s, Next::Value(value), continuations))
+                    }
+                },
+                Next::Value(value) => match continuations.pop() {
+                    Some(k) => RecursionState::Continue((s, k(value), continuations)),
+                    None => RecursionState::Done((s, value)),
+                },
+            },
+        );
+        let value = value
+            .downcast::<A>()
+            .expect("state computation must produce a value of type A");
+        (state, *value)
+    }
+
+    /// 計算を実行し、最終的な値を返します。
+    pub fn eval(&self, initial: S) -> A {
+        self.run(initial).1
+    }
+
+    /// 計算を実行し、最終的な状態を返します。
+    pub fn exec(&self, initial: S) -> S {
+        self.run(initial).0
+    }
+
+    fn continue_with<B: 'static, F>(self, f: F) -> State<S, B>
+    where
+        F: Fn(&A) -> Next<S> + 'static,
+    {
+        let k: Continuation<S> = Rc::new(move |value: Value| {
+            let a = value
+                .downcast_ref::<A>()
+                .expect("state computation must produce a value of type A");
+            f(a)
+        });
+        State::from_op(Op::Bind(Some(self.op), k))
+    }
+
+    pub fn fmap<B: 'static, F>(self, f: F) -> State<S, B>
+    where
+        F: Fn(&A) -> B + 'static,
+    {
+        self.continue_with(move |a| Next::Value(Box::new(f(a))))
+    }
+
+    pub fn ap<B: 'static, F>(self, fs: State<S, F>) -> State<S, B>
+    where
+        F: Clone + Fn(&A) -> B + 'static,
+    {
+        fs.bind(move |f| {
+            let f = f.clone();
+            self.clone().fmap(move |a| f(a))
+        })
+    }
+
+    pub fn bind<B: 'static, F>(self, f: F) -> State<S, B>
+    where
+        F: Fn(&A) -> State<S, B> + 'static,
+    {
+        self.continue_with(move |a| Next::Op(Rc::clone(&f(a).op)))
+    }
+}
+
+impl<S: Clone + 'static> State<S, S> {
+    /// 現在の状態を値として返す計算を作成します。
+    pub fn get() -> Self {
+        State::new(|s: S| (s.clone(), s))
+    }
+}
+
+impl<S: 'static> State<S, ()> {
+    /// 状態を置き換える計算を作成します。
+    pub fn put(s: S) -> Self
+    where
+        S: Clone,
+    {
+        State::new(move |_| (s.clone(), ()))
+    }
+
+    /// 状態に関数を適用して更新する計算を作成します。
+    pub fn modify<F>(f: F) -> Self
+    where
+        F: Fn(S) -> S + 'static,
+    {
+        State::new(move |s| (f(s), ()))
+    }
+}
+
+impl<S, A> Clone for State<S, A> {
+    fn clone(&self) -> Self {
+        State {
+            op: Rc::clone(&self.op),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, A> fmt::Debug for State<S, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "State")
+    }
+}
+
+impl<S: 'static, A: Clone + 'static> Pure for State<S, A> {
+    type Elm = A;
+    type M<U: Clone> = State<S, U>;
+
+    fn pure(value: A) -> State<S, A> {
+        State::new(move |s| (s, value.clone()))
+    }
+
+    fn unit() -> State<S, ()> {
+        State::new(|s| (s, ()))
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::{Pure, State};
+
+    fn f(n: &i64) -> State<i64, i64> {
+        let n = *n;
+        State::new(move |s: i64| (s.wrapping_add(n), s.wrapping_mul(n)))
+    }
+
+    fn g(n: &i64) -> State<i64, i64> {
+        let n = *n;
+        State::new(move |s: i64| (s.wrapping_sub(1), n.wrapping_add(s)))
+    }
+
+    #[quickcheck]
+    fn monad_left_identity(n: i64, s: i64) -> bool {
+        State::pure(n).bind(f).run(s) == f(&n).run(s)
+    }
+
+    #[quickcheck]
+    fn monad_right_identity(n: i64, s: i64) -> bool {
+        let m = f(&n);
+        m.clone().bind(|x| State::pure(*x)).run(s) == m.run(s)
+    }
+
+    #[quickcheck]
+    fn monad_associativity(n: i64, s: i64) -> bool {
+        let m = f(&n);
+        m.clone().bind(f).bind(g).run(s) == m.bind(|x| f(x).bind(g)).run(s)
+    }
+
+    #[quickcheck]
+    fn functor_identity(n: i64, s: i64) -> bool {
+        let m = f(&n);
+        m.clone().fmap(|x| *x).run(s) == m.run(s)
+    }
+
+    #[quickcheck]
+    fn functor_composition(n: i64, s: i64) -> bool {
+        let m = f(&n);
+        m.clone()
+            .fmap(|x| x.wrapping_add(1))
+            .fmap(|x| x.wrapping_mul(2))
+            .run(s)
+            == m.fmap(|x| x.wrapping_add(1).wrapping_mul(2)).run(s)
+    }
+
+    #[quickcheck]
+    fn ap_runs_functions_first(n: i64, s: i64) -> bool {
+        let fs = State::new(|s: i64| (s.wrapping_mul(3), move |x: &i64| x.wrapping_add(s)));
+        let m = f(&n);
+        let expected = fs
+            .clone()
+            .bind(move |h| {
+                let h = *h;
+                f(&n).fmap(move |x| h(x))
+            })
+            .run(s);
+        m.ap(fs).run(s) == expected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Pure, State};
+
+    #[test]
+    fn test_get_put_modify() {
+        let program = State::get()
+            .bind(|n: &i32| State::put(n * 2))
+            .bind(|_| State::modify(|n: i32| n + 1))
+            .bind(|_| State::inspect(|n: &i32| n.to_string()));
+        assert_eq!(program.run(5), (11, "11".to_string()));
+        assert_eq!(program.eval(5), "11".to_string());
+        assert_eq!(program.exec(5), 11);
+    }
+
+    #[test]
+    fn test_run_is_repeatable() {
+        let program = State::modify(|n: i32| n + 1).bind(|_| State::get());
+        assert_eq!(program.run(1), (2, 2));
+        assert_eq!(program.run(10), (11, 11));
+    }
+
+    #[test]
+    fn test_million_left_nested_binds() {
+        let mut program = State::<u64, u64>::pure(0);
+        for _ in 0..1_000_000 {
+            program = program.bind(|n| {
+                let n = *n;
+                State::new(move |s: u64| (s + 1, n + 1))
+            });
+        }
+        assert_eq!(program.run(0), (1_000_000, 1_000_000));
+    }
+
+    #[test]
+    fn test_million_right_nested_binds() {
+        fn count_down(n: u64) -> State<u64, u64> {
+            if n == 0 {
+                State::get()
+            } else {
+                State::modify(|s: u64| s + 1).bind(move |_| count_down(n - 1))
+            }
+        }
+        assert_eq!(count_down(1_000_000).eval(0), 1_000_000);
+    }
+}
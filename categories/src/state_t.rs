@@ -0,0 +1,257 @@
+use std::fmt;
+use std::rc::Rc;
+
+use crate::{Apply, MonadFamily, Pure};
+
+type Transition<S, M, A> = Rc<dyn Fn(S) -> <M as Apply>::M<(S, A)>>;
+
+/// StateTは、内側のモナドの計算に状態遷移を重ねたモナド変換子です。
+///
+/// 状態`S`を受け取り、`M<(S, A)>`を返す関数を包みます。失敗する状態遷移や、
+/// 複数の結果を持つ状態遷移を記述することができます。
+/// 内側のモナドは`MonadFamily`を実装した代表の型で指定します(例: `Option<()>`)。
+///
+/// # 型クラスとの関係
+///
+/// `State`と同じく、`fmap`、`ap`、`bind`は固有メソッドとして提供しています([型クラス階層の「関数を保持する型と型クラス」](https://github.com/j5ik2o/rust-fp/blob/main/categories/src/hierarchy.md))。
+///
+/// `State`と異なり、`bind`の連鎖はトランポリン化していません。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{for_yield, Pure, StateT};
+///
+/// type Stack = StateT<Vec<i32>, Option<()>, i32>;
+///
+/// fn pop() -> Stack {
+///     StateT::new(|mut stack: Vec<i32>| stack.pop().map(|top| (stack, top)))
+/// }
+///
+/// let sum = for_yield! {
+///     move;
+///     bind a = pop();
+///     bind b = pop();
+///     yield Stack::pure(a + b)
+/// };
+/// assert_eq!(sum.run(vec![1, 2, 3]), Some((vec![1], 5)));
+/// assert_eq!(sum.run(vec![1]), None);
+/// ```
+pub struct StateT<S: Clone, M: Apply, A: Clone> {
+    run: Transition<S, M, A>,
+}
+
+impl<S: Clone + 'static, M: MonadFamily + 'static, A: Clone + 'static> StateT<S, M, A> {
+    /// 状態遷移関数から計算を作成します。
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(S) -> <M as Apply>::M<(S, A)> + 'static,
+    {
+        StateT { run: Rc::new(f) }
+    }
+
+    /// 状態から値を取り出す計算を作成します。状態は変更しません。
+    pub fn inspect<F>(f: F) -> Self
+    where
+        F: Fn(&S) -> A + 'static,
+    {
+        StateT::new(move |s: S| {
+            let a = f(&s);
+            M::pure_of((s, a))
+        })
+    }
+
+    /// 内側のモナドの計算を持ち上げます。状態は変更しません。
+    pub fn lift(ma: <M as Apply>::M<A>) -> Self
+    where
+        <M as Apply>::M<A>: Clone + 'static,
+    {
+        StateT::new(move |s: S| M::map_of(ma.clone(), move |a| (s.clone(), a)))
+    }
+
+    /// 初期状態を渡して計算を実行し、最終的な状態と値の組を返します。
+    pub fn run(&self, initial: S) -> <M as Apply>::M<(S, A)> {
+        (self.run)(initial)
+    }
+
+    /// 計算を実行し、最終的な値を返します。
+    pub fn eval(&self, initial: S) -> <M as Apply>::M<A> {
+        M::map_of(self.run(initial), |(_, a)| a)
+    }
+
+    /// 計算を実行し、最終的な状態を返します。
+    pub fn exec(&self, initial: S) -> <M as Apply>::M<S> {
+        M::map_of(self.run(initial), |(s, _)| s)
+    }
+
+    pub fn fmap<B: Clone + 'static, F>(self, f: F) -> StateT<S, M, B>
+    where
+        F: Fn(&A) -> B + 'static,
+    {
+        StateT::new(move |s| M::map_of(self.run(s), |(s, a): (S, A)| (s, f(&a))))
+    }
+
+    pub fn ap<B: Clone + 'static, F>(self, fs: StateT<S, M, F>) -> StateT<S, M, B>
+    where
+        F: Clone + Fn(&A) -> B + 'static,
+    {
+        fs.bind(move |f| {
+            let f = f.clone();
+            self.clone().fmap(move |a| f(a))
+        })
+    }
+
+    pub fn bind<B: Clone + 'static, F>(self, f: F) -> StateT<S, M, B>
+    where
+        F: Fn(&A) -> StateT<S, M, B> + 'static,
+    {
+        StateT::new(move |s| M::bind_of(self.run(s), |(s, a): (S, A)| f(&a).run(s)))
+    }
+}
+
+impl<S: Clone + 'static, M: MonadFamily + 'static> StateT<S, M, S> {
+    /// 現在の状態を値として返す計算を作成します。
+    pub fn get() -> Self {
+        StateT::new(|s: S| M::pure_of((s.clone(), s)))
+    }
+}
+
+impl<S: Clone + 'static, M: MonadFamily + 'static> StateT<S, M, ()> {
+    /// 状態を置き換える計算を作成します。
+    pub fn put(s: S) -> Self {
+        StateT::new(move |_| M::pure_of((s.clone(), ())))
+    }
+
+    /// 状態に関数を適用して更新する計算を作成します。
+    pub fn modify<F>(f: F) -> Self
+    where
+        F: Fn(S) -> S + 'static,
+    {
+        StateT::new(move |s| M::pure_of((f(s), ())))
+    }
+}
+
+impl<S: Clone, M: Apply, A: Clone> Clone for StateT<S, M, A> {
+    fn clone(&self) -> Self {
+        StateT {
+            run: Rc::clone(&self.run),
+        }
+    }
+}
+
+impl<S: Clone, M: Apply, A: Clone> fmt::Debug for StateT<S, M, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "StateT")
+    }
+}
+
+impl<S: Clone + 'static, M: MonadFamily + 'static, A: Clone + 'static> Pure for StateT<S, M, A> {
+    type Elm = A;
+    type M<U: Clone> = StateT<S, M, U>;
+
+    fn pure(value: A) -> StateT<S, M, A> {
+        StateT::new(move |s| M::pure_of((s, value.clone())))
+    }
+
+    fn unit() -> StateT<S, M, ()> {
+        StateT::new(|s| M::pure_of((s, ())))
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::{Pure, StateT};
+
+    type T = StateT<i32, Vec<()>, i32>;
+
+    fn f(n: &i32) -> T {
+        let n = *n;
+        StateT::new(move |s: i32| vec![(s.wrapping_add(n), n), (s, n.wrapping_neg())])
+    }
+
+    fn g(n: &i32) -> T {
+        let n = *n;
+        StateT::new(move |s: i32| {
+            if s % 2 == 0 {
+                vec![]
+            } else {
+                vec![(s.wrapping_mul(3), n.wrapping_add(s))]
+            }
+        })
+    }
+
+    #[quickcheck]
+    fn functor_identity(s: i32) -> bool {
+        let m = T::get();
+        m.clone().fmap(|x| *x).run(s) == m.run(s)
+    }
+
+    #[quickcheck]
+    fn ap_consistency(s: i32) -> bool {
+        let m = T::get();
+        let fs = StateT::<i32, Vec<()>, _>::pure(|x: &i32| x.wrapping_mul(2));
+        m.clone().ap(fs).run(s) == m.fmap(|x| x.wrapping_mul(2)).run(s)
+    }
+
+    #[quickcheck]
+    fn monad_left_identity(n: i32, s: i32) -> bool {
+        T::pure(n).bind(f).run(s) == f(&n).run(s)
+    }
+
+    #[quickcheck]
+    fn monad_right_identity(n: i32, s: i32) -> bool {
+        let m = f(&n);
+        m.clone().bind(|x| T::pure(*x)).run(s) == m.run(s)
+    }
+
+    #[quickcheck]
+    fn monad_associativity(n: i32, s: i32) -> bool {
+        let m = f(&n);
+        m.clone().bind(f).bind(g).run(s) == m.bind(|x| f(x).bind(g)).run(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{for_yield, Pure, StateT};
+
+    #[test]
+    fn test_counter_over_result() {
+        type Counter = StateT<u32, Result<(), String>, u32>;
+
+        fn next_below(limit: u32) -> Counter {
+            StateT::new(move |n: u32| {
+                if n < limit {
+                    Ok((n + 1, n))
+                } else {
+                    Err(format!("limit {} reached", limit))
+                }
+            })
+        }
+
+        let ids = for_yield! {
+            move;
+            bind a = next_below(3);
+            bind b = next_below(3);
+            yield Counter::pure(a * 10 + b)
+        };
+        assert_eq!(ids.run(0), Ok((2, 1)));
+        assert_eq!(ids.run(2), Err("limit 3 reached".to_string()));
+    }
+
+    #[test]
+    fn test_get_put_modify_and_lift() {
+        type T = StateT<i32, Vec<()>, i32>;
+
+        let program = for_yield! {
+            move;
+            bind a = T::lift(vec![1, 2]);
+            bind _modified = StateT::<i32, Vec<()>, ()>::modify(move |s| s + a);
+            bind s = T::get();
+            yield T::pure(s * 100)
+        };
+        assert_eq!(program.run(10), vec![(11, 1100), (12, 1200)]);
+        assert_eq!(program.eval(0), vec![100, 200]);
+        assert_eq!(StateT::<i32, Vec<()>, ()>::put(5).exec(0), vec![5]);
+    }
+}
@@ -0,0 +1,258 @@
+use crate::{ApplicativeFamily, Apply, Functor};
+
+/// Traverseは、コンテナの構造を保ったまま、各要素にApplicativeな作用を適用する型クラスです。
+///
+/// `traverse`は各要素に作用を伴う関数を適用し、その作用をコンテナの外側にまとめます。
+/// `sequence`は作用を持つ要素のコンテナを、コンテナを持つ作用に変換します。
+/// 例えば`Vec<Result<A, E>>`は`Result<Vec<A>, E>`に、`Vec<Option<A>>`は`Option<Vec<A>>`になります。
+///
+/// # 型クラス階層における位置
+///
+/// TraverseはFunctorを拡張した型クラスです。走査先の作用は`ApplicativeFamily`を
+/// 実装したApplicativeであれば何でも構いません。
+///
+/// # Traverseの法則
+///
+/// 1. 恒等法則：`pure`で走査しても値は変わらない
+///    ```rust,ignore
+///    x.traverse(|a| Some(a.clone())) == Some(x)
+///    ```
+///
+/// 2. 自然性の法則：自然変換`t`は`traverse`と可換である
+///    ```rust,ignore
+///    t(x.traverse(f)) == x.traverse(|a| t(f(a)))
+///    ```
+///
+/// 3. 合成法則：2回の走査は1回の合成された走査と等しい
+///    ```rust,ignore
+///    x.traverse(f).bind(|ys| ys.traverse(g)) == x.traverse(|a| f(a).bind(g))
+///    ```
+///
+/// # メソッド
+///
+/// * `traverse` - 各要素に作用を伴う関数を適用し、作用をコンテナの外側にまとめる
+/// * `sequence` - 作用を持つ要素のコンテナを、コンテナを持つ作用に変換する
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::Traverse;
+///
+/// let values = vec![1, 2, 3];
+/// let result = values.traverse(|x| if *x > 0 { Some(*x * 2) } else { None });
+/// assert_eq!(result, Some(vec![2, 4, 6]));
+///
+/// let results: Vec<Result<i32, &str>> = vec![Ok(1), Err("error"), Ok(3)];
+/// assert_eq!(results.sequence(), Err("error"));
+/// ```
+pub trait Traverse: Functor + Sized {
+    fn traverse<G, F>(self, f: F) -> <G as Apply>::M<Self::M<<G as Apply>::Elm>>
+    where
+        G: ApplicativeFamily,
+        <G as Apply>::Elm: Clone,
+        Self::M<<G as Apply>::Elm>: Clone,
+        F: Fn(&Self::Elm) -> G;
+
+    fn sequence(self) -> <Self::Elm as Apply>::M<Self::M<<Self::Elm as Apply>::Elm>>
+    where
+        Self::Elm: ApplicativeFamily + Clone,
+        <Self::Elm as Apply>::Elm: Clone,
+        Self::M<<Self::Elm as Apply>::Elm>: Clone,
+    {
+        self.traverse(|g: &Self::Elm| g.clone())
+    }
+}
+
+/// `Vec<A>`に対するTraverseの実装
+///
+/// 要素は先頭から順に走査されます。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::Traverse;
+///
+/// let values: Vec<Option<i32>> = vec![Some(1), Some(2)];
+/// assert_eq!(values.sequence(), Some(vec![1, 2]));
+///
+/// let values: Vec<Option<i32>> = vec![Some(1), None];
+/// assert_eq!(values.sequence(), None);
+/// ```
+impl<A> Traverse for Vec<A> {
+    fn traverse<G, F>(self, f: F) -> <G as Apply>::M<Vec<<G as Apply>::Elm>>
+    where
+        G: ApplicativeFamily,
+        <G as Apply>::Elm: Clone,
+        Vec<<G as Apply>::Elm>: Clone,
+        F: Fn(&A) -> G,
+    {
+        let size = self.len();
+        self.iter()
+            .fold(G::pure_of(Vec::with_capacity(size)), |acc, a| {
+                G::map2(acc, f(a), |mut v, b| {
+                    v.push(b);
+                    v
+                })
+            })
+    }
+}
+
+/// `Option<A>`に対するTraverseの実装
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::Traverse;
+///
+/// let value: Option<Result<i32, &str>> = Some(Ok(1));
+/// assert_eq!(value.sequence(), Ok(Some(1)));
+///
+/// let value: Option<Result<i32, &str>> = None;
+/// assert_eq!(value.sequence(), Ok(None));
+/// ```
+impl<A> Traverse for Option<A> {
+    fn traverse<G, F>(self, f: F) -> <G as Apply>::M<Option<<G as Apply>::Elm>>
+    where
+        G: ApplicativeFamily,
+        <G as Apply>::Elm: Clone,
+        Option<<G as Apply>::Elm>: Clone,
+        F: Fn(&A) -> G,
+    {
+        match self {
+            Some(a) => G::map2(G::pure_of(()), f(&a), |_, b| Some(b)),
+            None => G::pure_of(None),
+        }
+    }
+}
+
+/// `Result<A, E>`に対するTraverseの実装
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::Traverse;
+///
+/// let value: Result<Option<i32>, &str> = Ok(Some(1));
+/// assert_eq!(value.sequence(), Some(Ok(1)));
+///
+/// let value: Result<Option<i32>, &str> = Err("error");
+/// assert_eq!(value.sequence(), Some(Err("error")));
+/// ```
+impl<A, E> Traverse for Result<A, E> {
+    fn traverse<G, F>(self, f: F) -> <G as Apply>::M<Result<<G as Apply>::Elm, E>>
+    where
+        G: ApplicativeFamily,
+        <G as Apply>::Elm: Clone,
+        Result<<G as Apply>::Elm, E>: Clone,
+        F: Fn(&A) -> G,
+    {
+        match self {
+            Ok(a) => G::map2(G::pure_of(()), f(&a), |_, b| Ok(b)),
+            Err(e) => G::pure_of(Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    mod vec {
+        use crate::{Bind, Functor, Traverse};
+
+        fn f(x: &i32) -> Option<i32> {
+            if *x % 7 == 0 {
+                None
+            } else {
+                Some(*x * 2)
+            }
+        }
+
+        fn g(x: &i32) -> Option<i32> {
+            if *x % 5 == 0 {
+                None
+            } else {
+                Some(*x + 1)
+            }
+        }
+
+        #[quickcheck]
+        fn traverse_identity(xs: Vec<i32>) -> bool {
+            xs.clone().traverse(|x| Some(*x)) == Some(xs)
+        }
+
+        #[quickcheck]
+        fn traverse_naturality(xs: Vec<i32>) -> bool {
+            xs.clone().traverse(f).ok_or(()) == xs.traverse(|x| f(x).ok_or(()))
+        }
+
+        #[quickcheck]
+        fn traverse_composition(xs: Vec<i32>) -> bool {
+            xs.clone()
+                .traverse(f)
+                .bind(|ys: &Vec<i32>| ys.clone().traverse(g))
+                == xs.traverse(|x| f(x).bind(g))
+        }
+
+        #[quickcheck]
+        fn sequence_consistency(xs: Vec<i32>) -> bool {
+            xs.clone().traverse(f) == xs.fmap(f).sequence()
+        }
+
+        #[test]
+        fn traverse_with_vec_effect() {
+            let result = vec![1, 2].traverse(|x| vec![*x, *x * 10]);
+            assert_eq!(
+                result,
+                vec![vec![1, 2], vec![1, 20], vec![10, 2], vec![10, 20]]
+            );
+        }
+
+        #[test]
+        fn sequence_returns_first_error() {
+            let results: Vec<Result<i32, &str>> = vec![Ok(1), Err("first"), Err("second")];
+            assert_eq!(results.sequence(), Err("first"));
+        }
+    }
+
+    mod option {
+        use crate::{Bind, Traverse};
+
+        fn f(x: &i32) -> Result<i32, String> {
+            if *x % 7 == 0 {
+                Err(format!("{} is divisible by 7", x))
+            } else {
+                Ok(*x * 2)
+            }
+        }
+
+        #[quickcheck]
+        fn traverse_identity(n: Option<i32>) -> bool {
+            n.traverse(|x| Some(*x)) == Some(n)
+        }
+
+        #[quickcheck]
+        fn traverse_naturality(n: Option<i32>) -> bool {
+            n.traverse(f).ok() == n.traverse(|x| f(x).ok())
+        }
+
+        #[quickcheck]
+        fn traverse_composition(n: Option<i32>) -> bool {
+            let g = |x: &i32| -> Result<i32, String> { Ok(*x + 1) };
+            n.traverse(f).bind(|ys: &Option<i32>| ys.traverse(g)) == n.traverse(|x| f(x).bind(g))
+        }
+    }
+
+    mod result {
+        use crate::Traverse;
+
+        #[quickcheck]
+        fn traverse_identity(n: Result<i32, String>) -> bool {
+            n.clone().traverse(|x| Some(*x)) == Some(n)
+        }
+
+        #[quickcheck]
+        fn traverse_naturality(n: Result<i32, String>) -> bool {
+            let f = |x: &i32| if *x % 7 == 0 { None } else { Some(*x * 2) };
+            n.clone().traverse(f).ok_or(()) == n.traverse(|x| f(x).ok_or(()))
+        }
+    }
+}
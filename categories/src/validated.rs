@@ -0,0 +1,378 @@
+use crate::{Applicative, ApplicativeFamily, Apply, Functor, NonEmptyVec, Pure, Semigroup};
+
+/// Validatedは、検証の結果を表す型で、すべてのエラーを蓄積します。
+///
+/// `Result`の`Bind`は最初のエラーで処理を打ち切りますが、Validatedの`Apply`は
+/// 独立した検証のエラーを`Semigroup::combine`で結合します。
+/// フォームや設定ファイルの検証で、すべての問題を一度に報告する用途に適しています。
+///
+/// # 型クラスとの関係
+///
+/// Validatedは`Functor`、`Apply`、`Pure`、`Applicative`を実装しますが、`Bind`と`Monad`は実装しません。
+/// `Bind`は前の結果に依存して次の検証を行うため、エラーを蓄積する`Apply`と整合しないためです。
+/// 前の結果に依存する検証には、最初のエラーで打ち切る`and_then`を使用します。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{NonEmptyVec, Validated, ValidatedNev};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct User {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// fn validate_name(name: &str) -> ValidatedNev<String, String> {
+///     if name.is_empty() {
+///         Validated::invalid_nev("name is empty".to_string())
+///     } else {
+///         Validated::Valid(name.to_string())
+///     }
+/// }
+///
+/// fn validate_age(age: i32) -> ValidatedNev<String, u32> {
+///     if age < 0 {
+///         Validated::invalid_nev(format!("age {} is negative", age))
+///     } else {
+///         Validated::Valid(age as u32)
+///     }
+/// }
+///
+/// let user = validate_name("alice").map2(validate_age(20), |name, age| User { name, age });
+/// assert_eq!(user, Validated::Valid(User { name: "alice".to_string(), age: 20 }));
+///
+/// let user = validate_name("").map2(validate_age(-1), |name, age| User { name, age });
+/// assert_eq!(
+///     user,
+///     Validated::Invalid(NonEmptyVec::new(
+///         "name is empty".to_string(),
+///         vec!["age -1 is negative".to_string()]
+///     ))
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Validated<E, A> {
+    Valid(A),
+    Invalid(E),
+}
+
+/// エラーを`NonEmptyVec`に蓄積するValidatedです。
+pub type ValidatedNev<E, A> = Validated<NonEmptyVec<E>, A>;
+
+impl<E, A> Validated<E, A> {
+    pub fn invalid_nev(error: E) -> ValidatedNev<E, A> {
+        Validated::Invalid(NonEmptyVec::singleton(error))
+    }
+
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Validated::Valid(_))
+    }
+
+    pub fn is_invalid(&self) -> bool {
+        !self.is_valid()
+    }
+
+    /// エラーを1要素の`NonEmptyVec`に変換します。
+    pub fn to_nev(self) -> ValidatedNev<E, A> {
+        self.map_invalid(NonEmptyVec::singleton)
+    }
+
+    /// エラーを変換します。
+    pub fn map_invalid<E2, F>(self, f: F) -> Validated<E2, A>
+    where
+        F: FnOnce(E) -> E2,
+    {
+        match self {
+            Validated::Valid(a) => Validated::Valid(a),
+            Validated::Invalid(e) => Validated::Invalid(f(e)),
+        }
+    }
+
+    /// 前の結果に依存する検証を続けます。エラーは蓄積せず、最初のエラーで打ち切ります。
+    pub fn and_then<B, F>(self, f: F) -> Validated<E, B>
+    where
+        F: FnOnce(A) -> Validated<E, B>,
+    {
+        match self {
+            Validated::Valid(a) => f(a),
+            Validated::Invalid(e) => Validated::Invalid(e),
+        }
+    }
+
+    pub fn into_result(self) -> Result<A, E> {
+        self.into()
+    }
+}
+
+impl<E: Semigroup, A> Validated<E, A> {
+    /// 2つの検証結果を結合します。両方が無効な場合は、エラーを`self`、`b`の順に結合します。
+    pub fn map2<B, Z, F>(self, b: Validated<E, B>, f: F) -> Validated<E, Z>
+    where
+        F: FnOnce(A, B) -> Z,
+    {
+        match (self, b) {
+            (Validated::Valid(a), Validated::Valid(b)) => Validated::Valid(f(a, b)),
+            (Validated::Invalid(e1), Validated::Invalid(e2)) => Validated::Invalid(e1.combine(e2)),
+            (Validated::Invalid(e), _) | (_, Validated::Invalid(e)) => Validated::Invalid(e),
+        }
+    }
+
+    /// 3つの検証結果を結合します。
+    pub fn map3<B, C, Z, F>(self, b: Validated<E, B>, c: Validated<E, C>, f: F) -> Validated<E, Z>
+    where
+        F: FnOnce(A, B, C) -> Z,
+    {
+        self.map2(b, |a, b| (a, b)).map2(c, |(a, b), c| f(a, b, c))
+    }
+
+    /// 4つの検証結果を結合します。
+    pub fn map4<B, C, D, Z, F>(
+        self,
+        b: Validated<E, B>,
+        c: Validated<E, C>,
+        d: Validated<E, D>,
+        f: F,
+    ) -> Validated<E, Z>
+    where
+        F: FnOnce(A, B, C, D) -> Z,
+    {
+        self.map3(b, c, |a, b, c| (a, b, c))
+            .map2(d, |(a, b, c), d| f(a, b, c, d))
+    }
+
+    /// 5つの検証結果を結合します。
+    pub fn map5<B, C, D, G, Z, F>(
+        self,
+        b: Validated<E, B>,
+        c: Validated<E, C>,
+        d: Validated<E, D>,
+        g: Validated<E, G>,
+        f: F,
+    ) -> Validated<E, Z>
+    where
+        F: FnOnce(A, B, C, D, G) -> Z,
+    {
+        self.map4(b, c, d, |a, b, c, d| (a, b, c, d))
+            .map2(g, |(a, b, c, d), g| f(a, b, c, d, g))
+    }
+}
+
+impl<E, A> From<Result<A, E>> for Validated<E, A> {
+    fn from(result: Result<A, E>) -> Self {
+        match result {
+            Ok(a) => Validated::Valid(a),
+            Err(e) => Validated::Invalid(e),
+        }
+    }
+}
+
+impl<E, A> From<Validated<E, A>> for Result<A, E> {
+    fn from(validated: Validated<E, A>) -> Self {
+        match validated {
+            Validated::Valid(a) => Ok(a),
+            Validated::Invalid(e) => Err(e),
+        }
+    }
+}
+
+impl<E, A> Functor for Validated<E, A> {
+    type Elm = A;
+    type M<B: Clone> = Validated<E, B>;
+
+    fn fmap<B: Clone, F>(self, f: F) -> Validated<E, B>
+    where
+        F: Fn(&A) -> B,
+    {
+        match self {
+            Validated::Valid(a) => Validated::Valid(f(&a)),
+            Validated::Invalid(e) => Validated::Invalid(e),
+        }
+    }
+}
+
+impl<E: Semigroup, A> Apply for Validated<E, A> {
+    type Elm = A;
+    type M<B: Clone> = Validated<E, B>;
+
+    fn ap<B: Clone, F>(self, fs: Validated<E, F>) -> Validated<E, B>
+    where
+        F: Clone + Fn(&A) -> B,
+    {
+        fs.map2(self, |f, a| f(&a))
+    }
+}
+
+impl<E, A: Clone> Pure for Validated<E, A> {
+    type Elm = A;
+    type M<U: Clone> = Validated<E, U>;
+
+    fn pure(value: A) -> Validated<E, A> {
+        Validated::Valid(value)
+    }
+
+    fn unit() -> Validated<E, ()> {
+        Validated::Valid(())
+    }
+}
+
+impl<E: Semigroup, A: Clone> Applicative for Validated<E, A> {}
+
+impl<E: Semigroup, A: Clone> ApplicativeFamily for Validated<E, A> {
+    fn pure_of<U: Clone>(value: U) -> Validated<E, U> {
+        Validated::Valid(value)
+    }
+
+    fn map_of<U: Clone, V: Clone, F>(fu: Validated<E, U>, f: F) -> Validated<E, V>
+    where
+        F: Fn(U) -> V,
+    {
+        match fu {
+            Validated::Valid(u) => Validated::Valid(f(u)),
+            Validated::Invalid(e) => Validated::Invalid(e),
+        }
+    }
+
+    fn map2<U: Clone, V: Clone, F>(fu: Validated<E, U>, fa: Self, f: F) -> Validated<E, V>
+    where
+        F: Fn(U, A) -> V,
+    {
+        fu.map2(fa, f)
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::{Apply, Functor, Pure, Validated};
+
+    fn validated(valid: bool, n: i64, e: Vec<String>) -> Validated<Vec<String>, i64> {
+        if valid {
+            Validated::Valid(n)
+        } else {
+            Validated::Invalid(e)
+        }
+    }
+
+    #[quickcheck]
+    fn functor_identity(valid: bool, n: i64, e: Vec<String>) -> bool {
+        let v = validated(valid, n, e);
+        v.clone().fmap(|x| *x) == v
+    }
+
+    #[quickcheck]
+    fn functor_composition(valid: bool, n: i64, e: Vec<String>) -> bool {
+        let v = validated(valid, n, e);
+        v.clone()
+            .fmap(|x| x.wrapping_add(1))
+            .fmap(|x| x.wrapping_mul(2))
+            == v.fmap(|x| x.wrapping_add(1).wrapping_mul(2))
+    }
+
+    #[quickcheck]
+    fn applicative_identity(valid: bool, n: i64, e: Vec<String>) -> bool {
+        let v = validated(valid, n, e);
+        v.clone().ap(Validated::pure(|x: &i64| *x)) == v
+    }
+
+    #[quickcheck]
+    fn applicative_homomorphism(n: i64) -> bool {
+        let f = |x: &i64| x.wrapping_mul(3);
+        Validated::<Vec<String>, i64>::pure(n).ap(Validated::pure(f)) == Validated::pure(f(&n))
+    }
+
+    #[quickcheck]
+    fn ap_accumulates_errors(e1: Vec<String>, e2: Vec<String>) -> bool {
+        let fs: Validated<Vec<String>, fn(&i64) -> i64> = Validated::Invalid(e1.clone());
+        let v: Validated<Vec<String>, i64> = Validated::Invalid(e2.clone());
+        let mut expected = e1;
+        expected.extend(e2);
+        v.ap(fs) == Validated::Invalid(expected)
+    }
+
+    #[quickcheck]
+    fn result_round_trip(valid: bool, n: i64, e: Vec<String>) -> bool {
+        let v = validated(valid, n, e);
+        Validated::from(v.clone().into_result()) == v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NonEmptyVec, Traverse, Validated, ValidatedNev};
+
+    fn positive(n: i32) -> ValidatedNev<String, i32> {
+        if n > 0 {
+            Validated::Valid(n)
+        } else {
+            Validated::invalid_nev(format!("{} is not positive", n))
+        }
+    }
+
+    #[test]
+    fn test_map5_accumulates_all_errors() {
+        let result = positive(1).map5(
+            positive(-2),
+            positive(3),
+            positive(0),
+            positive(-5),
+            |a, b, c, d, e| a + b + c + d + e,
+        );
+        assert_eq!(
+            result,
+            Validated::Invalid(NonEmptyVec::new(
+                "-2 is not positive".to_string(),
+                vec![
+                    "0 is not positive".to_string(),
+                    "-5 is not positive".to_string()
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_map5_valid() {
+        let result = positive(1).map5(
+            positive(2),
+            positive(3),
+            positive(4),
+            positive(5),
+            |a, b, c, d, e| a + b + c + d + e,
+        );
+        assert_eq!(result, Validated::Valid(15));
+    }
+
+    #[test]
+    fn test_and_then_short_circuits() {
+        let result = positive(-1).and_then(|n| positive(n - 10));
+        assert_eq!(
+            result,
+            Validated::invalid_nev("-1 is not positive".to_string())
+        );
+    }
+
+    #[test]
+    fn test_from_result() {
+        let result: Result<i32, String> = Err("boom".to_string());
+        assert_eq!(
+            Validated::from(result).to_nev(),
+            Validated::<String, i32>::invalid_nev("boom".to_string())
+        );
+        assert_eq!(Validated::<String, i32>::Valid(1).into_result(), Ok(1));
+    }
+
+    #[test]
+    fn test_traverse_accumulates_errors() {
+        let result = vec![1, -2, 3, -4].traverse(|n: &i32| positive(*n));
+        assert_eq!(
+            result,
+            Validated::Invalid(NonEmptyVec::new(
+                "-2 is not positive".to_string(),
+                vec!["-4 is not positive".to_string()]
+            ))
+        );
+        assert_eq!(
+            vec![1, 2].traverse(|n: &i32| positive(*n)),
+            Validated::Valid(vec![1, 2])
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{
+    Applicative, ApplicativeFamily, Apply, Bind, Functor, Monad, MonadFamily, Monoid, Pure,
+};
+
+/// Writerは、計算結果の値`A`とともに、ログ`W`を蓄積するモナドです。
+///
+/// 各ステップで追加されたログは`Semigroup::combine`で結合されます。
+/// 副作用を使わずに監査ログや計測値を収集することができます。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{for_yield, Bind, Pure, Writer};
+///
+/// fn deposit(amount: u32) -> Writer<Vec<String>, u32> {
+///     Writer::tell(vec![format!("deposit {}", amount)]).bind(move |_| Writer::pure(amount))
+/// }
+///
+/// let program = for_yield! {
+///     bind a = deposit(100);
+///     bind b = deposit(50);
+///     yield Writer::pure(a + b)
+/// };
+///
+/// let (log, total) = program.run();
+/// assert_eq!(total, 150);
+/// assert_eq!(log, vec!["deposit 100".to_string(), "deposit 50".to_string()]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Writer<W, A> {
+    log: W,
+    value: A,
+}
+
+impl<W: Monoid, A> Writer<W, A> {
+    pub fn new(log: W, value: A) -> Self {
+        Writer { log, value }
+    }
+
+    pub fn log(&self) -> &W {
+        &self.log
+    }
+
+    pub fn value(&self) -> &A {
+        &self.value
+    }
+
+    /// ログと値の組を返します。
+    pub fn run(self) -> (W, A) {
+        (self.log, self.value)
+    }
+
+    /// 値とともに、その計算で蓄積されたログを値として返します。
+    pub fn listen(self) -> Writer<W, (A, W)>
+    where
+        W: Clone,
+    {
+        Writer {
+            log: self.log.clone(),
+            value: (self.value, self.log),
+        }
+    }
+
+    /// 蓄積されたログを変換します。
+    pub fn censor<F>(self, f: F) -> Self
+    where
+        F: FnOnce(W) -> W,
+    {
+        Writer {
+            log: f(self.log),
+            value: self.value,
+        }
+    }
+}
+
+impl<W: Monoid> Writer<W, ()> {
+    /// ログを追加するだけの計算を作成します。
+    pub fn tell(log: W) -> Self {
+        Writer { log, value: () }
+    }
+}
+
+impl<W: Monoid, A> Functor for Writer<W, A> {
+    type Elm = A;
+    type M<B: Clone> = Writer<W, B>;
+
+    fn fmap<B: Clone, F>(self, f: F) -> Writer<W, B>
+    where
+        F: Fn(&A) -> B,
+    {
+        Writer {
+            value: f(&self.value),
+            log: self.log,
+        }
+    }
+}
+
+impl<W: Monoid, A> Apply for Writer<W, A> {
+    type Elm = A;
+    type M<B: Clone> = Writer<W, B>;
+
+    fn ap<B: Clone, F>(self, fs: Writer<W, F>) -> Writer<W, B>
+    where
+        F: Clone + Fn(&A) -> B,
+    {
+        Writer {
+            value: (fs.value)(&self.value),
+            log: fs.log.combine(self.log),
+        }
+    }
+}
+
+impl<W: Monoid, A: Clone> Pure for Writer<W, A> {
+    type Elm = A;
+    type M<U: Clone> = Writer<W, U>;
+
+    fn pure(value: A) -> Writer<W, A> {
+        Writer {
+            log: W::empty(),
+            value,
+        }
+    }
+
+    fn unit() -> Writer<W, ()> {
+        Writer::pure(())
+    }
+}
+
+impl<W: Monoid, A> Bind for Writer<W, A> {
+    type Elm = A;
+    type M<B: Clone> = Writer<W, B>;
+
+    fn bind<B: Clone, F>(self, f: F) -> Writer<W, B>
+    where
+        F: Fn(&A) -> Writer<W, B>,
+    {
+        let next = f(&self.value);
+        Writer {
+            log: self.log.combine(next.log),
+            value: next.value,
+        }
+    }
+}
+
+impl<W: Monoid, A: Clone> Applicative for Writer<W, A> {}
+impl<W: Monoid, A: Clone> Monad for Writer<W, A> {}
+
+impl<W: Monoid, A: Clone> ApplicativeFamily for Writer<W, A> {
+    fn pure_of<U: Clone>(value: U) -> Writer<W, U> {
+        Writer {
+            log: W::empty(),
+            value,
+        }
+    }
+
+    fn map_of<U: Clone, V: Clone, F>(fu: Writer<W, U>, f: F) -> Writer<W, V>
+    where
+        F: Fn(U) -> V,
+    {
+        Writer {
+            log: fu.log,
+            value: f(fu.value),
+        }
+    }
+
+    fn map2<U: Clone, V: Clone, F>(fu: Writer<W, U>, fa: Self, f: F) -> Writer<W, V>
+    where
+        F: Fn(U, A) -> V,
+    {
+        Writer {
+            log: fu.log.combine(fa.log),
+            value: f(fu.value, fa.value),
+        }
+    }
+}
+
+impl<W: Monoid, A: Clone> MonadFamily for Writer<W, A> {
+    fn bind_of<U: Clone, V: Clone, F>(fu: Writer<W, U>, f: F) -> Writer<W, V>
+    where
+        F: Fn(U) -> Writer<W, V>,
+    {
+        let next = f(fu.value);
+        Writer {
+            log: fu.log.combine(next.log),
+            value: next.value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::{Apply, Bind, Functor, Pure, Writer};
+
+    fn f(n: &i64) -> Writer<Vec<String>, i64> {
+        Writer::new(vec![format!("f {}", n)], n.wrapping_mul(2))
+    }
+
+    fn g(n: &i64) -> Writer<Vec<String>, i64> {
+        Writer::new(vec![format!("g {}", n)], n.wrapping_add(3))
+    }
+
+    #[quickcheck]
+    fn functor_identity(log: String, n: i64) -> bool {
+        let w = Writer::new(log, n);
+        w.clone().fmap(|x| *x) == w
+    }
+
+    #[quickcheck]
+    fn functor_composition(log: i32, n: i64) -> bool {
+        let w = Writer::new(log, n);
+        w.clone()
+            .fmap(|x| x.wrapping_add(1))
+            .fmap(|x| x.wrapping_mul(2))
+            == w.fmap(|x| x.wrapping_add(1).wrapping_mul(2))
+    }
+
+    #[quickcheck]
+    fn apply_combines_logs(l1: String, l2: String, n: i64) -> bool {
+        let fs = Writer::new(l1.clone(), |x: &i64| x.wrapping_add(1));
+        Writer::new(l2.clone(), n).ap(fs) == Writer::new(l1 + &l2, n.wrapping_add(1))
+    }
+
+    #[quickcheck]
+    fn monad_left_identity(n: i64) -> bool {
+        Writer::pure(n).bind(f) == f(&n)
+    }
+
+    #[quickcheck]
+    fn monad_right_identity(log: Vec<String>, n: i64) -> bool {
+        let w = Writer::new(log, n);
+        w.clone().bind(|x| Writer::pure(*x)) == w
+    }
+
+    #[quickcheck]
+    fn monad_associativity(log: Vec<String>, n: i64) -> bool {
+        let w = Writer::new(log, n);
+        w.clone().bind(f).bind(g) == w.bind(|x| f(x).bind(g))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bind, Pure, Traverse, Writer};
+
+    #[test]
+    fn test_tell_with_numeric_monoid() {
+        let program = Writer::tell(2)
+            .bind(|_| Writer::tell(3))
+            .bind(|_| Writer::pure("done"));
+        assert_eq!(program.run(), (5, "done"));
+    }
+
+    #[test]
+    fn test_listen() {
+        let program = Writer::new("a".to_string(), 1).bind(|n| Writer::new("b".to_string(), n + 1));
+        assert_eq!(
+            program.listen().run(),
+            ("ab".to_string(), (2, "ab".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_censor() {
+        let program = Writer::new(vec!["secret".to_string(), "public".to_string()], 1)
+            .censor(|log| log.into_iter().filter(|l| l != "secret").collect());
+        assert_eq!(program.run(), (vec!["public".to_string()], 1));
+    }
+
+    #[test]
+    fn test_traverse_accumulates_logs() {
+        let result = vec![1, 2, 3].traverse(|n: &i32| Writer::new(vec![*n], n * 10));
+        assert_eq!(result.run(), (vec![1, 2, 3], vec![10, 20, 30]));
+    }
+}
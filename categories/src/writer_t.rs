@@ -0,0 +1,277 @@
+use std::fmt;
+
+use crate::{
+    Applicative, ApplicativeFamily, Apply, Bind, Functor, Monad, MonadFamily, Monoid, Pure,
+};
+
+/// WriterTは、内側のモナドの計算にログの蓄積を重ねたモナド変換子です。
+///
+/// `M<(W, A)>`を包み、`bind`は内側のモナドの作用を保ったまま、各ステップのログを
+/// `Semigroup::combine`で結合します。
+/// 内側のモナドは`MonadFamily`を実装した代表の型で指定します(例: `Option<()>`)。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{for_yield, Bind, Pure, WriterT};
+///
+/// type Audit = WriterT<Vec<String>, Option<()>, i32>;
+///
+/// fn withdraw(balance: i32, amount: i32) -> Audit {
+///     if amount > balance {
+///         WriterT::new(None)
+///     } else {
+///         WriterT::writer(vec![format!("withdraw {}", amount)], balance - amount)
+///     }
+/// }
+///
+/// let result = for_yield! {
+///     bind a = withdraw(100, 30);
+///     bind b = withdraw(*a, 50);
+///     yield Audit::pure(*b)
+/// };
+/// assert_eq!(
+///     result.run(),
+///     Some((vec!["withdraw 30".to_string(), "withdraw 50".to_string()], 20))
+/// );
+/// assert_eq!(withdraw(10, 30).bind(|a| withdraw(*a, 1)).run(), None);
+/// ```
+pub struct WriterT<W: Clone, M: Apply, A: Clone> {
+    value: <M as Apply>::M<(W, A)>,
+}
+
+impl<W: Monoid + Clone, M: MonadFamily, A: Clone> WriterT<W, M, A> {
+    pub fn new(value: <M as Apply>::M<(W, A)>) -> Self {
+        WriterT { value }
+    }
+
+    /// ログと値の組から計算を作成します。
+    pub fn writer(log: W, value: A) -> Self {
+        WriterT::new(M::pure_of((log, value)))
+    }
+
+    /// 内側のモナドの計算を、空のログとともに持ち上げます。
+    pub fn lift(ma: <M as Apply>::M<A>) -> Self {
+        WriterT::new(M::map_of(ma, |a| (W::empty(), a)))
+    }
+
+    /// 包んでいる`M<(W, A)>`を返します。
+    pub fn run(self) -> <M as Apply>::M<(W, A)> {
+        self.value
+    }
+}
+
+impl<W: Monoid + Clone, M: MonadFamily> WriterT<W, M, ()> {
+    /// ログだけを追加する計算を作成します。
+    pub fn tell(log: W) -> Self {
+        WriterT::writer(log, ())
+    }
+}
+
+impl<W: Clone, M: Apply, A: Clone> Clone for WriterT<W, M, A>
+where
+    <M as Apply>::M<(W, A)>: Clone,
+{
+    fn clone(&self) -> Self {
+        WriterT {
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl<W: Clone, M: Apply, A: Clone> PartialEq for WriterT<W, M, A>
+where
+    <M as Apply>::M<(W, A)>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<W: Clone, M: Apply, A: Clone> fmt::Debug for WriterT<W, M, A>
+where
+    <M as Apply>::M<(W, A)>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("WriterT").field(&self.value).finish()
+    }
+}
+
+impl<W: Monoid + Clone, M: MonadFamily, A: Clone> Functor for WriterT<W, M, A> {
+    type Elm = A;
+    type M<B: Clone> = WriterT<W, M, B>;
+
+    fn fmap<B: Clone, F>(self, f: F) -> WriterT<W, M, B>
+    where
+        F: Fn(&A) -> B,
+    {
+        WriterT::new(M::map_of(self.value, |(log, a): (W, A)| (log, f(&a))))
+    }
+}
+
+impl<W: Monoid + Clone, M: MonadFamily, A: Clone> Pure for WriterT<W, M, A> {
+    type Elm = A;
+    type M<U: Clone> = WriterT<W, M, U>;
+
+    fn pure(value: A) -> WriterT<W, M, A> {
+        WriterT::writer(W::empty(), value)
+    }
+
+    fn unit() -> WriterT<W, M, ()> {
+        WriterT::writer(W::empty(), ())
+    }
+}
+
+impl<W: Monoid + Clone, M: MonadFamily, A: Clone> Apply for WriterT<W, M, A>
+where
+    <M as Apply>::M<(W, A)>: Clone,
+{
+    type Elm = A;
+    type M<U: Clone> = WriterT<W, M, U>;
+
+    fn ap<B: Clone, F>(self, fs: WriterT<W, M, F>) -> WriterT<W, M, B>
+    where
+        F: Clone + Fn(&A) -> B,
+    {
+        let value = self.value;
+        WriterT::new(M::bind_of(fs.value, |(first, f): (W, F)| {
+            M::map_of(value.clone(), move |(second, a): (W, A)| {
+                (first.clone().combine(second), f(&a))
+            })
+        }))
+    }
+}
+
+impl<W: Monoid + Clone, M: MonadFamily, A: Clone> Bind for WriterT<W, M, A> {
+    type Elm = A;
+    type M<U: Clone> = WriterT<W, M, U>;
+
+    fn bind<B: Clone, F>(self, f: F) -> WriterT<W, M, B>
+    where
+        F: Fn(&A) -> WriterT<W, M, B>,
+    {
+        WriterT::new(M::bind_of(self.value, |(first, a): (W, A)| {
+            M::map_of(f(&a).value, move |(second, b): (W, B)| {
+                (first.clone().combine(second), b)
+            })
+        }))
+    }
+}
+
+impl<W: Monoid + Clone, M: MonadFamily, A: Clone> Applicative for WriterT<W, M, A> where
+    <M as Apply>::M<(W, A)>: Clone
+{
+}
+
+impl<W: Monoid + Clone, M: MonadFamily, A: Clone> Monad for WriterT<W, M, A> where
+    <M as Apply>::M<(W, A)>: Clone
+{
+}
+
+impl<W: Monoid + Clone, M: MonadFamily, A: Clone> ApplicativeFamily for WriterT<W, M, A>
+where
+    <M as Apply>::M<(W, A)>: Clone,
+{
+    fn pure_of<U: Clone>(value: U) -> WriterT<W, M, U> {
+        WriterT::writer(W::empty(), value)
+    }
+
+    fn map_of<U: Clone, V: Clone, F>(fu: WriterT<W, M, U>, f: F) -> WriterT<W, M, V>
+    where
+        F: Fn(U) -> V,
+    {
+        WriterT::new(M::map_of(fu.value, |(log, u): (W, U)| (log, f(u))))
+    }
+
+    fn map2<U: Clone, V: Clone, F>(fu: WriterT<W, M, U>, fa: Self, f: F) -> WriterT<W, M, V>
+    where
+        F: Fn(U, A) -> V,
+    {
+        let value = fa.value;
+        WriterT::new(M::bind_of(fu.value, |(first, u): (W, U)| {
+            M::map_of(value.clone(), |(second, a): (W, A)| {
+                (first.clone().combine(second), f(u.clone(), a))
+            })
+        }))
+    }
+}
+
+impl<W: Monoid + Clone, M: MonadFamily, A: Clone> MonadFamily for WriterT<W, M, A>
+where
+    <M as Apply>::M<(W, A)>: Clone,
+{
+    fn bind_of<U: Clone, V: Clone, F>(fu: WriterT<W, M, U>, f: F) -> WriterT<W, M, V>
+    where
+        F: Fn(U) -> WriterT<W, M, V>,
+    {
+        WriterT::new(M::bind_of(fu.value, |(first, u): (W, U)| {
+            M::map_of(f(u).value, move |(second, v): (W, V)| {
+                (first.clone().combine(second), v)
+            })
+        }))
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::{Apply, Bind, Functor, Pure, WriterT};
+
+    type T = WriterT<String, Vec<()>, i32>;
+
+    fn f(n: &i32) -> T {
+        WriterT::new(vec![
+            (format!("f{}", n), *n),
+            ("f'".to_string(), n.wrapping_neg()),
+        ])
+    }
+
+    fn g(n: &i32) -> T {
+        WriterT::writer(format!("g{}", n), n.wrapping_mul(2))
+    }
+
+    #[quickcheck]
+    fn functor_identity(values: Vec<(String, i32)>) -> bool {
+        let m = T::new(values);
+        m.clone().fmap(|x| *x) == m
+    }
+
+    #[quickcheck]
+    fn applicative_identity(values: Vec<(String, i32)>) -> bool {
+        let m = T::new(values);
+        m.clone().ap(WriterT::pure(|x: &i32| *x)) == m
+    }
+
+    #[quickcheck]
+    fn monad_left_identity(n: i32) -> bool {
+        T::pure(n).bind(f) == f(&n)
+    }
+
+    #[quickcheck]
+    fn monad_right_identity(values: Vec<(String, i32)>) -> bool {
+        let m = T::new(values);
+        m.clone().bind(|x| T::pure(*x)) == m
+    }
+
+    #[quickcheck]
+    fn monad_associativity(values: Vec<(String, i32)>) -> bool {
+        let m = T::new(values);
+        m.clone().bind(f).bind(g) == m.bind(|x| f(x).bind(g))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{for_yield, Bind, Pure, WriterT};
+
+    #[test]
+    fn test_tell_and_lift() {
+        type T = WriterT<Vec<&'static str>, Vec<()>, i32>;
+
+        let result = for_yield! {
+            bind a = T::lift(vec![1, 2]);
+            bind _logged = WriterT::tell(vec!["seen"]);
+            yield T::pure(a * 10)
+        };
+        assert_eq!(result.run(), vec![(vec!["seen"], 10), (vec!["seen"], 20)]);
+    }
+}
@@ -0,0 +1,103 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Data, DeriveInput, Fields, Generics, Ident, Type};
+
+/// フィールドを束縛する変数名と、フィールドの型を返します。
+pub fn bindings(fields: &Fields) -> (Vec<Ident>, Vec<&Type>) {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let name = field
+                .ident
+                .clone()
+                .unwrap_or_else(|| format_ident!("field_{}", index));
+            (name, &field.ty)
+        })
+        .unzip()
+}
+
+/// 値の構築と分解の両方に使える、フィールドを束縛したパターンを作成します。
+pub fn constructor(path: TokenStream, fields: &Fields, names: &[Ident]) -> TokenStream {
+    match fields {
+        Fields::Named(_) => quote!(#path { #(#names),* }),
+        Fields::Unnamed(_) => quote!(#path ( #(#names),* )),
+        Fields::Unit => path,
+    }
+}
+
+/// フィールドに`values`を順に設定して値を構築する式を作成します。
+pub fn constructor_from_values(
+    path: TokenStream,
+    fields: &Fields,
+    names: &[Ident],
+    values: &[TokenStream],
+) -> TokenStream {
+    match fields {
+        Fields::Named(_) => quote!(#path { #(#names: #values),* }),
+        Fields::Unnamed(_) => quote!(#path ( #(#values),* )),
+        Fields::Unit => path,
+    }
+}
+
+/// 型`ty`が識別子`ident`を含むかどうかを返します。
+pub fn mentions(ty: &Type, ident: &Ident) -> bool {
+    fn go(tokens: TokenStream, ident: &Ident) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(i) => i == *ident,
+            TokenTree::Group(group) => go(group.stream(), ident),
+            _ => false,
+        })
+    }
+    go(quote!(#ty), ident)
+}
+
+/// 型パラメータを含むフィールドの型に`bound`を要求するwhere句を追加します。
+pub fn with_field_bounds(generics: &Generics, types: &[&Type], bound: TokenStream) -> Generics {
+    let mut generics = generics.clone();
+    let params: Vec<Ident> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let where_clause = generics.make_where_clause();
+    for ty in types {
+        if params.iter().any(|param| mentions(ty, param)) {
+            where_clause.predicates.push(parse_quote!(#ty: #bound));
+        }
+    }
+    generics
+}
+
+/// 構造体ではない場合にエラーを返します。
+pub fn struct_fields<'a>(input: &'a DeriveInput, derive: &str) -> syn::Result<&'a Fields> {
+    match &input.data {
+        Data::Struct(data) => Ok(&data.fields),
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            format!("{} can only be derived for structs", derive),
+        )),
+    }
+}
+
+/// 構造体または列挙型の各バリアントについて、構築に使うパスとフィールドを返します。
+pub fn variants<'a>(
+    input: &'a DeriveInput,
+    derive: &str,
+) -> syn::Result<Vec<(TokenStream, &'a Ident, &'a Fields)>> {
+    let name = &input.ident;
+    match &input.data {
+        Data::Struct(data) => Ok(vec![(quote!(#name), name, &data.fields)]),
+        Data::Enum(data) => Ok(data
+            .variants
+            .iter()
+            .map(|variant| {
+                let variant_name = &variant.ident;
+                (quote!(#name::#variant_name), variant_name, &variant.fields)
+            })
+            .collect()),
+        Data::Union(data) => Err(syn::Error::new(
+            data.union_token.span,
+            format!("{} cannot be derived for unions", derive),
+        )),
+    }
+}
@@ -0,0 +1,137 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{DeriveInput, Ident, Type};
+
+use crate::fields::{bindings, constructor, variants};
+use crate::shape::{classify, single_type_param, FieldShape};
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Left,
+    Right,
+}
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let param = single_type_param(input, "Foldable")?;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let variants = variants(input, "Foldable")?;
+    let arms = |direction: Direction| {
+        variants
+            .iter()
+            .map(|(path, _, fields)| {
+                let (names, types) = bindings(fields);
+                let pattern = constructor(path.clone(), fields, &names);
+                let mut steps = names.iter().zip(&types).collect::<Vec<_>>();
+                if let Direction::Right = direction {
+                    steps.reverse();
+                }
+                let steps = steps
+                    .into_iter()
+                    .map(|(field, ty)| {
+                        let step = fold(ty, quote!(#field), param, name, direction)?;
+                        Ok(quote!(let acc = #step;))
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?;
+                Ok(quote!(#pattern => {
+                    #(#steps)*
+                    acc
+                }))
+            })
+            .collect::<syn::Result<Vec<_>>>()
+    };
+    let left_arms = arms(Direction::Left)?;
+    let right_arms = arms(Direction::Right)?;
+
+    Ok(quote! {
+        impl #impl_generics ::rust_fp_categories::Foldable for #name #ty_generics #where_clause {
+            type Elm = #param;
+
+            #[allow(unused_variables)]
+            fn fold_left<__B, __F>(&self, b: __B, f: __F) -> __B
+            where
+                __F: Fn(__B, &#param) -> __B,
+            {
+                let f: &dyn Fn(__B, &#param) -> __B = &f;
+                let acc = b;
+                match self {
+                    #(#left_arms,)*
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn fold_right<__B, __F>(&self, b: __B, f: __F) -> __B
+            where
+                __F: Fn(&#param, __B) -> __B,
+            {
+                let f: &dyn Fn(&#param, __B) -> __B = &f;
+                let acc = b;
+                match self {
+                    #(#right_arms,)*
+                }
+            }
+        }
+    })
+}
+
+/// 型`ty`の値への参照`value`の各要素を、累積値`acc`に畳み込む式を作成します。
+fn fold(
+    ty: &Type,
+    value: TokenStream,
+    param: &Ident,
+    self_name: &Ident,
+    direction: Direction,
+) -> syn::Result<TokenStream> {
+    let method = match direction {
+        Direction::Left => quote!(fold_left),
+        Direction::Right => quote!(fold_right),
+    };
+    Ok(match classify(ty, param, self_name, "Foldable")? {
+        FieldShape::Param => match direction {
+            Direction::Left => quote!(f(acc, #value)),
+            Direction::Right => quote!(f(#value, acc)),
+        },
+        FieldShape::Unrelated => quote!(acc),
+        FieldShape::Recursive => quote!(::rust_fp_categories::Foldable::#method(#value, acc, f)),
+        FieldShape::Boxed(inner) => {
+            let inner = fold(inner, quote!(value), param, self_name, direction)?;
+            quote!({
+                let value = &**#value;
+                #inner
+            })
+        }
+        FieldShape::Tuple(elems) => {
+            let names: Vec<_> = (0..elems.len())
+                .map(|index| format_ident!("value_{}", index))
+                .collect();
+            let mut steps = elems.iter().zip(&names).collect::<Vec<_>>();
+            if let Direction::Right = direction {
+                steps.reverse();
+            }
+            let steps = steps
+                .into_iter()
+                .map(|(elem, name)| {
+                    let step = fold(elem, quote!(#name), param, self_name, direction)?;
+                    Ok(quote!(let acc = #step;))
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote!({
+                let (#(#names,)*) = #value;
+                #(#steps)*
+                acc
+            })
+        }
+        FieldShape::Container(inner) => match classify(inner, param, self_name, "Foldable")? {
+            FieldShape::Param => quote!(::rust_fp_categories::Foldable::#method(#value, acc, f)),
+            _ => {
+                let inner = fold(inner, quote!(value), param, self_name, direction)?;
+                let closure = match direction {
+                    Direction::Left => quote!(|acc, value| #inner),
+                    Direction::Right => quote!(|value, acc| #inner),
+                };
+                quote!(::rust_fp_categories::Foldable::#method(#value, acc, #closure))
+            }
+        },
+    })
+}
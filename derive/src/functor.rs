@@ -0,0 +1,92 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{DeriveInput, Ident, Type};
+
+use crate::fields::{bindings, constructor, constructor_from_values, variants};
+use crate::shape::{classify, replaced_args, single_type_param, FieldShape};
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let param = single_type_param(input, "Functor")?;
+    let mapped = format_ident!("__B");
+    let mapped_args = replaced_args(input, &mapped);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let arms = variants(input, "Functor")?
+        .into_iter()
+        .map(|(path, _, fields)| {
+            let (names, types) = bindings(fields);
+            let pattern = constructor(path.clone(), fields, &names);
+            let values = names
+                .iter()
+                .zip(&types)
+                .map(|(field, ty)| map(ty, quote!(#field), param, name))
+                .collect::<syn::Result<Vec<_>>>()?;
+            let body = constructor_from_values(path, fields, &names, &values);
+            Ok(quote!(#pattern => #body))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #impl_generics ::rust_fp_categories::Functor for #name #ty_generics #where_clause {
+            type Elm = #param;
+            type M<#mapped: Clone> = #name<#(#mapped_args),*>;
+
+            fn fmap<#mapped: Clone, __F>(self, f: __F) -> Self::M<#mapped>
+            where
+                __F: Fn(&#param) -> #mapped,
+            {
+                #[allow(unused_variables)]
+                let f: &dyn Fn(&#param) -> #mapped = &f;
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+    })
+}
+
+/// 型`ty`の値`value`の各要素に`f`を適用する式を作成します。
+fn map(
+    ty: &Type,
+    value: TokenStream,
+    param: &Ident,
+    self_name: &Ident,
+) -> syn::Result<TokenStream> {
+    Ok(match classify(ty, param, self_name, "Functor")? {
+        FieldShape::Param => quote!(f(&#value)),
+        FieldShape::Unrelated => value,
+        FieldShape::Recursive => quote!(::rust_fp_categories::Functor::fmap(#value, f)),
+        FieldShape::Boxed(inner) => {
+            let inner = map(inner, quote!(value), param, self_name)?;
+            quote!(::std::boxed::Box::new({
+                let value = *#value;
+                #inner
+            }))
+        }
+        FieldShape::Tuple(elems) => {
+            let names: Vec<_> = (0..elems.len())
+                .map(|index| format_ident!("value_{}", index))
+                .collect();
+            let values = elems
+                .iter()
+                .zip(&names)
+                .map(|(elem, name)| map(elem, quote!(#name), param, self_name))
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote!({
+                let (#(#names,)*) = #value;
+                (#(#values,)*)
+            })
+        }
+        FieldShape::Container(inner) => match classify(inner, param, self_name, "Functor")? {
+            FieldShape::Param => quote!(::rust_fp_categories::Functor::fmap(#value, f)),
+            _ => {
+                let inner = map(inner, quote!(value), param, self_name)?;
+                quote!(::rust_fp_categories::Functor::fmap(#value, |value| {
+                    let value = ::std::clone::Clone::clone(value);
+                    #inner
+                }))
+            }
+        },
+    })
+}
@@ -0,0 +1,113 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Ident, Type};
+
+use crate::fields::{bindings, constructor};
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let (repr, into_repr, from_repr) = match &input.data {
+        Data::Struct(data) => {
+            let (names, types) = bindings(&data.fields);
+            let constructor = constructor(quote!(#name), &data.fields, &names);
+            let hlist = hlist(&names);
+            (
+                hlist_type(&types),
+                quote! {
+                    let #constructor = self;
+                    #hlist
+                },
+                quote! {
+                    let #hlist = repr;
+                    #constructor
+                },
+            )
+        }
+        Data::Enum(data) => {
+            let mut repr = quote!(::rust_fp_categories::CNil);
+            let mut into_arms = Vec::new();
+            let mut from_arms = Vec::new();
+            for (index, variant) in data.variants.iter().enumerate().rev() {
+                let variant_name = &variant.ident;
+                let (names, types) = bindings(&variant.fields);
+                let variant_repr = hlist_type(&types);
+                repr = quote!(::rust_fp_categories::Coproduct<#variant_repr, #repr>);
+
+                let constructor =
+                    constructor(quote!(#name::#variant_name), &variant.fields, &names);
+                let injected = inject(index, hlist(&names));
+                into_arms.push(quote!(#constructor => #injected));
+                from_arms.push(quote!(#injected => #constructor));
+            }
+            let nil = inject_nil(data.variants.len());
+            (
+                repr,
+                quote! {
+                    match self {
+                        #(#into_arms,)*
+                    }
+                },
+                quote! {
+                    match repr {
+                        #(#from_arms,)*
+                        #nil => match nil {},
+                    }
+                },
+            )
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span,
+                "Generic cannot be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::rust_fp_categories::Generic for #name #ty_generics #where_clause {
+            type Repr = #repr;
+
+            fn into_repr(self) -> Self::Repr {
+                #into_repr
+            }
+
+            fn from_repr(repr: Self::Repr) -> Self {
+                #from_repr
+            }
+        }
+    })
+}
+
+fn hlist_type(types: &[&Type]) -> TokenStream {
+    types.iter().rev().fold(
+        quote!(::rust_fp_categories::HNil),
+        |tail, head| quote!(::rust_fp_categories::HCons<#head, #tail>),
+    )
+}
+
+/// 式とパターンの両方に使えるHListを作成します。
+fn hlist(names: &[Ident]) -> TokenStream {
+    names.iter().rev().fold(
+        quote!(::rust_fp_categories::HNil),
+        |tail, head| quote!(::rust_fp_categories::HCons { head: #head, tail: #tail }),
+    )
+}
+
+/// `index`番目の位置に`value`を注入した、式とパターンの両方に使えるCoproductを作成します。
+fn inject(index: usize, value: TokenStream) -> TokenStream {
+    let inl = quote!(::rust_fp_categories::Coproduct::Inl(#value));
+    (0..index).fold(
+        inl,
+        |inner, _| quote!(::rust_fp_categories::Coproduct::Inr(#inner)),
+    )
+}
+
+/// すべてのバリアントより後ろにある`CNil`を束縛するパターンを作成します。
+fn inject_nil(len: usize) -> TokenStream {
+    (0..len).fold(
+        quote!(nil),
+        |inner, _| quote!(::rust_fp_categories::Coproduct::Inr(#inner)),
+    )
+}
@@ -0,0 +1,249 @@
+//! rust-fp-categoriesの型クラスを導出するための手続き的マクロです。
+//!
+//! 通常はこのクレートを直接使わず、`rust_fp_categories`から再エクスポートされたマクロを使用します。
+
+extern crate proc_macro;
+
+mod fields;
+mod foldable;
+mod functor;
+mod generic;
+mod semigroup;
+mod shape;
+mod show;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// `rust_fp_categories::Generic`を導出します。
+///
+/// 構造体はフィールドを宣言順に並べたHListに、列挙型は各バリアントのHListを
+/// 宣言順に並べたCoproductに対応付けます。
+///
+/// # エラー
+///
+/// 共用体(`union`)には導出できません。
+#[proc_macro_derive(Generic)]
+pub fn derive_generic(input: TokenStream) -> TokenStream {
+    expand(input, generic::expand)
+}
+
+/// `rust_fp_categories::Semigroup`を導出します。
+///
+/// 各フィールドをそのフィールドの`Semigroup`で結合します。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{Semigroup, Max};
+///
+/// #[derive(Semigroup, Debug, PartialEq)]
+/// struct Stats {
+///     count: u32,
+///     tags: Vec<String>,
+///     peak: Max<i32>,
+/// }
+///
+/// let a = Stats { count: 1, tags: vec!["a".to_string()], peak: Max(3) };
+/// let b = Stats { count: 2, tags: vec!["b".to_string()], peak: Max(1) };
+/// assert_eq!(
+///     a.combine(b),
+///     Stats { count: 3, tags: vec!["a".to_string(), "b".to_string()], peak: Max(3) }
+/// );
+/// ```
+///
+/// # エラー
+///
+/// 構造体にのみ導出できます。列挙型や共用体に対しては
+/// `Semigroup can only be derived for structs`というエラーになります。
+///
+/// ```compile_fail
+/// use rust_fp_categories::Semigroup;
+///
+/// #[derive(Semigroup)]
+/// enum Choice {
+///     Left(i32),
+///     Right(i32),
+/// }
+/// ```
+#[proc_macro_derive(Semigroup)]
+pub fn derive_semigroup(input: TokenStream) -> TokenStream {
+    expand(input, semigroup::expand_semigroup)
+}
+
+/// `rust_fp_categories::Monoid`と、その前提となる`rust_fp_categories::Empty`を導出します。
+///
+/// `empty`は各フィールドの単位元から構築し、`is_empty`はすべてのフィールドが単位元である場合に
+/// `true`を返します。`Semigroup`は別途導出または実装する必要があります。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{Empty, Foldable, Monoid, Semigroup};
+///
+/// #[derive(Semigroup, Monoid, Debug, PartialEq, Clone)]
+/// struct Totals(u32, String);
+///
+/// assert_eq!(Totals::empty(), Totals(0, String::new()));
+/// let totals = vec![Totals(1, "a".to_string()), Totals(2, "b".to_string())].combine_all();
+/// assert_eq!(totals, Totals(3, "ab".to_string()));
+/// ```
+///
+/// # エラー
+///
+/// 構造体にのみ導出できます。列挙型や共用体に対しては
+/// `Monoid can only be derived for structs`というエラーになります。
+#[proc_macro_derive(Monoid)]
+pub fn derive_monoid(input: TokenStream) -> TokenStream {
+    expand(input, semigroup::expand_monoid)
+}
+
+/// `rust_fp_categories::Show`を導出します。
+///
+/// `Debug`と同じ形式で、各フィールドをそのフィールドの`Show`で文字列に変換します。
+/// 構造体と列挙型に導出できます。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::Show;
+///
+/// #[derive(Show)]
+/// struct User {
+///     name: String,
+///     age: u32,
+///     nickname: Option<String>,
+/// }
+///
+/// #[derive(Show)]
+/// enum Shape {
+///     Circle(u32),
+///     Empty,
+/// }
+///
+/// let user = User { name: "alice".to_string(), age: 20, nickname: None };
+/// assert_eq!(user.show(), "User { name: alice, age: 20, nickname: None }");
+/// assert_eq!(Shape::Circle(3).show(), "Circle(3)");
+/// assert_eq!(Shape::Empty.show(), "Empty");
+/// ```
+///
+/// # エラー
+///
+/// 共用体には導出できません。
+#[proc_macro_derive(Show)]
+pub fn derive_show(input: TokenStream) -> TokenStream {
+    expand(input, show::expand)
+}
+
+/// `rust_fp_categories::Functor`を導出します。
+///
+/// 型パラメータをちょうど1つ持つ構造体と列挙型に導出でき、`Functor::M<B>`はその型パラメータを
+/// `B`に置き換えた型になります。要素の型パラメータ`A`を含むフィールドは、次の形に対応しています。
+///
+/// * `A`そのもの
+/// * 導出対象の型そのもの(`Tree<A>`や`Self`)
+/// * `Box<T>`や`(T, U)`のように、これらを包んだ型
+/// * 最後の型引数だけが`A`を含む`Functor`のコンテナ(`Vec<A>`、`Option<Box<Tree<A>>>`など)
+///
+/// `A`を含まないフィールドはそのまま移されます。
+/// コンテナの要素が`A`そのものでない場合は、要素を複製するため`Clone`が必要です。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::{Foldable, Functor};
+///
+/// #[derive(Functor, Foldable, Debug, PartialEq, Clone)]
+/// enum Tree<A> {
+///     Leaf,
+///     Node(Box<Tree<A>>, A, Box<Tree<A>>),
+/// }
+///
+/// fn node<A>(left: Tree<A>, value: A, right: Tree<A>) -> Tree<A> {
+///     Tree::Node(Box::new(left), value, Box::new(right))
+/// }
+///
+/// let tree = node(node(Tree::Leaf, 1, Tree::Leaf), 2, Tree::Leaf);
+/// let doubled = tree.fmap(|a| a * 2);
+/// assert_eq!(doubled, node(node(Tree::Leaf, 2, Tree::Leaf), 4, Tree::Leaf));
+/// assert_eq!(doubled.to_vec(), vec![2, 4]);
+/// ```
+///
+/// # エラー
+///
+/// 型パラメータが1つでない型に対しては
+/// `Functor can only be derived for types with exactly one type parameter`というエラーになります。
+///
+/// ```compile_fail
+/// use rust_fp_categories::Functor;
+///
+/// #[derive(Functor)]
+/// struct Pair<A, B>(A, B);
+/// ```
+///
+/// 関数や参照、配列など、上記以外の形で`A`を含むフィールドは
+/// `Functor cannot be derived for this field`というエラーになります。
+///
+/// ```compile_fail
+/// use rust_fp_categories::Functor;
+///
+/// #[derive(Functor)]
+/// struct Callback<A> {
+///     run: fn(A) -> A,
+/// }
+/// ```
+///
+/// 共用体には導出できません。
+#[proc_macro_derive(Functor)]
+pub fn derive_functor(input: TokenStream) -> TokenStream {
+    expand(input, functor::expand)
+}
+
+/// `rust_fp_categories::Foldable`を導出します。
+///
+/// 対応する型の形は`Functor`の導出と同じです。要素はフィールドの宣言順に畳み込まれます。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::Foldable;
+///
+/// #[derive(Foldable)]
+/// struct Labeled<A> {
+///     label: String,
+///     primary: A,
+///     others: Vec<A>,
+/// }
+///
+/// let labeled = Labeled { label: "scores".to_string(), primary: 1, others: vec![2, 3] };
+/// assert_eq!(labeled.fold_left(0, |acc, a| acc * 10 + a), 123);
+/// assert_eq!(labeled.fold_right(0, |a, acc| acc * 10 + a), 321);
+/// ```
+///
+/// # エラー
+///
+/// `Functor`の導出と同じく、型パラメータが1つでない型や、対応していない形のフィールドに対しては
+/// コンパイルエラーになります。
+///
+/// ```compile_fail
+/// use rust_fp_categories::Foldable;
+///
+/// #[derive(Foldable)]
+/// struct Borrowed<'a, A> {
+///     value: &'a A,
+/// }
+/// ```
+#[proc_macro_derive(Foldable)]
+pub fn derive_foldable(input: TokenStream) -> TokenStream {
+    expand(input, foldable::expand)
+}
+
+fn expand(
+    input: TokenStream,
+    f: fn(&DeriveInput) -> syn::Result<proc_macro2::TokenStream>,
+) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    f(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
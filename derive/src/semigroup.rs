@@ -0,0 +1,81 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::DeriveInput;
+
+use crate::fields::{
+    bindings, constructor, constructor_from_values, struct_fields, with_field_bounds,
+};
+
+pub fn expand_semigroup(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let fields = struct_fields(input, "Semigroup")?;
+    let (names, types) = bindings(fields);
+    let others: Vec<_> = names
+        .iter()
+        .map(|name| format_ident!("other_{}", name.unraw()))
+        .collect();
+    let generics = with_field_bounds(
+        &input.generics,
+        &types,
+        quote!(::rust_fp_categories::Semigroup),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let this = constructor(quote!(#name), fields, &names);
+    let other_values = others
+        .iter()
+        .map(|other| quote!(#other))
+        .collect::<Vec<_>>();
+    let other = constructor_from_values(quote!(#name), fields, &names, &other_values);
+    let combined = names
+        .iter()
+        .zip(&others)
+        .map(|(name, other)| quote!(::rust_fp_categories::Semigroup::combine(#name, #other)))
+        .collect::<Vec<_>>();
+    let result = constructor_from_values(quote!(#name), fields, &names, &combined);
+
+    Ok(quote! {
+        impl #impl_generics ::rust_fp_categories::Semigroup for #name #ty_generics #where_clause {
+            fn combine(self, other: Self) -> Self {
+                let #this = self;
+                let #other = other;
+                #result
+            }
+        }
+    })
+}
+
+pub fn expand_monoid(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let fields = struct_fields(input, "Monoid")?;
+    let (names, types) = bindings(fields);
+    let generics = with_field_bounds(
+        &input.generics,
+        &types,
+        quote!(::rust_fp_categories::Monoid),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let empties = names
+        .iter()
+        .map(|_| quote!(::rust_fp_categories::Empty::empty()))
+        .collect::<Vec<_>>();
+    let empty = constructor_from_values(quote!(#name), fields, &names, &empties);
+    let this = constructor(quote!(#name), fields, &names);
+
+    Ok(quote! {
+        impl #impl_generics ::rust_fp_categories::Empty for #name #ty_generics #where_clause {
+            fn empty() -> Self {
+                #empty
+            }
+
+            fn is_empty(&self) -> bool {
+                let #this = self;
+                true #(&& ::rust_fp_categories::Empty::is_empty(#names))*
+            }
+        }
+
+        impl #impl_generics ::rust_fp_categories::Monoid for #name #ty_generics #where_clause {}
+    })
+}
@@ -0,0 +1,143 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, GenericArgument, GenericParam, Ident, PathArguments, Type};
+
+use crate::fields::mentions;
+
+/// `Functor`や`Foldable`の導出で、フィールドの型が要素の型パラメータをどのように含むかを表します。
+pub enum FieldShape<'a> {
+    /// 型パラメータそのもの
+    Param,
+    /// 型パラメータを含まない型
+    Unrelated,
+    /// 導出対象の型そのもの(再帰的な型)
+    Recursive,
+    /// 型パラメータを含む`Box<T>`
+    Boxed(&'a Type),
+    /// 型パラメータを含むタプル
+    Tuple(Vec<&'a Type>),
+    /// 最後の型引数だけが型パラメータを含むコンテナ(例: `Vec<T>`)
+    Container(&'a Type),
+}
+
+/// 型パラメータがちょうど1つであることを確かめ、その識別子を返します。
+pub fn single_type_param<'a>(input: &'a DeriveInput, derive: &str) -> syn::Result<&'a Ident> {
+    let mut params = input.generics.type_params();
+    match (params.next(), params.next()) {
+        (Some(param), None) => Ok(&param.ident),
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            format!(
+                "{} can only be derived for types with exactly one type parameter",
+                derive
+            ),
+        )),
+    }
+}
+
+/// 型パラメータ`param`を`replacement`に置き換えた、導出対象の型の引数を返します。
+pub fn replaced_args(input: &DeriveInput, replacement: &Ident) -> Vec<TokenStream> {
+    input
+        .generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(def) => {
+                let lifetime = &def.lifetime;
+                quote!(#lifetime)
+            }
+            GenericParam::Type(_) => quote!(#replacement),
+            GenericParam::Const(def) => {
+                let ident = &def.ident;
+                quote!(#ident)
+            }
+        })
+        .collect()
+}
+
+pub fn classify<'a>(
+    ty: &'a Type,
+    param: &Ident,
+    name: &Ident,
+    derive: &str,
+) -> syn::Result<FieldShape<'a>> {
+    if !mentions(ty, param) {
+        return Ok(FieldShape::Unrelated);
+    }
+    match ty {
+        Type::Paren(paren) => classify(&paren.elem, param, name, derive),
+        Type::Group(group) => classify(&group.elem, param, name, derive),
+        Type::Tuple(tuple) => Ok(FieldShape::Tuple(tuple.elems.iter().collect())),
+        Type::Path(path) if path.qself.is_none() => {
+            if path.path.is_ident(param) {
+                return Ok(FieldShape::Param);
+            }
+            let segments = &path.path.segments;
+            let last = segments.last().expect("path has at least one segment");
+            let prefix_mentions = segments
+                .iter()
+                .take(segments.len() - 1)
+                .any(|segment| mentions_in_arguments(&segment.arguments, param));
+            let type_args: Vec<&Type> = match &last.arguments {
+                PathArguments::AngleBracketed(args) => args
+                    .args
+                    .iter()
+                    .filter_map(|arg| match arg {
+                        GenericArgument::Type(ty) => Some(ty),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => vec![],
+            };
+            if prefix_mentions {
+                return Err(unsupported(ty, param, derive));
+            }
+            if last.ident == *name || last.ident == "Self" {
+                return match type_args.as_slice() {
+                    [] if last.ident == "Self" => Ok(FieldShape::Recursive),
+                    [arg] if is_param(arg, param) => Ok(FieldShape::Recursive),
+                    _ => Err(unsupported(ty, param, derive)),
+                };
+            }
+            match type_args.split_last() {
+                Some((last_arg, rest)) if rest.iter().all(|arg| !mentions(arg, param)) => {
+                    if last.ident == "Box" && rest.is_empty() {
+                        Ok(FieldShape::Boxed(last_arg))
+                    } else {
+                        Ok(FieldShape::Container(last_arg))
+                    }
+                }
+                _ => Err(unsupported(ty, param, derive)),
+            }
+        }
+        _ => Err(unsupported(ty, param, derive)),
+    }
+}
+
+fn is_param(ty: &Type, param: &Ident) -> bool {
+    match ty {
+        Type::Path(path) => path.qself.is_none() && path.path.is_ident(param),
+        _ => false,
+    }
+}
+
+fn mentions_in_arguments(arguments: &PathArguments, param: &Ident) -> bool {
+    match arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().any(|arg| match arg {
+            GenericArgument::Type(ty) => mentions(ty, param),
+            _ => false,
+        }),
+        _ => false,
+    }
+}
+
+fn unsupported(ty: &Type, param: &Ident, derive: &str) -> syn::Error {
+    syn::Error::new_spanned(
+        ty,
+        format!(
+            "{} cannot be derived for this field: `{}` may only appear as the field type itself, \
+             inside `Box` or a tuple, as the derived type, or as the last type argument of a container",
+            derive, param
+        ),
+    )
+}
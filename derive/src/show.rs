@@ -0,0 +1,52 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::{DeriveInput, Fields};
+
+use crate::fields::{bindings, constructor, variants, with_field_bounds};
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let variants = variants(input, "Show")?;
+    let types: Vec<_> = variants
+        .iter()
+        .flat_map(|(_, _, fields)| fields.iter().map(|field| &field.ty))
+        .collect();
+    let generics = with_field_bounds(&input.generics, &types, quote!(::rust_fp_categories::Show));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let arms = variants.iter().map(|(path, label, fields)| {
+        let (names, _) = bindings(fields);
+        let pattern = constructor(path.clone(), fields, &names);
+        let label = label.to_string();
+        let shown: Vec<_> = names
+            .iter()
+            .map(|name| quote!(::rust_fp_categories::Show::show(#name)))
+            .collect();
+        let body = match fields {
+            Fields::Named(_) if !names.is_empty() => {
+                let labels = names.iter().map(|name| format!("{}: ", name.unraw()));
+                quote! {
+                    format!("{} {{ {} }}", #label, [#(#labels.to_string() + &#shown),*].join(", "))
+                }
+            }
+            Fields::Unnamed(_) if !names.is_empty() => quote! {
+                format!("{}({})", #label, [#(#shown),*].join(", "))
+            },
+            _ => quote!(#label.to_string()),
+        };
+        quote!(#pattern => #body)
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::rust_fp_categories::Show for #name #ty_generics #where_clause {
+            type Elm = Self;
+
+            fn show(self) -> String {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+    })
+}
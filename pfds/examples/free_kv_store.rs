@@ -0,0 +1,121 @@
+//! Freeモナドでキー・バリューストアの操作を記述し、2つのインタプリタで実行する例です。
+//!
+//! * `List`を連想リストとして使う、永続データ構造ベースのインタプリタ
+//! * 操作をログとして記録するだけの、`Writer`ベースのインタプリタ
+
+use rust_fp_categories::{for_yield, Empty, Foldable, Free, Pure, Reply, ReplyTo, Writer};
+use rust_fp_pfds::{List, Stack};
+
+/// キー・バリューストアに対する命令です。各命令は結果を返すための`ReplyTo`を保持します。
+enum KvOp {
+    Put(String, String, ReplyTo<()>),
+    Get(String, ReplyTo<Option<String>>),
+    Delete(String, ReplyTo<bool>),
+}
+
+fn put(key: &str, value: &str) -> Free<KvOp, ()> {
+    Free::lift_f(|reply| KvOp::Put(key.to_string(), value.to_string(), reply))
+}
+
+fn get(key: &str) -> Free<KvOp, Option<String>> {
+    Free::lift_f(|reply| KvOp::Get(key.to_string(), reply))
+}
+
+fn delete(key: &str) -> Free<KvOp, bool> {
+    Free::lift_f(|reply| KvOp::Delete(key.to_string(), reply))
+}
+
+/// ユーザー名を登録し、挨拶文を組み立てる業務フローです。
+fn register_user(id: &str, name: &str) -> Free<KvOp, String> {
+    for_yield! {
+        move;
+        let key = id.to_string();
+        bind _saved = put(&key, name);
+        bind _removed = delete("guest");
+        bind found = get(&key);
+        yield Free::pure(format!("hello, {}", found.unwrap_or_default()))
+    }
+}
+
+/// `List`を連想リストとして使うストアです。
+struct ListStore {
+    entries: List<(String, String)>,
+}
+
+impl ListStore {
+    fn new() -> Self {
+        ListStore {
+            entries: List::empty(),
+        }
+    }
+
+    fn lookup(&self, key: &str) -> Option<String> {
+        self.entries.fold_left(None, |found, (k, v)| {
+            found.or_else(|| if k == key { Some(v.clone()) } else { None })
+        })
+    }
+
+    fn remove(&mut self, key: &str) -> bool {
+        let existed = self.lookup(key).is_some();
+        self.entries =
+            self.entries
+                .fold_right(List::empty(), |(k, v), acc: List<(String, String)>| {
+                    if k == key {
+                        acc
+                    } else {
+                        acc.cons((k.clone(), v.clone()))
+                    }
+                });
+        existed
+    }
+
+    fn interpret(&mut self, op: &KvOp) -> Reply {
+        match op {
+            KvOp::Put(key, value, reply) => {
+                self.remove(key);
+                self.entries = self.entries.clone().cons((key.clone(), value.clone()));
+                reply.send(())
+            }
+            KvOp::Get(key, reply) => reply.send(self.lookup(key)),
+            KvOp::Delete(key, reply) => reply.send(self.remove(key)),
+        }
+    }
+}
+
+/// 操作をログとして記録するインタプリタです。ストアの状態は持ちません。
+fn logging(op: &KvOp) -> Writer<Vec<String>, Reply> {
+    match op {
+        KvOp::Put(key, value, reply) => {
+            reply.send_in(Writer::new(vec![format!("PUT {}={}", key, value)], ()))
+        }
+        KvOp::Get(key, reply) => reply.send_in(Writer::new(
+            vec![format!("GET {}", key)],
+            Some(format!("<{}>", key)),
+        )),
+        KvOp::Delete(key, reply) => {
+            reply.send_in(Writer::new(vec![format!("DELETE {}", key)], false))
+        }
+    }
+}
+
+fn main() {
+    let program = register_user("user-1", "alice");
+
+    let mut store = ListStore::new();
+    let greeting = program.run(|op| store.interpret(op));
+    assert_eq!(greeting, Ok("hello, alice".to_string()));
+    assert_eq!(store.lookup("user-1"), Some("alice".to_string()));
+    println!("store: {:?}", greeting);
+
+    let (log, greeting) = program.fold_map(logging).run();
+    assert_eq!(greeting, Ok("hello, <user-1>".to_string()));
+    assert_eq!(
+        log,
+        vec![
+            "PUT user-1=alice".to_string(),
+            "DELETE guest".to_string(),
+            "GET user-1".to_string(),
+        ]
+    );
+    println!("log: {:?}", log);
+}
@@ -1,7 +1,9 @@
 use std::rc::Rc;
 
 use crate::{Queue, QueueError};
-use rust_fp_categories::{Applicative, Apply, Bind, Empty, Foldable, Functor, Monad, Pure};
+use rust_fp_categories::{
+    Applicative, ApplicativeFamily, Apply, Bind, Empty, Foldable, Functor, Monad, Pure, Traverse,
+};
 
 /// An array-based queue implementation.
 ///
@@ -27,6 +29,15 @@ impl<A> ArrayQueue<A> {
     }
 }
 
+impl<A> From<Vec<A>> for ArrayQueue<A> {
+    /// Creates a queue whose front is the first element of the vector.
+    fn from(elements: Vec<A>) -> Self {
+        ArrayQueue {
+            elements: Rc::new(elements),
+        }
+    }
+}
+
 impl<A> Empty for ArrayQueue<A> {
     fn empty() -> Self {
         ArrayQueue::new()
@@ -190,6 +201,27 @@ impl<A: Clone> Foldable for ArrayQueue<A> {
     }
 }
 
+impl<A: Clone> Traverse for ArrayQueue<A> {
+    fn traverse<G, F>(self, f: F) -> <G as Apply>::M<ArrayQueue<<G as Apply>::Elm>>
+    where
+        G: ApplicativeFamily,
+        <G as Apply>::Elm: Clone,
+        ArrayQueue<<G as Apply>::Elm>: Clone,
+        F: Fn(&A) -> G,
+    {
+        let values = self.elements.iter().fold(
+            G::pure_of(Vec::with_capacity(self.elements.len())),
+            |acc, a| {
+                G::map2(acc, f(a), |mut values, b| {
+                    values.push(b);
+                    values
+                })
+            },
+        );
+        G::map_of(values, ArrayQueue::from)
+    }
+}
+
 impl<A: Clone> Queue<A> for ArrayQueue<A> {
     fn enqueue(self, value: A) -> Self {
         let mut new_elements = (*self.elements).clone();
@@ -343,4 +375,52 @@ mod tests {
         assert_eq!(queue1_new.size(), 2);
         assert_eq!(queue2.size(), 3);
     }
+
+    #[test]
+    fn test_from_vec() -> Result<(), QueueError> {
+        let queue = ArrayQueue::from(vec![1, 2, 3]);
+        assert_eq!(queue.size(), 3);
+        assert_eq!(queue.peek()?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_traverse_large_queue() {
+        let queue = ArrayQueue::from((0..100_000).collect::<Vec<_>>());
+        let traversed = queue.clone().traverse(|x| Some(*x));
+        assert_eq!(traversed, Some(queue));
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use super::*;
+    use crate::Queue;
+    use quickcheck_macros::quickcheck;
+
+    fn f(x: &i32) -> Option<i32> {
+        if *x % 7 == 0 {
+            None
+        } else {
+            Some(*x * 2)
+        }
+    }
+
+    #[quickcheck]
+    fn traverse_identity(xs: Vec<i32>) -> bool {
+        let values = ArrayQueue::from_iter(xs);
+        values.clone().traverse(|x| Some(*x)) == Some(values)
+    }
+
+    #[quickcheck]
+    fn traverse_naturality(xs: Vec<i32>) -> bool {
+        let values = ArrayQueue::from_iter(xs);
+        values.clone().traverse(f).ok_or(()) == values.traverse(|x| f(x).ok_or(()))
+    }
+
+    #[quickcheck]
+    fn sequence_consistency(xs: Vec<i32>) -> bool {
+        let values = ArrayQueue::from_iter(xs);
+        values.clone().traverse(f) == values.fmap(f).sequence()
+    }
 }
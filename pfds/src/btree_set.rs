@@ -1,4 +1,5 @@
 use std::marker::PhantomData;
+use std::ops::ControlFlow;
 use std::rc::Rc;
 use std::vec::Vec;
 
@@ -207,6 +208,13 @@ impl<A: Clone + PartialEq + PartialOrd + Eq> Foldable for BTreeSet<A> {
     {
         self.tree.fold_right(b, f)
     }
+
+    fn try_fold<B, C, F>(&self, b: B, f: F) -> ControlFlow<C, B>
+    where
+        F: Fn(B, &Self::Elm) -> ControlFlow<C, B>,
+    {
+        self.tree.try_fold(b, f)
+    }
 }
 
 #[cfg(test)]
@@ -281,4 +289,12 @@ mod tests {
         assert!(set.member(2));
         assert!(set.member(3));
     }
+
+    #[test]
+    fn test_foldable_queries() {
+        let set = BTreeSet::from(vec![4, 1, 3]);
+        assert_eq!(set.find(|n| n % 2 == 1), Some(1));
+        assert_eq!(set.min_by(|a, b| a.cmp(b)), Some(1));
+        assert_eq!(set.length(), 3);
+    }
 }
@@ -1,5 +1,6 @@
 use std::hash::Hash;
 use std::marker::PhantomData;
+use std::ops::ControlFlow;
 use std::rc::Rc;
 use std::vec::Vec;
 
@@ -230,6 +231,21 @@ impl<A: Clone + PartialEq + Eq + Hash> Foldable for HashSet<A> {
         }
         result
     }
+
+    fn try_fold<B, C, F>(&self, b: B, f: F) -> ControlFlow<C, B>
+    where
+        F: Fn(B, &Self::Elm) -> ControlFlow<C, B>,
+    {
+        self.elements.iter().try_fold(b, f)
+    }
+
+    fn length(&self) -> usize {
+        self.elements.len()
+    }
+
+    fn to_vec(&self) -> Vec<A> {
+        self.elements.clone()
+    }
 }
 
 #[cfg(test)]
@@ -304,4 +320,12 @@ mod tests {
         assert!(set.member(2));
         assert!(set.member(3));
     }
+
+    #[test]
+    fn test_foldable_queries() {
+        let set = HashSet::from(vec![1, 2, 2, 3]);
+        assert_eq!(set.length(), 3);
+        assert!(set.for_all(|n| *n > 0));
+        assert_eq!(set.fold_map(|n| rust_fp_categories::Sum(*n)).0, 6);
+    }
 }
@@ -22,6 +22,7 @@ mod list_optimized_v2;
 mod list_queue;
 #[cfg(test)]
 mod list_queue_tests;
+mod non_empty_list;
 mod optimized_deque;
 mod optimized_queue;
 #[cfg(test)]
@@ -58,6 +59,7 @@ pub use list_deque::*;
 pub use list_optimized::List as ListOptimized;
 pub use list_optimized_v2::List as ListOptimizedV2;
 pub use list_queue::*;
+pub use non_empty_list::*;
 pub use optimized_deque::*;
 pub use optimized_queue::*;
 pub use persistent_stack::*;
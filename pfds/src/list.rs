@@ -1,6 +1,8 @@
 use crate::stack::Stack;
 use crate::StackError;
+use rust_fp_categories::optics::Indexed;
 use rust_fp_categories::*;
+use std::ops::ControlFlow;
 use std::rc::Rc;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -63,7 +65,11 @@ impl<A: Clone> Semigroup for List<A> {
             List::Nil => other,
             List::Cons { head: h, tail: t } => List::Cons {
                 head: h,
-                tail: Rc::new(Rc::try_unwrap(t).unwrap_or(List::Nil).combine(other)),
+                tail: Rc::new(
+                    Rc::try_unwrap(t)
+                        .unwrap_or_else(|t| (*t).clone())
+                        .combine(other),
+                ),
             },
         }
     }
@@ -158,6 +164,16 @@ impl<A: Clone> Bind for List<A> {
 
 impl<A: Clone> Monad for List<A> {}
 
+// --- Alternative
+
+impl<A: Clone> Alternative for List<A> {
+    fn combine_k(self, other: Self) -> Self {
+        self.combine(other)
+    }
+}
+
+impl<A: Clone> MonadPlus for List<A> {}
+
 // --- Foldable
 
 impl<A: Clone> Foldable for List<A> {
@@ -179,6 +195,46 @@ impl<A: Clone> Foldable for List<A> {
     {
         self.reverse().fold_left(b, |b, a| f(a, b))
     }
+
+    fn try_fold<B, C, F>(&self, b: B, f: F) -> ControlFlow<C, B>
+    where
+        F: Fn(B, &Self::Elm) -> ControlFlow<C, B>,
+    {
+        let mut acc = b;
+        let mut current = self;
+        while let List::Cons { head, tail } = current {
+            acc = f(acc, head)?;
+            current = tail;
+        }
+        ControlFlow::Continue(acc)
+    }
+
+    fn length(&self) -> usize {
+        let mut n = 0;
+        let mut current = self;
+        while let List::Cons { tail, .. } = current {
+            n += 1;
+            current = tail;
+        }
+        n
+    }
+}
+
+// --- Traverse
+
+impl<A: Clone> Traverse for List<A> {
+    fn traverse<G, F>(self, f: F) -> <G as Apply>::M<List<<G as Apply>::Elm>>
+    where
+        G: ApplicativeFamily,
+        <G as Apply>::Elm: Clone,
+        List<<G as Apply>::Elm>: Clone,
+        F: Fn(&A) -> G,
+    {
+        let reversed = self.fold_left(G::pure_of(List::empty()), |acc, a| {
+            G::map2(acc, f(a), |l: List<<G as Apply>::Elm>, b| l.cons(b))
+        });
+        G::map_of(reversed, |l| l.reverse())
+    }
 }
 
 impl<A: Clone> Stack<A> for List<A> {
@@ -277,15 +333,54 @@ impl<A: Clone> Stack<A> for List<A> {
     }
 }
 
+/// Focuses on an element by its index, as used by `optics::index`.
+///
+/// Unlike `Stack::update`, replacing an element never fails on a shared list:
+/// the elements before the index are copied and the rest of the list is shared.
+impl<A: Clone> Indexed<u32, A> for List<A> {
+    fn get_at(&self, key: &u32) -> Option<A> {
+        self.get(*key).ok().cloned()
+    }
+
+    fn replace_at(self, key: &u32, value: A) -> Self {
+        let mut prefix = Vec::new();
+        let mut current = &self;
+        for _ in 0..*key {
+            match current {
+                List::Nil => return self,
+                List::Cons { head, tail } => {
+                    prefix.push(head.clone());
+                    current = tail;
+                }
+            }
+        }
+        match current {
+            List::Nil => self,
+            List::Cons { tail, .. } => prefix.into_iter().rev().fold(
+                List::Cons {
+                    head: value,
+                    tail: Rc::clone(tail),
+                },
+                |list, head| list.cons(head),
+            ),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::list::List;
     use crate::stack::StackError;
     use crate::Stack;
+    use rust_fp_categories::optics::{self, Compose, Lens};
     use rust_fp_categories::Bind;
     use rust_fp_categories::Empty;
     use rust_fp_categories::Functor;
+    use rust_fp_categories::Pure;
     use rust_fp_categories::Semigroup;
+    use rust_fp_categories::{guard, Alternative};
+    use rust_fp_categories::{Foldable, Sum};
+    use std::cell::Cell;
 
     #[test]
     fn test_from_vec_to_vec() -> Result<(), StackError> {
@@ -348,6 +443,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_combine_k() -> Result<(), StackError> {
+        let list1: List<i32> = List::from(vec![1, 2]);
+        let list2: List<i32> = List::from(vec![3]);
+        let vec1: Vec<i32> = list1
+            .clone()
+            .combine_k(List::empty())
+            .combine_k(list2)
+            .into();
+        assert_eq!(vec1, vec![1, 2, 3]);
+        let vec2: Vec<i32> = List::empty().combine_k(list1).into();
+        assert_eq!(vec2, vec![1, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_guard() -> Result<(), StackError> {
+        let list1: List<i32> = List::from(vec![1, 2, 3, 4]);
+        let list2 = list1.bind(|v| {
+            let v = *v;
+            guard::<List<()>>(v % 2 == 0).bind(move |_| List::pure(v))
+        });
+        let vec1: Vec<i32> = list2.into();
+        assert_eq!(vec1, vec![2, 4]);
+        Ok(())
+    }
+
     #[test]
     fn test_head_tail() -> Result<(), StackError> {
         let list1: List<i32> = List::from(vec![1, 2, 3, 4, 5]);
@@ -377,4 +499,116 @@ mod tests {
         assert_eq!(list1.tail(), list2.tail());
         Ok(())
     }
+
+    #[test]
+    fn test_foldable_queries_stop_early() {
+        let list = List::from(vec![1, 2, 3, 4]);
+        let calls = Cell::new(0);
+        let found = list.find(|n| {
+            calls.set(calls.get() + 1);
+            n % 2 == 0
+        });
+        assert_eq!(found, Some(2));
+        assert_eq!(calls.get(), 2);
+        assert_eq!(list.length(), 4);
+        assert_eq!(list.fold_map(|n| Sum(*n)), Sum(10));
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4]);
+        assert!(List::<i32>::empty().for_all(|_| false));
+    }
+
+    #[test]
+    fn test_optics_focus_on_index() {
+        let list = List::from(vec![1, 2, 3]);
+        let second = optics::index::<List<i32>, _, _>(1);
+        let updated = second.modify(list.clone(), |n| n * 10);
+        assert_eq!(updated, List::from(vec![1, 20, 3]));
+        assert_eq!(list, List::from(vec![1, 2, 3]));
+        assert_eq!(second.get_option(&updated), Some(20));
+        assert_eq!(second.set(List::from(vec![1]), 5), List::from(vec![1]));
+    }
+
+    #[test]
+    fn test_optics_compose_into_list() {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        struct Team {
+            scores: List<i32>,
+        }
+
+        let scores = Lens::new(|t: &Team| t.scores.clone(), |_, scores| Team { scores });
+        let team = Team {
+            scores: List::from(vec![1, 2, 3]),
+        };
+
+        let first = scores.clone().and_then(optics::index(0));
+        assert_eq!(first.set(team.clone(), 7).scores, List::from(vec![7, 2, 3]));
+
+        let all = scores.and_then(optics::each::<List<i32>, i32>());
+        assert_eq!(all.get_all(&team), vec![1, 2, 3]);
+        assert_eq!(
+            all.modify(team, |n| n + 1).scores,
+            List::from(vec![2, 3, 4])
+        );
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::list::List;
+    use quickcheck_macros::quickcheck;
+    use rust_fp_categories::{Bind, Functor, Traverse};
+
+    fn f(x: &i32) -> Option<i32> {
+        if *x % 7 == 0 {
+            None
+        } else {
+            Some(*x * 2)
+        }
+    }
+
+    fn g(x: &i32) -> Option<i32> {
+        if *x % 5 == 0 {
+            None
+        } else {
+            Some(*x + 1)
+        }
+    }
+
+    #[quickcheck]
+    fn traverse_identity(xs: Vec<i32>) -> bool {
+        let list = List::from(xs);
+        list.clone().traverse(|x| Some(*x)) == Some(list)
+    }
+
+    #[quickcheck]
+    fn traverse_naturality(xs: Vec<i32>) -> bool {
+        let list = List::from(xs);
+        list.clone().traverse(f).ok_or(()) == list.traverse(|x| f(x).ok_or(()))
+    }
+
+    #[quickcheck]
+    fn traverse_composition(xs: Vec<i32>) -> bool {
+        let list = List::from(xs);
+        list.clone()
+            .traverse(f)
+            .bind(|ys: &List<i32>| ys.clone().traverse(g))
+            == list.traverse(|x| f(x).bind(g))
+    }
+
+    #[quickcheck]
+    fn traverse_preserves_order(xs: Vec<i32>) -> bool {
+        let list = List::from(xs.clone());
+        list.traverse(|x| Some(*x)).map(|l| l.into()) == Some(xs)
+    }
+
+    #[quickcheck]
+    fn sequence_consistency(xs: Vec<i32>) -> bool {
+        let list = List::from(xs);
+        list.clone().traverse(f) == list.fmap(f).sequence()
+    }
+
+    #[test]
+    fn sequence_returns_first_error() {
+        let list: List<Result<i32, &str>> = List::from(vec![Ok(1), Err("first"), Err("second")]);
+        assert_eq!(list.sequence(), Err("first"));
+    }
 }
@@ -0,0 +1,311 @@
+use crate::{List, Stack};
+use rust_fp_categories::*;
+
+/// `List`を使った、少なくとも1つの要素を持つリストです。
+///
+/// 先頭要素を常に持つため、`head`は失敗しません。
+/// `Apply`は、`Bind`と整合するように、関数と値のすべての組み合わせに適用します。
+///
+/// # 例
+///
+/// ```
+/// use rust_fp_categories::Empty;
+/// use rust_fp_pfds::{List, NonEmptyList};
+///
+/// let values = NonEmptyList::new(1, List::from(vec![2, 3]));
+/// assert_eq!(*values.head(), 1);
+/// assert_eq!(values.len(), 3);
+/// assert_eq!(values.reduce(), 6);
+///
+/// assert!(NonEmptyList::from_list(List::from(vec![1])).is_some());
+/// assert!(NonEmptyList::<i32>::from_list(List::empty()).is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonEmptyList<A: Clone> {
+    head: A,
+    tail: List<A>,
+}
+
+impl<A: Clone> NonEmptyList<A> {
+    pub fn new(head: A, tail: List<A>) -> Self {
+        NonEmptyList { head, tail }
+    }
+
+    pub fn singleton(head: A) -> Self {
+        NonEmptyList::new(head, List::empty())
+    }
+
+    /// `List`が空でなければ、`NonEmptyList`に変換します。
+    pub fn from_list(list: List<A>) -> Option<Self> {
+        list.uncons()
+            .ok()
+            .map(|(head, tail)| NonEmptyList::new(head, tail))
+    }
+
+    /// `Vec`が空でなければ、`NonEmptyList`に変換します。
+    pub fn from_vec(values: Vec<A>) -> Option<Self> {
+        NonEmptyList::from_list(List::from(values))
+    }
+
+    pub fn head(&self) -> &A {
+        &self.head
+    }
+
+    pub fn tail(&self) -> &List<A> {
+        &self.tail
+    }
+
+    pub fn len(&self) -> usize {
+        self.tail.size() + 1
+    }
+
+    /// 常に`false`を返します。
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    pub fn cons(self, value: A) -> Self {
+        NonEmptyList::new(value, self.tail.cons(self.head))
+    }
+
+    pub fn to_list(self) -> List<A> {
+        self.tail.cons(self.head)
+    }
+
+    /// 初期値を使わずに、すべての要素を`Semigroup::combine`で結合します。
+    pub fn reduce(self) -> A
+    where
+        A: Semigroup,
+    {
+        self.tail
+            .fold_left(self.head, |acc, a| acc.combine(a.clone()))
+    }
+}
+
+impl<A: Clone> From<NonEmptyList<A>> for List<A> {
+    fn from(values: NonEmptyList<A>) -> Self {
+        values.to_list()
+    }
+}
+
+// --- Semigroup
+
+impl<A: Clone> Semigroup for NonEmptyList<A> {
+    fn combine(self, other: Self) -> Self {
+        NonEmptyList::new(self.head, self.tail.combine(other.to_list()))
+    }
+}
+
+// --- Functor
+
+impl<A: Clone> Functor for NonEmptyList<A> {
+    type Elm = A;
+    type M<U: Clone> = NonEmptyList<U>;
+
+    fn fmap<B: Clone, F>(self, f: F) -> NonEmptyList<B>
+    where
+        F: Fn(&A) -> B,
+    {
+        let head = f(&self.head);
+        NonEmptyList::new(head, self.tail.fmap(f))
+    }
+}
+
+// --- Applicative
+
+impl<A: Clone> Pure for NonEmptyList<A> {
+    type Elm = A;
+    type M<U: Clone> = NonEmptyList<U>;
+
+    fn pure(value: A) -> NonEmptyList<A> {
+        NonEmptyList::singleton(value)
+    }
+
+    fn unit() -> NonEmptyList<()> {
+        NonEmptyList::singleton(())
+    }
+}
+
+impl<A: Clone> Apply for NonEmptyList<A> {
+    type Elm = A;
+    type M<U: Clone> = NonEmptyList<U>;
+
+    fn ap<B: Clone, F>(self, fs: NonEmptyList<F>) -> NonEmptyList<B>
+    where
+        F: Clone + Fn(&A) -> B,
+    {
+        fs.bind(|f| self.clone().fmap(f))
+    }
+}
+
+impl<A: Clone> Applicative for NonEmptyList<A> {}
+
+// --- Bind
+
+impl<A: Clone> Bind for NonEmptyList<A> {
+    type Elm = A;
+    type M<U: Clone> = NonEmptyList<U>;
+
+    fn bind<B: Clone, F>(self, f: F) -> NonEmptyList<B>
+    where
+        F: Fn(&A) -> NonEmptyList<B>,
+    {
+        // 後ろから、構築済みの残りのリストへ各結果を前置することで、要素数に比例する時間で構築します。
+        let rest = self.tail.fold_right(List::empty(), |a, rest| {
+            f(a).fold_right(rest, |b, rest| rest.cons(b.clone()))
+        });
+        let first = f(&self.head);
+        let tail = first.tail.fold_right(rest, |b, rest| rest.cons(b.clone()));
+        NonEmptyList::new(first.head, tail)
+    }
+}
+
+impl<A: Clone> Monad for NonEmptyList<A> {}
+
+// --- Foldable
+
+impl<A: Clone> Foldable for NonEmptyList<A> {
+    type Elm = A;
+
+    fn fold_left<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(B, &A) -> B,
+    {
+        let b = f(b, &self.head);
+        self.tail.fold_left(b, f)
+    }
+
+    fn fold_right<B, F>(&self, b: B, f: F) -> B
+    where
+        F: Fn(&A, B) -> B,
+    {
+        let b = self.tail.fold_right(b, &f);
+        f(&self.head, b)
+    }
+}
+
+#[cfg(test)]
+mod laws {
+    use crate::{List, NonEmptyList};
+    use quickcheck_macros::quickcheck;
+    use rust_fp_categories::eq::Eq;
+    use rust_fp_categories::laws;
+    use rust_fp_categories::{Apply, Bind, Foldable, Functor, Pure, Semigroup};
+
+    fn non_empty(head: i32, tail: Vec<i32>) -> NonEmptyList<i32> {
+        NonEmptyList::new(head, List::from(tail))
+    }
+
+    fn f(n: &i32) -> NonEmptyList<i32> {
+        non_empty(*n, vec![n.wrapping_mul(2)])
+    }
+
+    fn g(n: &i32) -> NonEmptyList<i32> {
+        NonEmptyList::singleton(n.wrapping_sub(1))
+    }
+
+    #[quickcheck]
+    fn functor_identity(head: i32, tail: Vec<i32>) -> bool {
+        let xs = non_empty(head, tail);
+        xs.clone().fmap(|x| *x) == xs
+    }
+
+    #[quickcheck]
+    fn applicative_identity(head: i32, tail: Vec<i32>) -> bool {
+        let xs = non_empty(head, tail);
+        xs.clone().ap(NonEmptyList::pure(|x: &i32| *x)) == xs
+    }
+
+    #[quickcheck]
+    fn monad_left_identity(n: i32) -> bool {
+        NonEmptyList::pure(n).bind(f) == f(&n)
+    }
+
+    #[quickcheck]
+    fn monad_right_identity(head: i32, tail: Vec<i32>) -> bool {
+        let xs = non_empty(head, tail);
+        xs.clone().bind(|x| NonEmptyList::pure(*x)) == xs
+    }
+
+    #[quickcheck]
+    fn monad_associativity(head: i32, tail: Vec<i32>) -> bool {
+        let xs = non_empty(head, tail);
+        xs.clone().bind(f).bind(g) == xs.bind(|x| f(x).bind(g))
+    }
+
+    #[quickcheck]
+    fn semigroup_associativity(a: Vec<i32>, b: Vec<i32>, c: Vec<i32>) -> bool {
+        let a = non_empty(0, a);
+        let b = non_empty(1, b);
+        let c = non_empty(2, c);
+        a.clone().combine(b.clone()).combine(c.clone()) == a.combine(b.combine(c))
+    }
+
+    #[quickcheck]
+    fn fold_left_and_fold_right_agree(head: i32, tail: Vec<i32>) -> bool {
+        let xs = non_empty(head, tail);
+        let left = xs.fold_left(Vec::new(), |mut acc, x| {
+            acc.push(*x);
+            acc
+        });
+        let right = xs.fold_right(Vec::new(), |x, mut acc| {
+            acc.insert(0, *x);
+            acc
+        });
+        left == right
+    }
+
+    #[quickcheck]
+    fn bind_agrees_with_vec(head: i32, tail: Vec<i32>) -> bool {
+        let xs = non_empty(head, tail);
+        let bound: Vec<i32> = xs.clone().to_list().into();
+        let bound = bound.bind(|x| -> Vec<i32> { f(x).to_list().into() });
+        let actual: Vec<i32> = xs.bind(f).to_list().into();
+        actual == bound
+    }
+
+    #[test]
+    fn laws_module_accepts_non_empty_list() {
+        let eq = Eq::natural();
+        let result = laws::check(|(head, tail, a): (i32, Vec<i32>, i32)| {
+            let xs = non_empty(head, tail);
+            laws::semigroup_associativity(xs.clone(), xs.clone().cons(a), xs.clone(), &eq)?;
+            laws::functor_identity(xs.clone(), &eq)?;
+            laws::bind_associativity(xs.clone(), f, g, &eq)?;
+            laws::monad_left_identity(a, f, &eq)?;
+            laws::monad_right_identity(xs.clone(), &eq)?;
+            laws::foldable_consistency(xs, &Eq::natural())
+        });
+        assert_eq!(result, Ok(()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{List, NonEmptyList};
+    use rust_fp_categories::{Apply, Functor};
+
+    #[test]
+    fn test_from_vec() {
+        let xs = NonEmptyList::from_vec(vec![1, 2, 3]).unwrap();
+        assert_eq!(*xs.head(), 1);
+        assert_eq!(xs.tail(), &List::from(vec![2, 3]));
+        assert!(NonEmptyList::<i32>::from_vec(vec![]).is_none());
+    }
+
+    #[test]
+    fn test_reduce_strings() {
+        let xs = NonEmptyList::new("a".to_string(), List::from(vec!["b".to_string()]));
+        assert_eq!(xs.cons("z".to_string()).reduce(), "zab");
+    }
+
+    #[test]
+    fn test_ap_is_cartesian() {
+        let xs = NonEmptyList::from_vec(vec![1, 2]).unwrap();
+        let fs = NonEmptyList::from_vec(vec![|x: &i32| x + 10, |x: &i32| x * 10]).unwrap();
+        let expected: Vec<i32> = List::from(xs.ap(fs)).into();
+        assert_eq!(expected, vec![11, 12, 10, 20]);
+        let doubled: Vec<i32> = NonEmptyList::singleton(4).fmap(|x| x * 2).to_list().into();
+        assert_eq!(doubled, vec![8]);
+    }
+}
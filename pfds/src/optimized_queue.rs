@@ -1,7 +1,9 @@
 use std::rc::Rc;
 
 use crate::{List, Queue, QueueError, Stack};
-use rust_fp_categories::{Applicative, Apply, Bind, Empty, Foldable, Functor, Monad, Pure};
+use rust_fp_categories::{
+    Applicative, ApplicativeFamily, Apply, Bind, Empty, Foldable, Functor, Monad, Pure, Traverse,
+};
 
 /// An optimized queue implementation using two lists.
 ///
@@ -218,6 +220,22 @@ impl<A: Clone> Foldable for OptimizedQueue<A> {
     }
 }
 
+impl<A: Clone> Traverse for OptimizedQueue<A> {
+    fn traverse<G, F>(self, f: F) -> <G as Apply>::M<OptimizedQueue<<G as Apply>::Elm>>
+    where
+        G: ApplicativeFamily,
+        <G as Apply>::Elm: Clone,
+        OptimizedQueue<<G as Apply>::Elm>: Clone,
+        F: Fn(&A) -> G,
+    {
+        self.fold_left(G::pure_of(OptimizedQueue::empty()), |acc, a| {
+            G::map2(acc, f(a), |q: OptimizedQueue<<G as Apply>::Elm>, b| {
+                q.enqueue(b)
+            })
+        })
+    }
+}
+
 impl<A: Clone> Queue<A> for OptimizedQueue<A> {
     fn enqueue(self, value: A) -> Self {
         let new_rear = Rc::new(List::cons((*self.rear).clone(), value));
@@ -430,3 +448,36 @@ mod tests {
         assert!(rust_fp_categories::Empty::is_empty(&queue));
     }
 }
+
+#[cfg(test)]
+mod laws {
+    use super::*;
+    use crate::Queue;
+    use quickcheck_macros::quickcheck;
+
+    fn f(x: &i32) -> Option<i32> {
+        if *x % 7 == 0 {
+            None
+        } else {
+            Some(*x * 2)
+        }
+    }
+
+    #[quickcheck]
+    fn traverse_identity(xs: Vec<i32>) -> bool {
+        let values = OptimizedQueue::from_iter(xs);
+        values.clone().traverse(|x| Some(*x)) == Some(values)
+    }
+
+    #[quickcheck]
+    fn traverse_naturality(xs: Vec<i32>) -> bool {
+        let values = OptimizedQueue::from_iter(xs);
+        values.clone().traverse(f).ok_or(()) == values.traverse(|x| f(x).ok_or(()))
+    }
+
+    #[quickcheck]
+    fn sequence_consistency(xs: Vec<i32>) -> bool {
+        let values = OptimizedQueue::from_iter(xs);
+        values.clone().traverse(f) == values.fmap(f).sequence()
+    }
+}
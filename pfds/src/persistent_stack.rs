@@ -1,6 +1,10 @@
 use crate::stack::Stack;
 use crate::StackError;
-use rust_fp_categories::{Applicative, Apply, Bind, Empty, Foldable, Functor, Monad, Pure};
+use rust_fp_categories::{
+    Alternative, Applicative, ApplicativeFamily, Apply, Bind, Empty, Foldable, Functor, Monad,
+    MonadPlus, Pure, Traverse,
+};
+use std::ops::ControlFlow;
 use std::rc::Rc;
 
 /// PersistentStack is a fully persistent stack implementation.
@@ -228,6 +232,16 @@ impl<A: Clone> Bind for PersistentStack<A> {
 // Implement Monad for PersistentStack
 impl<A: Clone> Monad for PersistentStack<A> {}
 
+// Implement Alternative for PersistentStack
+impl<A: Clone> Alternative for PersistentStack<A> {
+    fn combine_k(self, other: Self) -> Self {
+        self.combine(other)
+    }
+}
+
+// Implement MonadPlus for PersistentStack
+impl<A: Clone> MonadPlus for PersistentStack<A> {}
+
 // Implement Foldable for PersistentStack
 impl<A: Clone> Foldable for PersistentStack<A> {
     type Elm = A;
@@ -257,6 +271,49 @@ impl<A: Clone> Foldable for PersistentStack<A> {
             }
         }
     }
+
+    fn try_fold<B, C, F>(&self, b: B, f: F) -> ControlFlow<C, B>
+    where
+        F: Fn(B, &Self::Elm) -> ControlFlow<C, B>,
+    {
+        let mut acc = b;
+        let mut current = self;
+        while let PersistentStack::Node(value, tail) = current {
+            acc = f(acc, value)?;
+            current = tail;
+        }
+        ControlFlow::Continue(acc)
+    }
+
+    fn length(&self) -> usize {
+        let mut n = 0;
+        let mut current = self;
+        while let PersistentStack::Node(_, tail) = current {
+            n += 1;
+            current = tail;
+        }
+        n
+    }
+}
+
+// Implement Traverse for PersistentStack
+impl<A: Clone> Traverse for PersistentStack<A> {
+    fn traverse<G, F>(self, f: F) -> <G as Apply>::M<PersistentStack<<G as Apply>::Elm>>
+    where
+        G: ApplicativeFamily,
+        <G as Apply>::Elm: Clone,
+        PersistentStack<<G as Apply>::Elm>: Clone,
+        F: Fn(&A) -> G,
+    {
+        let reversed = self.fold_left(G::pure_of(PersistentStack::empty()), |acc, a| {
+            G::map2(acc, f(a), |s: PersistentStack<<G as Apply>::Elm>, b| {
+                s.cons(b)
+            })
+        });
+        G::map_of(reversed, |s| {
+            s.fold_left(PersistentStack::empty(), |acc, b| acc.cons(b.clone()))
+        })
+    }
 }
 
 #[cfg(test)]
@@ -337,6 +394,19 @@ mod tests {
         assert_eq!(vec, vec2);
     }
 
+    #[test]
+    fn test_combine_k() {
+        let stack1 = PersistentStack::from(vec![1, 2]);
+        let stack2 = PersistentStack::from(vec![3]);
+        let combined = stack1
+            .clone()
+            .combine_k(PersistentStack::empty())
+            .combine_k(stack2);
+        let vec: Vec<i32> = combined.into();
+        assert_eq!(vec, vec![1, 2, 3]);
+        assert_eq!(PersistentStack::empty().combine_k(stack1.clone()), stack1);
+    }
+
     #[test]
     fn test_persistence() -> Result<(), StackError> {
         let stack1 = PersistentStack::empty().cons(1).cons(2);
@@ -353,3 +423,49 @@ mod tests {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod laws {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    fn f(x: &i32) -> Option<i32> {
+        if *x % 7 == 0 {
+            None
+        } else {
+            Some(*x * 2)
+        }
+    }
+
+    #[quickcheck]
+    fn traverse_identity(xs: Vec<i32>) -> bool {
+        let values = PersistentStack::from(xs);
+        values.clone().traverse(|x| Some(*x)) == Some(values)
+    }
+
+    #[quickcheck]
+    fn traverse_naturality(xs: Vec<i32>) -> bool {
+        let values = PersistentStack::from(xs);
+        values.clone().traverse(f).ok_or(()) == values.traverse(|x| f(x).ok_or(()))
+    }
+
+    #[quickcheck]
+    fn sequence_consistency(xs: Vec<i32>) -> bool {
+        let mapped = PersistentStack::from(xs.iter().map(f).collect::<Vec<_>>());
+        PersistentStack::from(xs).traverse(f) == mapped.sequence()
+    }
+
+    #[test]
+    fn test_foldable_queries_stop_early() {
+        let stack = PersistentStack::empty().cons(3).cons(2).cons(1);
+        let calls = std::cell::Cell::new(0);
+        let exists = stack.exists(|n| {
+            calls.set(calls.get() + 1);
+            *n == 2
+        });
+        assert!(exists);
+        assert_eq!(calls.get(), 2);
+        assert_eq!(stack.length(), 3);
+        assert_eq!(stack.max_by(|a, b| a.cmp(b)), Some(3));
+    }
+}
@@ -1,5 +1,6 @@
 use std::boxed::Box;
 use std::marker::PhantomData;
+use std::ops::ControlFlow;
 
 use crate::Set;
 use rust_fp_categories::{Applicative, Apply, Bind, Empty, Foldable, Functor, Monad, Pure};
@@ -44,38 +45,72 @@ impl<A: Clone + Eq> Foldable for Tree<A> {
     where
         F: Fn(B, &Self::Elm) -> B,
     {
-        match self {
-            Tree::Empty => b,
-            Tree::Cons(left, value, right) => {
-                // Fold the left subtree
-                let b1 = left.fold_left(b, &f);
+        // Recurse with `&F` so that the closure type does not grow at each level
+        fn go<A: Eq, B, F>(tree: &Tree<A>, b: B, f: &F) -> B
+        where
+            F: Fn(B, &A) -> B,
+        {
+            match tree {
+                Tree::Empty => b,
+                Tree::Cons(left, value, right) => {
+                    // Fold the left subtree
+                    let b1 = go(left, b, f);
 
-                // Apply f to the current value
-                let b2 = f(b1, value);
+                    // Apply f to the current value
+                    let b2 = f(b1, value);
 
-                // Fold the right subtree
-                right.fold_left(b2, &f)
+                    // Fold the right subtree
+                    go(right, b2, f)
+                }
             }
         }
+        go(self, b, &f)
     }
 
     fn fold_right<B, F>(&self, b: B, f: F) -> B
     where
         F: Fn(&Self::Elm, B) -> B,
     {
-        match self {
-            Tree::Empty => b,
-            Tree::Cons(left, value, right) => {
-                // Fold the right subtree
-                let b1 = right.fold_right(b, &f);
+        fn go<A: Eq, B, F>(tree: &Tree<A>, b: B, f: &F) -> B
+        where
+            F: Fn(&A, B) -> B,
+        {
+            match tree {
+                Tree::Empty => b,
+                Tree::Cons(left, value, right) => {
+                    // Fold the right subtree
+                    let b1 = go(right, b, f);
+
+                    // Apply f to the current value
+                    let b2 = f(value, b1);
 
-                // Apply f to the current value
-                let b2 = f(value, b1);
+                    // Fold the left subtree
+                    go(left, b2, f)
+                }
+            }
+        }
+        go(self, b, &f)
+    }
 
-                // Fold the left subtree
-                left.fold_right(b2, &f)
+    fn try_fold<B, C, F>(&self, b: B, f: F) -> ControlFlow<C, B>
+    where
+        F: Fn(B, &Self::Elm) -> ControlFlow<C, B>,
+    {
+        fn go<A: Eq, B, C, F>(tree: &Tree<A>, b: B, f: &F) -> ControlFlow<C, B>
+        where
+            F: Fn(B, &A) -> ControlFlow<C, B>,
+        {
+            match tree {
+                Tree::Empty => ControlFlow::Continue(b),
+                Tree::Cons(left, value, right) => {
+                    // Stop as soon as the left subtree or the current value breaks
+                    let b1 = go(left, b, f)?;
+                    let b2 = f(b1, value)?;
+                    go(right, b2, f)
+                }
             }
         }
+        go(self, b, &f)
     }
 }
 
@@ -343,6 +378,8 @@ impl<A: Clone + PartialEq + PartialOrd + Eq> Set<A> for Tree<A> {
 mod tests {
     use crate::{Set, StackError, Tree};
     use rust_fp_categories::Empty;
+    use rust_fp_categories::Foldable;
+    use std::ops::ControlFlow;
 
     #[test]
     fn test_size() -> Result<(), StackError> {
@@ -406,4 +443,25 @@ mod tests {
         assert!(!set2.is_subset_of(&set1));
         assert!(!set1.is_subset_of(&set3));
     }
+
+    #[test]
+    fn test_foldable_try_fold_in_order() {
+        let tree = Tree::empty()
+            .insert(5)
+            .insert(2)
+            .insert(8)
+            .insert(1)
+            .insert(3);
+        let first_two = tree.fold_while(Vec::new(), |mut acc, n| {
+            if acc.len() == 2 {
+                ControlFlow::Break(acc)
+            } else {
+                acc.push(*n);
+                ControlFlow::Continue(acc)
+            }
+        });
+        assert_eq!(first_two, vec![1, 2]);
+        assert_eq!(tree.find(|n| *n > 2), Some(3));
+        assert_eq!(tree.to_vec(), vec![1, 2, 3, 5, 8]);
+    }
 }